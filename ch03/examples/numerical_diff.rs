@@ -0,0 +1,694 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+// ---------------------------------------------------------------------------
+// Fornberg の差分係数
+// ---------------------------------------------------------------------------
+
+/// Fornberg のアルゴリズム: 点 x_0, ..., x_{n-1} の値から点 z での
+/// 0〜m 階微分を近似する重み c[k][j] を求める
+///
+/// f^{(k)}(z) ≈ Σ_j c[k][j] f(x_j)。点の配置は任意（等間隔でなくてもよい）です。
+fn fornberg_weights(z: f64, x: &[f64], m: usize) -> Vec<Vec<f64>> {
+    let n = x.len();
+    let mut c = vec![vec![0.0; n]; m + 1];
+    c[0][0] = 1.0;
+    let mut c1 = 1.0;
+    let mut c4 = x[0] - z;
+
+    for i in 1..n {
+        let mn = i.min(m);
+        let mut c2 = 1.0;
+        let c5 = c4;
+        c4 = x[i] - z;
+        for j in 0..i {
+            let c3 = x[i] - x[j];
+            c2 *= c3;
+            if j == i - 1 {
+                for k in (1..=mn).rev() {
+                    c[k][i] = c1 * (k as f64 * c[k - 1][i - 1] - c5 * c[k][i - 1]) / c2;
+                }
+                c[0][i] = -c1 * c5 * c[0][i - 1] / c2;
+            }
+            for k in (1..=mn).rev() {
+                c[k][j] = (c4 * c[k][j] - k as f64 * c[k - 1][j]) / c3;
+            }
+            c[0][j] = c4 * c[0][j] / c3;
+        }
+        c1 = c2;
+    }
+    c
+}
+
+/// 等間隔の差分ステンシル（オフセット × h の点での重み）
+struct Stencil {
+    offsets: Vec<f64>,
+    weights: Vec<f64>,
+    order: usize,
+}
+
+impl Stencil {
+    /// order 階微分の中心差分（誤差 O(h^accuracy), accuracy は偶数）
+    fn central(order: usize, accuracy: usize) -> Self {
+        assert!(order >= 1, "微分の階数は 1 以上です");
+        // 必要な点数は 2⌊(order+1)/2⌋ - 1 + accuracy
+        let half = order.div_ceil(2) - 1 + accuracy / 2;
+        let offsets: Vec<f64> = (-(half as i64)..=half as i64).map(|k| k as f64).collect();
+        Self::from_offsets(order, offsets)
+    }
+
+    /// order 階微分の前進差分（点 0, 1, ..., order + accuracy - 1）
+    fn forward(order: usize, accuracy: usize) -> Self {
+        let offsets: Vec<f64> = (0..order + accuracy).map(|k| k as f64).collect();
+        Self::from_offsets(order, offsets)
+    }
+
+    fn from_offsets(order: usize, offsets: Vec<f64>) -> Self {
+        let weights = fornberg_weights(0.0, &offsets, order)[order].clone();
+        Self {
+            offsets,
+            weights,
+            order,
+        }
+    }
+
+    fn apply<F>(&self, f: &F, x: f64, h: f64) -> f64
+    where
+        F: Fn(f64) -> f64,
+    {
+        let sum: f64 = self
+            .offsets
+            .iter()
+            .zip(&self.weights)
+            .filter(|(_, w)| **w != 0.0)
+            .map(|(&o, &w)| w * f(x + o * h))
+            .sum();
+        sum / h.powi(self.order as i32)
+    }
+
+    /// 打ち切り誤差の次数 p（誤差 ∝ h^p）: 重みが 0 にならない最初の高次モーメントから求める
+    fn accuracy(&self) -> usize {
+        let fact = |k: usize| (1..=k).map(|i| i as f64).product::<f64>();
+        (self.order + 1..self.order + 20)
+            .find(|&k| {
+                let moment: f64 = self
+                    .offsets
+                    .iter()
+                    .zip(&self.weights)
+                    .map(|(o, w)| w * o.powi(k as i32))
+                    .sum::<f64>()
+                    / fact(k);
+                moment.abs() > 1e-10
+            })
+            .map_or(0, |k| k - self.order)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Ridders の外挿
+// ---------------------------------------------------------------------------
+
+/// Ridders の方法: 中心差分を h, h/1.4, h/1.4², ... で計算してネヴィル表で h → 0 に外挿する
+///
+/// 表の各段で誤差推定を更新し、誤差が最小になった値を返します。
+/// 誤差が急に増え始めたら（丸め誤差が支配的になったら）打ち切ります。
+fn ridders<F>(f: &F, x: f64, h0: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    const CON: f64 = 1.4;
+    const CON2: f64 = CON * CON;
+    const NTAB: usize = 10;
+    const SAFE: f64 = 2.0;
+
+    let mut a = vec![vec![0.0; NTAB]; NTAB];
+    let mut h = h0;
+    a[0][0] = (f(x + h) - f(x - h)) / (2.0 * h);
+    let mut best = (a[0][0], f64::INFINITY);
+
+    for i in 1..NTAB {
+        h /= CON;
+        a[0][i] = (f(x + h) - f(x - h)) / (2.0 * h);
+        let mut fac = CON2;
+        for j in 1..=i {
+            // h² の冪で外挿（中心差分の誤差は h² の偶数冪の級数）
+            a[j][i] = (a[j - 1][i] * fac - a[j - 1][i - 1]) / (fac - 1.0);
+            fac *= CON2;
+            let err = (a[j][i] - a[j - 1][i])
+                .abs()
+                .max((a[j][i] - a[j - 1][i - 1]).abs());
+            if err <= best.1 {
+                best = (a[j][i], err);
+            }
+        }
+        if (a[i][i] - a[i - 1][i - 1]).abs() >= SAFE * best.1 {
+            break;
+        }
+    }
+    best
+}
+
+// ---------------------------------------------------------------------------
+// 最適刻み幅
+// ---------------------------------------------------------------------------
+
+/// 中心差分 (f(x+h) - f(x-h)) / 2h の最適刻み幅を自動で選ぶ
+///
+/// 全誤差 ≈ ε|f|/h（丸め）+ h²|f'''|/6（打ち切り）を最小にする
+/// h* = (3ε|f| / |f'''|)^{1/3}。|f'''| は大きめの刻み ε^{1/5} の 5 点差分で見積もります。
+/// f''' が 0 に近いときは標準的な ε^{1/3} (|x| + 1) を使います。
+fn optimal_step<F>(f: &F, x: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let eps = f64::EPSILON;
+    let scale = x.abs().max(1.0);
+    let h3 = eps.powf(0.2) * scale;
+    let third = Stencil::central(3, 2).apply(f, x, h3).abs();
+    let fx = f(x).abs().max(eps);
+    if third < 1e-12 * fx {
+        eps.cbrt() * scale
+    } else {
+        (3.0 * eps * fx / third).cbrt()
+    }
+}
+
+/// 最適刻み幅の中心差分による 1 階微分
+fn derivative<F>(f: &F, x: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let h = optimal_step(f, x);
+    // x + h と x - h を実際に表現できる値に丸め、その差で割る
+    let (xp, xm) = (x + h, x - h);
+    (f(xp) - f(xm)) / (xp - xm)
+}
+
+// ---------------------------------------------------------------------------
+// 複素ステップ微分
+// ---------------------------------------------------------------------------
+
+/// 複素ステップ微分用の最小限の複素数
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    fn exp(self) -> Self {
+        let r = self.re.exp();
+        Self::new(r * self.im.cos(), r * self.im.sin())
+    }
+
+    fn sin(self) -> Self {
+        Self::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    fn cos(self) -> Self {
+        Self::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+
+    fn sqrt(self) -> Self {
+        // r - re の桁落ちを避けるため、大きい方の成分を先に求めて他方を割り算で得る
+        let t = (0.5 * (self.re.abs() + self.re.hypot(self.im))).sqrt();
+        if t == 0.0 {
+            Self::new(0.0, 0.0)
+        } else if self.re >= 0.0 {
+            Self::new(t, 0.5 * self.im / t)
+        } else {
+            Self::new(0.5 * self.im.abs() / t, t.copysign(self.im))
+        }
+    }
+
+    fn powi(self, n: i32) -> Self {
+        // 繰り返し二乗法（負の冪は逆数の正の冪）
+        let mut base = if n < 0 {
+            Self::new(1.0, 0.0) / self
+        } else {
+            self
+        };
+        let mut k = n.unsigned_abs();
+        let mut acc = Self::new(1.0, 0.0);
+        while k > 0 {
+            if k & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base * base;
+            k >>= 1;
+        }
+        acc
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, o: Self) -> Self {
+        Self::new(self.re + o.re, self.im + o.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, o: Self) -> Self {
+        Self::new(self.re - o.re, self.im - o.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, o: Self) -> Self {
+        Self::new(
+            self.re * o.re - self.im * o.im,
+            self.re * o.im + self.im * o.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+    fn div(self, o: Self) -> Self {
+        let d = o.re * o.re + o.im * o.im;
+        Self::new(
+            (self.re * o.re + self.im * o.im) / d,
+            (self.im * o.re - self.re * o.im) / d,
+        )
+    }
+}
+
+/// 複素ステップ微分 f'(x) ≈ Im f(x + ih) / h
+///
+/// 差をとらないので桁落ちがなく、h = 1e-20 のような極端に小さい刻みで
+/// 機械精度の 1 階微分が得られます。f は実軸上で実数値の解析関数である必要があります。
+fn complex_step<F>(f: &F, x: f64) -> f64
+where
+    F: Fn(Complex) -> Complex,
+{
+    let h = 1e-20;
+    f(Complex::new(x, h)).im / h
+}
+
+// ---------------------------------------------------------------------------
+// 勾配・ヤコビ行列・ヘッセ行列
+// ---------------------------------------------------------------------------
+
+/// 各成分の刻み幅: 中心差分の標準的な最適値 ε^{1/3} (|x_i| + 1)
+fn step_for(x: f64, power: f64) -> f64 {
+    f64::EPSILON.powf(power) * x.abs().max(1.0)
+}
+
+/// スカラー関数 f: R^n → R の勾配（中心差分）
+fn gradient<F>(f: &F, x: &[f64]) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let mut xp = x.to_vec();
+    (0..x.len())
+        .map(|i| {
+            let h = step_for(x[i], 1.0 / 3.0);
+            xp[i] = x[i] + h;
+            let fp = f(&xp);
+            xp[i] = x[i] - h;
+            let fm = f(&xp);
+            xp[i] = x[i];
+            (fp - fm) / (2.0 * h)
+        })
+        .collect()
+}
+
+/// ベクトル関数 F: R^n → R^m のヤコビ行列 J[i][j] = ∂F_i/∂x_j（中心差分）
+fn jacobian<F>(f: &F, x: &[f64]) -> Vec<Vec<f64>>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    let m = f(x).len();
+    let mut jac = vec![vec![0.0; x.len()]; m];
+    let mut xp = x.to_vec();
+    for j in 0..x.len() {
+        let h = step_for(x[j], 1.0 / 3.0);
+        xp[j] = x[j] + h;
+        let fp = f(&xp);
+        xp[j] = x[j] - h;
+        let fm = f(&xp);
+        xp[j] = x[j];
+        for (row, (p, q)) in jac.iter_mut().zip(fp.iter().zip(&fm)) {
+            row[j] = (p - q) / (2.0 * h);
+        }
+    }
+    jac
+}
+
+/// スカラー関数のヘッセ行列（2 階中心差分, 刻み ε^{1/4}）
+///
+/// 対角: [f(x+h e_i) - 2f(x) + f(x-h e_i)] / h²
+/// 非対角: [f(++) - f(+-) - f(-+) + f(--)] / 4 h_i h_j
+fn hessian<F>(f: &F, x: &[f64]) -> Vec<Vec<f64>>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let n = x.len();
+    let f0 = f(x);
+    let h: Vec<f64> = x.iter().map(|&xi| step_for(xi, 0.25)).collect();
+    let mut hess = vec![vec![0.0; n]; n];
+    let mut xp = x.to_vec();
+
+    for i in 0..n {
+        xp[i] = x[i] + h[i];
+        let fp = f(&xp);
+        xp[i] = x[i] - h[i];
+        let fm = f(&xp);
+        xp[i] = x[i];
+        hess[i][i] = (fp - 2.0 * f0 + fm) / (h[i] * h[i]);
+
+        for j in i + 1..n {
+            let mut corner = |si: f64, sj: f64| {
+                xp[i] = x[i] + si * h[i];
+                xp[j] = x[j] + sj * h[j];
+                let v = f(&xp);
+                xp[i] = x[i];
+                xp[j] = x[j];
+                v
+            };
+            let v = (corner(1.0, 1.0) - corner(1.0, -1.0) - corner(-1.0, 1.0) + corner(-1.0, -1.0))
+                / (4.0 * h[i] * h[j]);
+            hess[i][j] = v;
+            hess[j][i] = v;
+        }
+    }
+    hess
+}
+
+/// 2×2 連立一次方程式 A x = b（クラメルの公式）
+fn solve_2x2(a: &[Vec<f64>], b: &[f64]) -> [f64; 2] {
+    let det = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+    [
+        (b[0] * a[1][1] - a[0][1] * b[1]) / det,
+        (a[0][0] * b[1] - b[0] * a[1][0]) / det,
+    ]
+}
+
+/// (関数, 微分する点, 厳密な導関数値)
+type DiffCase<'a> = (&'a dyn Fn(f64) -> f64, f64, f64);
+
+fn max_abs_diff(a: &[Vec<f64>], b: &[Vec<f64>]) -> f64 {
+    a.iter()
+        .flatten()
+        .zip(b.iter().flatten())
+        .map(|(p, q)| (p - q).abs())
+        .fold(0.0, f64::max)
+}
+
+fn main() {
+    let f = |x: f64| x.sin();
+    let x0: f64 = 1.0;
+
+    // --- 1. Fornberg の重み ---
+    println!("=== 1. Fornberg のアルゴリズムによる差分係数 ===");
+    for (label, stencil) in [
+        ("1階 中心 O(h²)", Stencil::central(1, 2)),
+        ("1階 中心 O(h⁴)", Stencil::central(1, 4)),
+        ("1階 中心 O(h⁶)", Stencil::central(1, 6)),
+        ("2階 中心 O(h²)", Stencil::central(2, 2)),
+        ("2階 中心 O(h⁴)", Stencil::central(2, 4)),
+        ("1階 前進 O(h²)", Stencil::forward(1, 2)),
+        ("3階 中心 O(h²)", Stencil::central(3, 2)),
+    ] {
+        let weights: Vec<String> = stencil
+            .offsets
+            .iter()
+            .zip(&stencil.weights)
+            .map(|(o, w)| format!("{:+}:{:.4}", o, w))
+            .collect();
+        println!(
+            "  {:<16} 次数 {}  [{}]",
+            label,
+            stencil.accuracy(),
+            weights.join(", ")
+        );
+    }
+    // 不等間隔の例: 点 {-1, 0, 0.5, 2} から x=0 の 1 階微分
+    let nonuniform = [-1.0, 0.0, 0.5, 2.0];
+    let w = &fornberg_weights(0.0, &nonuniform, 1)[1];
+    let approx: f64 = nonuniform
+        .iter()
+        .zip(w)
+        .map(|(&o, &wi)| wi * f(x0 + 0.01 * o))
+        .sum::<f64>()
+        / 0.01;
+    println!(
+        "  不等間隔 {{-1, 0, 0.5, 2}}·h の 1 階微分 (h=0.01): 誤差 {:.2e}",
+        (approx - x0.cos()).abs()
+    );
+
+    // --- 2. 刻み幅と誤差（diff_h.rs の拡張） ---
+    println!("\n=== 2. 刻み幅 h と誤差: d/dx sin x at x = 1 ===");
+    let exact = x0.cos();
+    let stencils: Vec<Stencil> = [2, 4, 6, 8]
+        .iter()
+        .map(|&p| Stencil::central(1, p))
+        .collect();
+    println!(
+        "  {:>8} {:>11} {:>11} {:>11} {:>11}",
+        "h", "O(h²)", "O(h⁴)", "O(h⁶)", "O(h⁸)"
+    );
+    let mut best = vec![(f64::INFINITY, 0.0); stencils.len()];
+    for k in 1..=12 {
+        let h = 10f64.powi(-k);
+        let errors: Vec<f64> = stencils
+            .iter()
+            .map(|s| (s.apply(&f, x0, h) - exact).abs())
+            .collect();
+        for (b, &e) in best.iter_mut().zip(&errors) {
+            if e < b.0 {
+                *b = (e, h);
+            }
+        }
+        println!(
+            "  {:>8.0e} {:>11.2e} {:>11.2e} {:>11.2e} {:>11.2e}",
+            h, errors[0], errors[1], errors[2], errors[3]
+        );
+    }
+    println!("  最小誤差と理論的な最適刻み h ~ ε^(1/(p+1)):");
+    for (p, (e, h)) in [2, 4, 6, 8].iter().zip(&best) {
+        println!(
+            "    O(h^{}): 最小誤差 {:.2e} (h = {:.0e}), ε^(1/{}) = {:.1e}",
+            p,
+            e,
+            h,
+            p + 1,
+            f64::EPSILON.powf(1.0 / (*p as f64 + 1.0))
+        );
+    }
+
+    // --- 3. Ridders の外挿と最適刻み幅 ---
+    println!("\n=== 3. Ridders の外挿・最適刻み幅・複素ステップ ===");
+    // Squire & Trapp (1998) の例: f(x) = e^x / √(sin³x + cos³x)
+    let g = |x: f64| x.exp() / (x.sin().powi(3) + x.cos().powi(3)).sqrt();
+    let gc = |z: Complex| z.exp() / (z.sin().powi(3) + z.cos().powi(3)).sqrt();
+    let dg = |x: f64| {
+        let (s, c) = (x.sin(), x.cos());
+        let q = s.powi(3) + c.powi(3);
+        g(x) * (1.0 - 1.5 * (s * s * c - c * c * s) / q)
+    };
+    println!(
+        "  {:<28} {:>22} {:>22} {:>22}",
+        "", "sin x at 1", "e^x/√(sin³+cos³) at 1.5", "e^x at 10"
+    );
+    let exp = |x: f64| x.exp();
+    let cases: [DiffCase; 3] = [
+        (&f, 1.0, 1f64.cos()),
+        (&g, 1.5, dg(1.5)),
+        (&exp, 10.0, 10f64.exp()),
+    ];
+    let rel = |v: f64, exact: f64| (v - exact).abs() / exact.abs();
+
+    let row = |label: &str, values: Vec<String>| {
+        println!(
+            "  {:<28} {:>22} {:>22} {:>22}",
+            label, values[0], values[1], values[2]
+        );
+    };
+    row(
+        "中心差分 h=1e-5",
+        cases
+            .iter()
+            .map(|(f, x, e)| format!("{:.2e}", rel(Stencil::central(1, 2).apply(f, *x, 1e-5), *e)))
+            .collect(),
+    );
+    row(
+        "最適刻み h*",
+        cases
+            .iter()
+            .map(|(f, x, _)| format!("h*={:.1e}", optimal_step(f, *x)))
+            .collect(),
+    );
+    row(
+        "最適刻みの中心差分",
+        cases
+            .iter()
+            .map(|(f, x, e)| format!("{:.2e}", rel(derivative(f, *x), *e)))
+            .collect(),
+    );
+    row(
+        "Ridders（真の誤差）",
+        cases
+            .iter()
+            .map(|(f, x, e)| format!("{:.2e}", rel(ridders(f, *x, 0.1).0, *e)))
+            .collect(),
+    );
+    row(
+        "Ridders（推定誤差）",
+        cases
+            .iter()
+            .map(|(f, x, e)| format!("{:.2e}", ridders(f, *x, 0.1).1 / e.abs()))
+            .collect(),
+    );
+    let cs = [
+        complex_step(&|z: Complex| z.sin(), 1.0),
+        complex_step(&gc, 1.5),
+        complex_step(&|z: Complex| z.exp(), 10.0),
+    ];
+    row(
+        "複素ステップ h=1e-20",
+        cs.iter()
+            .zip(&cases)
+            .map(|(v, (_, _, e))| format!("{:.2e}", rel(*v, *e)))
+            .collect(),
+    );
+
+    // --- 4. 勾配・ヤコビ行列・ヘッセ行列 ---
+    println!("\n=== 4. 勾配・ヤコビ行列・ヘッセ行列 ===");
+    // ch05 optimization/L_BFGS.rs の Rosenbrock 関数
+    let rosenbrock = |p: &[f64]| (1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0] * p[0]).powi(2);
+    let p = [-1.2, 1.0];
+    let grad_exact = [
+        -2.0 * (1.0 - p[0]) - 400.0 * p[0] * (p[1] - p[0] * p[0]),
+        200.0 * (p[1] - p[0] * p[0]),
+    ];
+    let hess_exact = vec![
+        vec![2.0 - 400.0 * (p[1] - 3.0 * p[0] * p[0]), -400.0 * p[0]],
+        vec![-400.0 * p[0], 200.0],
+    ];
+    let grad = gradient(&rosenbrock, &p);
+    println!(
+        "  Rosenbrock 勾配 at (-1.2, 1): ({:.8}, {:.8})  解析解との差 {:.2e}",
+        grad[0],
+        grad[1],
+        max_abs_diff(std::slice::from_ref(&grad), &[grad_exact.to_vec()])
+    );
+    let hess = hessian(&rosenbrock, &p);
+    println!(
+        "  Rosenbrock ヘッセ行列: [[{:.4}, {:.4}], [{:.4}, {:.4}]]  解析解との差 {:.2e}",
+        hess[0][0],
+        hess[0][1],
+        hess[1][0],
+        hess[1][1],
+        max_abs_diff(&hess, &hess_exact)
+    );
+
+    // ch05 multivariable_newton の連立方程式 F(x, y) = (x² + y² - 1, y - x²)
+    let system = |v: &[f64]| vec![v[0] * v[0] + v[1] * v[1] - 1.0, v[1] - v[0] * v[0]];
+    let v = [1.0, 2.0];
+    let jac = jacobian(&system, &v);
+    let jac_exact = vec![vec![2.0 * v[0], 2.0 * v[1]], vec![-2.0 * v[0], 1.0]];
+    println!(
+        "  連立方程式のヤコビ行列 at (1, 2): [[{:.6}, {:.6}], [{:.6}, {:.6}]]  差 {:.2e}",
+        jac[0][0],
+        jac[0][1],
+        jac[1][0],
+        jac[1][1],
+        max_abs_diff(&jac, &jac_exact)
+    );
+
+    // --- 5. ニュートン法に数値微分を渡す ---
+    println!("\n=== 5. 導関数を書かないニュートン法 ===");
+    // ch05 root_finding/newton.rs と同じ f(x) = x² - 2（df を数値微分で置き換える）
+    let h = |x: f64| x * x - 2.0;
+    let mut x = 1.0;
+    let mut converged = false;
+    for i in 0..20 {
+        let hx = h(x);
+        if hx.abs() < 1e-14 {
+            println!(
+                "  1 変数: x = {:.15} (反復 {}, √2 との差 {:.1e})",
+                x,
+                i,
+                (x - 2f64.sqrt()).abs()
+            );
+            converged = true;
+            break;
+        }
+        x -= hx / derivative(&h, x);
+    }
+    if !converged {
+        println!("  1 変数: 20 回で収束しませんでした (x = {:.15})", x);
+    }
+
+    // ch05 multivariable_newton と同じ連立方程式: J δ = -F をヤコビ行列の数値微分で解く
+    let mut v = vec![1.0, 2.0];
+    let mut converged = false;
+    for i in 0..50 {
+        let fv = system(&v);
+        if fv.iter().map(|r| r * r).sum::<f64>().sqrt() < 1e-12 {
+            println!(
+                "  連立方程式: (x, y) = ({:.10}, {:.10}) (反復 {})",
+                v[0], v[1], i
+            );
+            converged = true;
+            break;
+        }
+        let delta = solve_2x2(&jacobian(&system, &v), &[-fv[0], -fv[1]]);
+        v[0] += delta[0];
+        v[1] += delta[1];
+    }
+    if !converged {
+        println!(
+            "  連立方程式: 50 回で収束しませんでした ((x, y) = ({:.10}, {:.10}))",
+            v[0], v[1]
+        );
+    }
+
+    // Rosenbrock の最小化: 勾配とヘッセ行列を数値微分したニュートン法
+    let mut p = vec![-1.2, 1.0];
+    let mut converged = false;
+    for i in 0..100 {
+        let g = gradient(&rosenbrock, &p);
+        if g.iter().map(|gi| gi * gi).sum::<f64>().sqrt() < 1e-6 {
+            println!(
+                "  Rosenbrock 最小化: ({:.8}, {:.8}), f = {:.2e} (反復 {})",
+                p[0],
+                p[1],
+                rosenbrock(&p),
+                i
+            );
+            converged = true;
+            break;
+        }
+        let step = solve_2x2(&hessian(&rosenbrock, &p), &g);
+        // 単純な後退線探索（f が減るまでステップを半分にする）
+        let f0 = rosenbrock(&p);
+        let mut t = 1.0;
+        while t > 1e-8 && rosenbrock(&[p[0] - t * step[0], p[1] - t * step[1]]) > f0 {
+            t *= 0.5;
+        }
+        p[0] -= t * step[0];
+        p[1] -= t * step[1];
+    }
+    if !converged {
+        println!(
+            "  Rosenbrock 最小化: 100 回で収束しませんでした ({:.8}, {:.8}), f = {:.2e}",
+            p[0],
+            p[1],
+            rosenbrock(&p)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::cell::Cell;
+use std::f64::consts::PI;
+
+/// 積分結果：近似値・誤差推定・関数評価回数
+#[derive(Debug, Clone, Copy)]
+pub struct Estimate {
+    pub value: f64,
+    pub error: f64,
+    pub n_evals: usize,
+}
+
+/// 1次元積分器の共通インターフェース
+///
+/// 異なる手法を `Vec<Box<dyn Integrator>>` に並べて同じ被積分関数で比較できるよう、
+/// 関数はトレイトオブジェクト `&dyn Fn(f64) -> f64` で受け取ります。
+/// 区間の端は `f64::INFINITY` / `f64::NEG_INFINITY` でもよく、扱えない区間かどうかは
+/// 各積分器が判断します。
+pub trait Integrator {
+    fn name(&self) -> String;
+    fn integrate(&self, f: &dyn Fn(f64) -> f64, a: f64, b: f64) -> Estimate;
+}
+
+/// 関数評価回数を数えるラッパー
+pub struct Counted<'a> {
+    f: &'a dyn Fn(f64) -> f64,
+    count: Cell<usize>,
+}
+
+impl<'a> Counted<'a> {
+    pub fn new(f: &'a dyn Fn(f64) -> f64) -> Self {
+        Self {
+            f,
+            count: Cell::new(0),
+        }
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        self.count.set(self.count.get() + 1);
+        (self.f)(x)
+    }
+
+    /// これまでの評価回数
+    pub fn n_evals(&self) -> usize {
+        self.count.get()
+    }
+}
+
+/// ルジャンドル多項式 P_n(x) と導関数 P_n'(x) を漸化式で評価する
+fn legendre(n: usize, x: f64) -> (f64, f64) {
+    let mut p0 = 1.0;
+    let mut p1 = x;
+    if n == 0 {
+        return (1.0, 0.0);
+    }
+    for k in 2..=n {
+        let k = k as f64;
+        let p2 = ((2.0 * k - 1.0) * x * p1 - (k - 1.0) * p0) / k;
+        p0 = p1;
+        p1 = p2;
+    }
+    // P_n'(x) = n (x P_n - P_{n-1}) / (x² - 1)
+    let dp = n as f64 * (x * p1 - p0) / (x * x - 1.0);
+    (p1, dp)
+}
+
+/// [-1, 1] 上の n 点ガウス・ルジャンドル則の (節点, 重み)
+///
+/// ルジャンドル多項式の三項漸化式とニュートン法で任意の n について求めます。
+/// 節点は大きい順に並びます。
+pub fn gauss_legendre(n: usize) -> (Vec<f64>, Vec<f64>) {
+    assert!(n >= 1, "点数は 1 以上が必要です");
+    let mut nodes = vec![0.0; n];
+    let mut weights = vec![0.0; n];
+
+    // 根は ±対称なので正の側 (n+1)/2 個だけ求める
+    for i in 0..n.div_ceil(2) {
+        // チェビシェフ型の初期値（i 番目に大きい根の近似）
+        let mut x = (PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+        for _ in 0..100 {
+            let (p, dp) = legendre(n, x);
+            let dx = p / dp;
+            x -= dx;
+            if dx.abs() < 1e-16 {
+                break;
+            }
+        }
+        let (_, dp) = legendre(n, x);
+        let w = 2.0 / ((1.0 - x * x) * dp * dp);
+        nodes[i] = x;
+        nodes[n - 1 - i] = -x;
+        weights[i] = w;
+        weights[n - 1 - i] = w;
+    }
+    // 奇数点のとき中央の節点はちょうど 0
+    if n % 2 == 1 {
+        nodes[n / 2] = 0.0;
+    }
+
+    (nodes, weights)
+}
@@ -0,0 +1,705 @@
+use argmin::core::{CostFunction, Error, Executor, Gradient, Hessian, State};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::newton::Newton;
+use argmin::solver::quasinewton::LBFGS;
+use ndarray::{Array1, Array2, LinalgScalar, ScalarOperand, array};
+use ndarray_linalg::Solve;
+use num_traits::{One, Zero};
+use roots::{SimpleConvergency, find_root_newton_raphson};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// ----------------------------------------
+// 二重数・超二重数
+// ----------------------------------------
+
+/// 二重数 re + eps·ε（ε² = 0）
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Dual {
+    re: f64,
+    eps: f64,
+}
+
+impl Dual {
+    fn new(re: f64, eps: f64) -> Self {
+        Self { re, eps }
+    }
+
+    /// 微分する変数（ε の係数が 1）
+    fn variable(re: f64) -> Self {
+        Self::new(re, 1.0)
+    }
+
+    /// 連鎖律: f(a + bε) = f(a) + f'(a) b ε
+    fn chain(self, f: f64, df: f64) -> Self {
+        Self::new(f, df * self.eps)
+    }
+}
+
+/// 超二重数 re + e1·ε₁ + e2·ε₂ + e12·ε₁ε₂（ε₁² = ε₂² = 0）
+///
+/// x_i に ε₁、x_j に ε₂ を与えて評価すると、ε₁ε₂ の係数が ∂²f/∂x_i∂x_j になります。
+/// 差分を使わないので 2 階微分にも打ち切り誤差・桁落ちがありません。
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HyperDual {
+    re: f64,
+    e1: f64,
+    e2: f64,
+    e12: f64,
+}
+
+impl HyperDual {
+    fn new(re: f64, e1: f64, e2: f64, e12: f64) -> Self {
+        Self { re, e1, e2, e12 }
+    }
+
+    /// 連鎖律（2 階まで）:
+    /// f(a + bε₁ + cε₂ + dε₁ε₂) = f(a) + f'(a)(bε₁ + cε₂ + dε₁ε₂) + f''(a) bc ε₁ε₂
+    fn chain(self, f: f64, df: f64, d2f: f64) -> Self {
+        Self::new(
+            f,
+            df * self.e1,
+            df * self.e2,
+            df * self.e12 + d2f * self.e1 * self.e2,
+        )
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, o: Self) -> Self {
+        Self::new(self.re + o.re, self.eps + o.eps)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, o: Self) -> Self {
+        Self::new(self.re - o.re, self.eps - o.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, o: Self) -> Self {
+        Self::new(self.re * o.re, self.re * o.eps + self.eps * o.re)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, o: Self) -> Self {
+        Self::new(
+            self.re / o.re,
+            (self.eps * o.re - self.re * o.eps) / (o.re * o.re),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl Add for HyperDual {
+    type Output = Self;
+    fn add(self, o: Self) -> Self {
+        Self::new(
+            self.re + o.re,
+            self.e1 + o.e1,
+            self.e2 + o.e2,
+            self.e12 + o.e12,
+        )
+    }
+}
+
+impl Sub for HyperDual {
+    type Output = Self;
+    fn sub(self, o: Self) -> Self {
+        Self::new(
+            self.re - o.re,
+            self.e1 - o.e1,
+            self.e2 - o.e2,
+            self.e12 - o.e12,
+        )
+    }
+}
+
+impl Mul for HyperDual {
+    type Output = Self;
+    fn mul(self, o: Self) -> Self {
+        Self::new(
+            self.re * o.re,
+            self.re * o.e1 + self.e1 * o.re,
+            self.re * o.e2 + self.e2 * o.re,
+            self.re * o.e12 + self.e1 * o.e2 + self.e2 * o.e1 + self.e12 * o.re,
+        )
+    }
+}
+
+impl Div for HyperDual {
+    type Output = Self;
+    fn div(self, o: Self) -> Self {
+        // x / y = x · (1/y)。1/a の導関数は -1/a², 2 階は 2/a³
+        let inv = 1.0 / o.re;
+        self * o.chain(inv, -inv * inv, 2.0 * inv * inv * inv)
+    }
+}
+
+impl Neg for HyperDual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.e1, -self.e2, -self.e12)
+    }
+}
+
+/// f64 との混合演算と ndarray の要素として必要なトレイトをまとめて実装する
+macro_rules! impl_scalar_support {
+    ($t:ident) => {
+        impl Add<f64> for $t {
+            type Output = $t;
+            fn add(self, c: f64) -> $t {
+                self + $t::constant(c)
+            }
+        }
+        impl Sub<f64> for $t {
+            type Output = $t;
+            fn sub(self, c: f64) -> $t {
+                self - $t::constant(c)
+            }
+        }
+        impl Mul<f64> for $t {
+            type Output = $t;
+            fn mul(self, c: f64) -> $t {
+                self * $t::constant(c)
+            }
+        }
+        impl Div<f64> for $t {
+            type Output = $t;
+            fn div(self, c: f64) -> $t {
+                self / $t::constant(c)
+            }
+        }
+        impl Add<$t> for f64 {
+            type Output = $t;
+            fn add(self, x: $t) -> $t {
+                $t::constant(self) + x
+            }
+        }
+        impl Sub<$t> for f64 {
+            type Output = $t;
+            fn sub(self, x: $t) -> $t {
+                $t::constant(self) - x
+            }
+        }
+        impl Mul<$t> for f64 {
+            type Output = $t;
+            fn mul(self, x: $t) -> $t {
+                $t::constant(self) * x
+            }
+        }
+        impl Div<$t> for f64 {
+            type Output = $t;
+            fn div(self, x: $t) -> $t {
+                $t::constant(self) / x
+            }
+        }
+        // Array::zeros, sum, dot などに必要
+        impl Zero for $t {
+            fn zero() -> Self {
+                $t::constant(0.0)
+            }
+            fn is_zero(&self) -> bool {
+                *self == Self::zero()
+            }
+        }
+        impl One for $t {
+            fn one() -> Self {
+                $t::constant(1.0)
+            }
+        }
+        // 配列 × スカラー（&a * x）に必要
+        impl ScalarOperand for $t {}
+    };
+}
+
+impl_scalar_support!(Dual);
+impl_scalar_support!(HyperDual);
+
+// ----------------------------------------
+// 目的関数を書くための共通インターフェース
+// ----------------------------------------
+
+/// f64・二重数・超二重数を同じように扱うためのトレイト
+///
+/// 関数を `fn f<T: Scalar>(x: T) -> T` の形で一度書けば、
+/// T = f64 で値、T = Dual で 1 階微分、T = HyperDual で 2 階微分が得られます。
+/// `LinalgScalar` を要求するので `Array1<T>` の `dot` や `sum` も使えます。
+trait Scalar:
+    LinalgScalar
+    + ScalarOperand
+    + Neg<Output = Self>
+    + Add<f64, Output = Self>
+    + Sub<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+{
+    fn constant(c: f64) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn atan(self) -> Self;
+}
+
+impl Scalar for f64 {
+    fn constant(c: f64) -> Self {
+        c
+    }
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+    fn atan(self) -> Self {
+        f64::atan(self)
+    }
+}
+
+impl Scalar for Dual {
+    fn constant(c: f64) -> Self {
+        Self::new(c, 0.0)
+    }
+    fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+    fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e)
+    }
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), 1.0 / self.re)
+    }
+    fn sqrt(self) -> Self {
+        let r = self.re.sqrt();
+        self.chain(r, 0.5 / r)
+    }
+    fn powi(self, n: i32) -> Self {
+        // n = 0 で 0 · re^(-1) が re = 0 のとき NaN になるのを避ける
+        match n {
+            0 => Self::constant(1.0),
+            _ => self.chain(self.re.powi(n), n as f64 * self.re.powi(n - 1)),
+        }
+    }
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), 1.0 / (1.0 + self.re * self.re))
+    }
+}
+
+impl Scalar for HyperDual {
+    fn constant(c: f64) -> Self {
+        Self::new(c, 0.0, 0.0, 0.0)
+    }
+    fn sin(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        self.chain(s, c, -s)
+    }
+    fn cos(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        self.chain(c, -s, -c)
+    }
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e, e)
+    }
+    fn ln(self) -> Self {
+        let inv = 1.0 / self.re;
+        self.chain(self.re.ln(), inv, -inv * inv)
+    }
+    fn sqrt(self) -> Self {
+        let r = self.re.sqrt();
+        self.chain(r, 0.5 / r, -0.25 / (r * self.re))
+    }
+    fn powi(self, n: i32) -> Self {
+        // n = 0, 1 では係数 0 の項に re の負冪が掛かり、re = 0 のとき NaN になる
+        match n {
+            0 => return Self::constant(1.0),
+            1 => return self,
+            _ => {}
+        }
+        let nf = n as f64;
+        self.chain(
+            self.re.powi(n),
+            nf * self.re.powi(n - 1),
+            nf * (nf - 1.0) * self.re.powi(n - 2),
+        )
+    }
+    fn atan(self) -> Self {
+        let q = 1.0 / (1.0 + self.re * self.re);
+        self.chain(self.re.atan(), q, -2.0 * self.re * q * q)
+    }
+}
+
+// ----------------------------------------
+// 微分の計算
+// ----------------------------------------
+
+/// スカラー関数の値と導関数 (f(x), f'(x))
+fn derivative<F: Fn(Dual) -> Dual>(f: F, x: f64) -> (f64, f64) {
+    let y = f(Dual::variable(x));
+    (y.re, y.eps)
+}
+
+/// スカラー関数の値と 1 階・2 階微分 (f(x), f'(x), f''(x))
+fn second_derivative<F: Fn(HyperDual) -> HyperDual>(f: F, x: f64) -> (f64, f64, f64) {
+    let y = f(HyperDual::new(x, 1.0, 1.0, 0.0));
+    (y.re, y.e1, y.e12)
+}
+
+/// 勾配 ∇f(x): 方向ごとに 1 回ずつ、計 n 回の評価
+fn gradient<F: Fn(&Array1<Dual>) -> Dual>(f: F, x: &Array1<f64>) -> Array1<f64> {
+    let mut xd = x.mapv(Dual::constant);
+    Array1::from_shape_fn(x.len(), |i| {
+        xd[i].eps = 1.0;
+        let g = f(&xd).eps;
+        xd[i].eps = 0.0;
+        g
+    })
+}
+
+/// ヤコビ行列 J[i][j] = ∂F_i/∂x_j: 列ごとに 1 回ずつ評価
+fn jacobian<F: Fn(&Array1<Dual>) -> Array1<Dual>>(f: F, x: &Array1<f64>) -> Array2<f64> {
+    let n = x.len();
+    let mut xd = x.mapv(Dual::constant);
+    let columns: Vec<Array1<Dual>> = (0..n)
+        .map(|j| {
+            xd[j].eps = 1.0;
+            let col = f(&xd);
+            xd[j].eps = 0.0;
+            col
+        })
+        .collect();
+    Array2::from_shape_fn((columns[0].len(), n), |(i, j)| columns[j][i].eps)
+}
+
+/// ヘッセ行列: 上三角の各 (i, j) について x_i に ε₁、x_j に ε₂ を与えて評価
+fn hessian<F: Fn(&Array1<HyperDual>) -> HyperDual>(f: F, x: &Array1<f64>) -> Array2<f64> {
+    let n = x.len();
+    let mut hess = Array2::zeros((n, n));
+    let mut xh = x.mapv(HyperDual::constant);
+    for i in 0..n {
+        for j in i..n {
+            xh[i].e1 = 1.0;
+            xh[j].e2 = 1.0;
+            let h = f(&xh).e12;
+            hess[[i, j]] = h;
+            hess[[j, i]] = h;
+            xh[i].e1 = 0.0;
+            xh[j].e2 = 0.0;
+        }
+    }
+    hess
+}
+
+// ----------------------------------------
+// argmin との接続
+// ----------------------------------------
+
+/// 任意の Scalar 型で評価できる目的関数
+trait Objective {
+    fn eval<T: Scalar>(&self, p: &Array1<T>) -> T;
+}
+
+/// Objective から argmin の CostFunction・Gradient・Hessian を自動で実装するラッパー
+struct AutoDiff<O>(O);
+
+impl<O: Objective> CostFunction for AutoDiff<O> {
+    type Param = Array1<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self.0.eval(p))
+    }
+}
+
+impl<O: Objective> Gradient for AutoDiff<O> {
+    type Param = Array1<f64>;
+    type Gradient = Array1<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(gradient(|q| self.0.eval(q), p))
+    }
+}
+
+impl<O: Objective> Hessian for AutoDiff<O> {
+    type Param = Array1<f64>;
+    type Hessian = Array2<f64>;
+
+    fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+        Ok(hessian(|q| self.0.eval(q), p))
+    }
+}
+
+/// L_BFGS.rs と同じ Rosenbrock 関数（勾配は書かない）
+struct Rosenbrock;
+
+impl Objective for Rosenbrock {
+    fn eval<T: Scalar>(&self, p: &Array1<T>) -> T {
+        let (x, y) = (p[0], p[1]);
+        (-x + 1.0).powi(2) + (y - x.powi(2)).powi(2) * 100.0
+    }
+}
+
+/// n 次元の拡張 Rosenbrock 関数 Σ [100 (x_{i+1} - x_i²)² + (1 - x_i)²]
+struct ExtendedRosenbrock;
+
+impl Objective for ExtendedRosenbrock {
+    fn eval<T: Scalar>(&self, p: &Array1<T>) -> T {
+        (0..p.len() - 1).fold(T::zero(), |acc, i| {
+            acc + (p[i + 1] - p[i].powi(2)).powi(2) * 100.0 + (-p[i] + 1.0).powi(2)
+        })
+    }
+}
+
+/// 指数減衰 y = a e^{-b t} の最小二乗フィット。配列演算（mapv, dot）で書いた目的関数の例
+struct ExponentialFit {
+    t: Array1<f64>,
+    y: Array1<f64>,
+}
+
+impl Objective for ExponentialFit {
+    fn eval<T: Scalar>(&self, p: &Array1<T>) -> T {
+        let (a, b) = (p[0], p[1]);
+        let model: Array1<T> = self.t.mapv(|ti| a * (-(b * ti)).exp());
+        let residual = &model - &self.y.mapv(T::constant);
+        residual.dot(&residual) * 0.5
+    }
+}
+
+fn main() {
+    // ----------------------------------------
+    // 1. 導関数・2 階微分
+    // ----------------------------------------
+    println!("=== 1. 二重数・超二重数による微分 ===");
+    // f(x) = e^x / √(sin³x + cos³x)（Squire & Trapp の例）
+    fn g<T: Scalar>(x: T) -> T {
+        x.exp() / (x.sin().powi(3) + x.cos().powi(3)).sqrt()
+    }
+    let x0 = 1.5;
+    let (s, c) = (x0.sin(), x0.cos());
+    let q = s.powi(3) + c.powi(3);
+    let dg_exact = g(x0) * (1.0 - 1.5 * (s * s * c - c * c * s) / q);
+    let (value, dg) = derivative(g, x0);
+    println!(
+        "f(1.5) = {:.15}, f'(1.5) = {:.15} (解析解との相対誤差 {:.1e})",
+        value,
+        dg,
+        (dg - dg_exact).abs() / dg_exact.abs()
+    );
+    // h(x) = atan(x) ln(1 + x²), h'(x) = [ln(1 + x²) + 2x atan(x)] / (1 + x²)
+    fn h<T: Scalar>(x: T) -> T {
+        x.atan() * (x * x + 1.0).ln()
+    }
+    let dh_exact = ((1.0 + x0 * x0).ln() + 2.0 * x0 * x0.atan()) / (1.0 + x0 * x0);
+    println!(
+        "h'(1.5) = {:.15} (解析解との相対誤差 {:.1e})",
+        derivative(h, x0).1,
+        (derivative(h, x0).1 - dh_exact).abs() / dh_exact
+    );
+    // 2 階微分: 超二重数と二重数の結果の中心差分を比較
+    let (_, d1, d2) = second_derivative(g, x0);
+    let h = 1e-5;
+    let d2_fd = (derivative(g, x0 + h).1 - derivative(g, x0 - h).1) / (2.0 * h);
+    println!(
+        "f'(1.5) = {:.15} (超二重数), f''(1.5) = {:.12}（f' の差分 {:.12}）",
+        d1, d2, d2_fd
+    );
+
+    // ----------------------------------------
+    // 2. newton.rs: roots のニュートン法に自動微分の導関数を渡す
+    // ----------------------------------------
+    println!("\n=== 2. ニュートン法（導関数は自動微分） ===");
+    fn f<T: Scalar>(x: T) -> T {
+        x * x - 2.0
+    }
+    let mut convergency = SimpleConvergency {
+        eps: 1e-15f64,
+        max_iter: 100,
+    };
+    // find_root_newton_raphson(初期値, 関数, 導関数, 収束条件)
+    let root = find_root_newton_raphson(
+        1.0,
+        |x| derivative(f, x).0,
+        |x| derivative(f, x).1,
+        &mut convergency,
+    );
+    match root {
+        Ok(x) => println!(
+            "解: x = {:.15} (√2 との差 {:.1e})",
+            x,
+            (x - 2f64.sqrt()).abs()
+        ),
+        Err(e) => println!("エラー: {:?}", e),
+    }
+
+    // ----------------------------------------
+    // 3. multivariable_newton: ヤコビ行列を自動微分
+    // ----------------------------------------
+    println!("\n=== 3. 多変数ニュートン法（ヤコビ行列は自動微分） ===");
+    fn system<T: Scalar>(v: &Array1<T>) -> Array1<T> {
+        let (x, y) = (v[0], v[1]);
+        Array1::from(vec![x.powi(2) + y.powi(2) - 1.0, y - x.powi(2)])
+    }
+    let mut v = array![1.0, 2.0];
+    let j0 = jacobian(system, &v);
+    println!("J(1, 2) = {}", j0);
+    let mut converged = false;
+    for i in 0..100 {
+        let fv = system(&v);
+        if fv.dot(&fv).sqrt() < 1e-12 {
+            println!("解: x = {:.10}, y = {:.10} (反復 {})", v[0], v[1], i);
+            converged = true;
+            break;
+        }
+        let delta = jacobian(system, &v)
+            .solve(&(-fv))
+            .expect("Singular Jacobian");
+        v = v + delta;
+    }
+    if !converged {
+        println!(
+            "100 回で収束しませんでした (x = {:.10}, y = {:.10})",
+            v[0], v[1]
+        );
+    }
+
+    // ----------------------------------------
+    // 4. argmin の L-BFGS・ニュートン法に自動微分の勾配・ヘッセ行列を渡す
+    // ----------------------------------------
+    println!("\n=== 4. argmin との接続 ===");
+    let p0 = array![-1.2, 1.0];
+    let auto = AutoDiff(Rosenbrock);
+    let g_auto = auto.gradient(&p0).unwrap();
+    // L_BFGS.rs の手書きの勾配
+    let (x, y) = (p0[0], p0[1]);
+    let g_hand = array![
+        -2.0 * (1.0 - x) - 400.0 * x * (y - x.powi(2)),
+        200.0 * (y - x.powi(2))
+    ];
+    println!(
+        "Rosenbrock 勾配: 自動微分 {} / 手書き {} (差 {:.1e})",
+        g_auto,
+        g_hand,
+        (&g_auto - &g_hand).mapv(f64::abs).sum()
+    );
+    println!("ヘッセ行列: {}", auto.hessian(&p0).unwrap());
+
+    let res = Executor::new(
+        AutoDiff(Rosenbrock),
+        LBFGS::new(MoreThuenteLineSearch::new(), 7),
+    )
+    .configure(|state| state.param(p0.clone()).max_iters(100).target_cost(1e-10))
+    .run()
+    .expect("Optimization failed");
+    println!(
+        "L-BFGS:       x = {:.8}, f = {:.2e} (反復 {})",
+        res.state().get_best_param().unwrap(),
+        res.state().get_best_cost(),
+        res.state().get_iter()
+    );
+
+    let res = Executor::new(AutoDiff(Rosenbrock), Newton::new())
+        .configure(|state| state.param(p0.clone()).max_iters(50).target_cost(1e-20))
+        .run()
+        .expect("Optimization failed");
+    println!(
+        "ニュートン法: x = {:.8}, f = {:.2e} (反復 {})",
+        res.state().get_best_param().unwrap(),
+        res.state().get_best_cost(),
+        res.state().get_iter()
+    );
+
+    // 10 次元の拡張 Rosenbrock（勾配は 10 方向の二重数評価で得る）
+    let n = 10;
+    let start = Array1::from_shape_fn(n, |i| if i % 2 == 0 { -1.2 } else { 1.0 });
+    let res = Executor::new(
+        AutoDiff(ExtendedRosenbrock),
+        LBFGS::new(MoreThuenteLineSearch::new(), 7),
+    )
+    .configure(|state| state.param(start).max_iters(500).target_cost(1e-12))
+    .run()
+    .expect("Optimization failed");
+    println!(
+        "拡張 Rosenbrock (n = {}): f = {:.2e}, max|x - 1| = {:.1e} (反復 {})",
+        n,
+        res.state().get_best_cost(),
+        res.state()
+            .get_best_param()
+            .unwrap()
+            .iter()
+            .map(|xi| (xi - 1.0).abs())
+            .fold(0.0, f64::max),
+        res.state().get_iter()
+    );
+
+    // ----------------------------------------
+    // 5. 配列演算で書いた目的関数（ndarray の要素としての二重数）
+    // ----------------------------------------
+    println!("\n=== 5. 指数減衰のフィット（mapv と dot で書いた目的関数） ===");
+    let (a_true, b_true) = (2.5, 1.3);
+    let t = Array1::linspace(0.0, 4.0, 41);
+    // 決定的な小さいノイズ
+    let y = Array1::from_shape_fn(t.len(), |i| {
+        a_true * (-b_true * t[i]).exp() + 0.01 * (7.0 * i as f64).sin()
+    });
+    let fit = ExponentialFit { t, y };
+    let p_init = array![1.0, 0.5];
+    let h_auto = hessian(|q| fit.eval(q), &p_init);
+    // 自動微分のヘッセ行列を勾配の中心差分と比較
+    let eps = 1e-6;
+    let h_fd = Array2::from_shape_fn((2, 2), |(i, j)| {
+        let mut pp = p_init.clone();
+        let mut pm = p_init.clone();
+        pp[j] += eps;
+        pm[j] -= eps;
+        (gradient(|q| fit.eval(q), &pp)[i] - gradient(|q| fit.eval(q), &pm)[i]) / (2.0 * eps)
+    });
+    println!(
+        "ヘッセ行列（超二重数と勾配の差分の最大差 {:.1e}）",
+        (&h_auto - &h_fd)
+            .mapv(f64::abs)
+            .fold(0.0, |m: f64, v| m.max(*v))
+    );
+    let res = Executor::new(AutoDiff(fit), LBFGS::new(MoreThuenteLineSearch::new(), 7))
+        .configure(|state| state.param(p_init).max_iters(200))
+        .run()
+        .expect("Optimization failed");
+    let p = res.state().get_best_param().unwrap();
+    println!(
+        "a = {:.6}, b = {:.6}（真値 a = {}, b = {}）, 残差二乗和/2 = {:.3e}",
+        p[0],
+        p[1],
+        a_true,
+        b_true,
+        res.state().get_best_cost()
+    );
+}
@@ -0,0 +1,577 @@
+use ndarray::Array1;
+use sprs::{CsMat, TriMat};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// 要素の次数
+#[derive(Clone, Copy, PartialEq)]
+enum Order {
+    /// 1次要素（頂点のみに自由度）
+    P1,
+    /// 2次要素（頂点 + 辺の中点に自由度）
+    P2,
+}
+
+/// 三角形メッシュ
+struct Mesh {
+    nodes: Vec<[f64; 2]>,
+    /// 要素の節点番号（P1: 頂点3つ、P2: 頂点3つ + 辺 01, 12, 20 の中点）
+    elements: Vec<Vec<usize>>,
+    /// 境界辺の節点番号（P1: 両端、P2: 両端 + 中点）
+    /// 要素の反時計回りの向きを保つので、外向き法線は (dy, -dx) となる
+    boundary_edges: Vec<Vec<usize>>,
+    order: Order,
+}
+
+impl Mesh {
+    /// 長方形 [0, lx] × [0, ly] を nx × ny のセルに分け、各セルを2つの三角形に分割する
+    /// keep(x, y) が false を返すセル（中心座標で判定）は取り除く（L字領域などに使う）
+    fn structured<F>(nx: usize, ny: usize, lx: f64, ly: f64, order: Order, keep: F) -> Self
+    where
+        F: Fn(f64, f64) -> bool,
+    {
+        let (hx, hy) = (lx / nx as f64, ly / ny as f64);
+        let grid_id = |i: usize, j: usize| j * (nx + 1) + i;
+
+        // 1. 格子点番号で三角形を作る（反時計回り）
+        let mut triangles = Vec::new();
+        for j in 0..ny {
+            for i in 0..nx {
+                if !keep((i as f64 + 0.5) * hx, (j as f64 + 0.5) * hy) {
+                    continue;
+                }
+                let (a, b) = (grid_id(i, j), grid_id(i + 1, j));
+                let (c, d) = (grid_id(i + 1, j + 1), grid_id(i, j + 1));
+                triangles.push([a, b, c]);
+                triangles.push([a, c, d]);
+            }
+        }
+
+        // 2. 実際に使われる格子点だけを節点として番号を振り直す
+        let mut renumber = HashMap::new();
+        let mut nodes = Vec::new();
+        for tri in &triangles {
+            for &g in tri {
+                renumber.entry(g).or_insert_with(|| {
+                    let (i, j) = (g % (nx + 1), g / (nx + 1));
+                    nodes.push([i as f64 * hx, j as f64 * hy]);
+                    nodes.len() - 1
+                });
+            }
+        }
+        let triangles: Vec<[usize; 3]> = triangles
+            .iter()
+            .map(|t| [renumber[&t[0]], renumber[&t[1]], renumber[&t[2]]])
+            .collect();
+
+        // 3. 辺の数え上げ: 1つの要素にしか属さない辺が境界辺
+        let key = |a: usize, b: usize| (a.min(b), a.max(b));
+        let mut edge_count: HashMap<(usize, usize), usize> = HashMap::new();
+        for t in &triangles {
+            for k in 0..3 {
+                *edge_count.entry(key(t[k], t[(k + 1) % 3])).or_insert(0) += 1;
+            }
+        }
+
+        // 4. P2 要素では辺の中点に節点を追加する（隣接要素で共有）
+        let mut midpoint: HashMap<(usize, usize), usize> = HashMap::new();
+        if order == Order::P2 {
+            let mut edges: Vec<_> = edge_count.keys().copied().collect();
+            edges.sort();
+            for (a, b) in edges {
+                let (pa, pb) = (nodes[a], nodes[b]);
+                nodes.push([0.5 * (pa[0] + pb[0]), 0.5 * (pa[1] + pb[1])]);
+                midpoint.insert((a, b), nodes.len() - 1);
+            }
+        }
+
+        let mut elements = Vec::new();
+        let mut boundary_edges = Vec::new();
+        for t in &triangles {
+            let mut element = t.to_vec();
+            for k in 0..3 {
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+                let mut edge = vec![a, b];
+                if order == Order::P2 {
+                    element.push(midpoint[&key(a, b)]);
+                    edge.push(midpoint[&key(a, b)]);
+                }
+                if edge_count[&key(a, b)] == 1 {
+                    boundary_edges.push(edge);
+                }
+            }
+            elements.push(element);
+        }
+
+        Self {
+            nodes,
+            elements,
+            boundary_edges,
+            order,
+        }
+    }
+
+    /// 最大の要素サイズ（辺長）
+    fn h_max(&self) -> f64 {
+        let mut h: f64 = 0.0;
+        for e in &self.elements {
+            for k in 0..3 {
+                let (p, q) = (self.nodes[e[k]], self.nodes[e[(k + 1) % 3]]);
+                h = h.max(((p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2)).sqrt());
+            }
+        }
+        h
+    }
+}
+
+/// 三角形上の4次精度 Dunavant 求積則（重心座標, 重み）。重みの和は 1
+fn triangle_quadrature() -> Vec<([f64; 3], f64)> {
+    let (a, wa) = (0.445_948_490_915_965, 0.223_381_589_678_011);
+    let (b, wb) = (0.091_576_213_509_771, 0.109_951_743_655_322);
+    let mut rule = Vec::new();
+    for (s, w) in [(a, wa), (b, wb)] {
+        let t = 1.0 - 2.0 * s;
+        rule.push(([s, s, t], w));
+        rule.push(([s, t, s], w));
+        rule.push(([t, s, s], w));
+    }
+    rule
+}
+
+/// 区間 [0, 1] 上の3点ガウス・ルジャンドル求積則（点, 重み）
+fn line_quadrature() -> [(f64, f64); 3] {
+    let s = (0.6_f64).sqrt();
+    [
+        (0.5 * (1.0 - s), 5.0 / 18.0),
+        (0.5, 8.0 / 18.0),
+        (0.5 * (1.0 + s), 5.0 / 18.0),
+    ]
+}
+
+/// 要素の幾何情報: 面積と重心座標の勾配 ∇λ_i（アフィン写像なので要素内で一定）
+fn element_geometry(p: [[f64; 2]; 3]) -> (f64, [[f64; 2]; 3]) {
+    let det = (p[1][0] - p[0][0]) * (p[2][1] - p[0][1]) - (p[2][0] - p[0][0]) * (p[1][1] - p[0][1]);
+    let grad = [
+        [(p[1][1] - p[2][1]) / det, (p[2][0] - p[1][0]) / det],
+        [(p[2][1] - p[0][1]) / det, (p[0][0] - p[2][0]) / det],
+        [(p[0][1] - p[1][1]) / det, (p[1][0] - p[0][0]) / det],
+    ];
+    (0.5 * det.abs(), grad)
+}
+
+/// 重心座標 λ での基底関数の値と勾配
+fn shape_functions(order: Order, l: [f64; 3], gl: [[f64; 2]; 3]) -> (Vec<f64>, Vec<[f64; 2]>) {
+    match order {
+        Order::P1 => (l.to_vec(), gl.to_vec()),
+        Order::P2 => {
+            let mut phi = Vec::with_capacity(6);
+            let mut grad = Vec::with_capacity(6);
+            // 頂点: λ_i (2λ_i - 1)
+            for i in 0..3 {
+                phi.push(l[i] * (2.0 * l[i] - 1.0));
+                let c = 4.0 * l[i] - 1.0;
+                grad.push([c * gl[i][0], c * gl[i][1]]);
+            }
+            // 辺の中点: 4 λ_i λ_j
+            for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+                phi.push(4.0 * l[i] * l[j]);
+                grad.push([
+                    4.0 * (l[i] * gl[j][0] + l[j] * gl[i][0]),
+                    4.0 * (l[i] * gl[j][1] + l[j] * gl[i][1]),
+                ]);
+            }
+            (phi, grad)
+        }
+    }
+}
+
+/// 1次元の基底関数（区間 [0, 1] 上の局所座標 s）: P1 は両端、P2 は両端 + 中点
+fn shape_functions_1d(order: Order, s: f64) -> (Vec<f64>, Vec<f64>) {
+    match order {
+        Order::P1 => (vec![1.0 - s, s], vec![-1.0, 1.0]),
+        Order::P2 => (
+            vec![
+                (1.0 - s) * (1.0 - 2.0 * s),
+                s * (2.0 * s - 1.0),
+                4.0 * s * (1.0 - s),
+            ],
+            vec![4.0 * s - 3.0, 4.0 * s - 1.0, 4.0 - 8.0 * s],
+        ),
+    }
+}
+
+/// 2次元ポアソン問題の境界値
+struct BoundaryData<'a> {
+    /// 境界辺がディリクレ境界かどうか（辺の中点で判定）
+    is_dirichlet: &'a dyn Fn(f64, f64) -> bool,
+    /// ディリクレ境界値 u = g_D
+    dirichlet: &'a dyn Fn(f64, f64) -> f64,
+    /// ノイマン境界値 ∂u/∂n = g_N（引数は座標と外向き単位法線）
+    neumann: &'a dyn Fn(f64, f64, [f64; 2]) -> f64,
+}
+
+/// 要素行列・ベクトルを組み立てて K u = F を解く
+fn solve_poisson(
+    mesh: &Mesh,
+    f: &dyn Fn(f64, f64) -> f64,
+    bc: &BoundaryData,
+) -> (Array1<f64>, usize) {
+    let n = mesh.nodes.len();
+
+    // ディリクレ節点とその値（節点補間）
+    let mut fixed: Vec<Option<f64>> = vec![None; n];
+    for edge in &mesh.boundary_edges {
+        let (pa, pb) = (mesh.nodes[edge[0]], mesh.nodes[edge[1]]);
+        if (bc.is_dirichlet)(0.5 * (pa[0] + pb[0]), 0.5 * (pa[1] + pb[1])) {
+            for &i in edge {
+                let p = mesh.nodes[i];
+                fixed[i] = Some((bc.dirichlet)(p[0], p[1]));
+            }
+        }
+    }
+
+    let mut triplet = TriMat::new((n, n));
+    let mut rhs = Array1::<f64>::zeros(n);
+    let quad = triangle_quadrature();
+
+    for e in &mesh.elements {
+        let p = [mesh.nodes[e[0]], mesh.nodes[e[1]], mesh.nodes[e[2]]];
+        let (area, gl) = element_geometry(p);
+        let m = e.len();
+        let mut k_local = vec![vec![0.0; m]; m];
+        let mut f_local = vec![0.0; m];
+
+        for &(l, w) in &quad {
+            let (phi, grad) = shape_functions(mesh.order, l, gl);
+            let x = l[0] * p[0][0] + l[1] * p[1][0] + l[2] * p[2][0];
+            let y = l[0] * p[0][1] + l[1] * p[1][1] + l[2] * p[2][1];
+            let fx = f(x, y);
+            for a in 0..m {
+                f_local[a] += w * area * fx * phi[a];
+                for b in 0..m {
+                    k_local[a][b] += w * area * (grad[a][0] * grad[b][0] + grad[a][1] * grad[b][1]);
+                }
+            }
+        }
+
+        // 全体行列への足し込み
+        // ディリクレ節点の列は既知の値として右辺へ移項し、行列の対称性を保つ
+        for a in 0..m {
+            let i = e[a];
+            if fixed[i].is_some() {
+                continue;
+            }
+            rhs[i] += f_local[a];
+            for b in 0..m {
+                let j = e[b];
+                match fixed[j] {
+                    Some(g) => rhs[i] -= k_local[a][b] * g,
+                    None => triplet.add_triplet(i, j, k_local[a][b]),
+                }
+            }
+        }
+    }
+
+    // ノイマン境界: ∫ g_N φ_i ds
+    for edge in &mesh.boundary_edges {
+        let (pa, pb) = (mesh.nodes[edge[0]], mesh.nodes[edge[1]]);
+        let (dx, dy) = (pb[0] - pa[0], pb[1] - pa[1]);
+        let len = (dx * dx + dy * dy).sqrt();
+        if (bc.is_dirichlet)(0.5 * (pa[0] + pb[0]), 0.5 * (pa[1] + pb[1])) {
+            continue;
+        }
+        let normal = [dy / len, -dx / len];
+        for (s, w) in line_quadrature() {
+            let (phi, _) = shape_functions_1d(mesh.order, s);
+            let g = (bc.neumann)(pa[0] + s * dx, pa[1] + s * dy, normal);
+            for (a, &i) in edge.iter().enumerate() {
+                if fixed[i].is_none() {
+                    rhs[i] += w * len * g * phi[a];
+                }
+            }
+        }
+    }
+
+    // ディリクレ節点の行は恒等式 u_i = g_i
+    for (i, value) in fixed.iter().enumerate() {
+        if let Some(g) = value {
+            triplet.add_triplet(i, i, 1.0);
+            rhs[i] = *g;
+        }
+    }
+
+    let k = triplet.to_csr::<usize>();
+    conjugate_gradient(&k, &rhs, 1e-12, 10 * n)
+}
+
+/// 前処理付き共役勾配法（ヤコビ前処理）
+/// 剛性行列は対称正定値なので CG 法で解ける
+fn conjugate_gradient(
+    a: &CsMat<f64>,
+    b: &Array1<f64>,
+    tol: f64,
+    max_iter: usize,
+) -> (Array1<f64>, usize) {
+    let n = b.len();
+    let mut inv_diag = Array1::<f64>::ones(n);
+    for (i, row) in a.outer_iterator().enumerate() {
+        if let Some(&d) = row.get(i) {
+            inv_diag[i] = 1.0 / d;
+        }
+    }
+
+    let mut x = Array1::<f64>::zeros(n);
+    let mut r = b.clone();
+    let mut z = &r * &inv_diag;
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+    let b_norm = b.dot(b).sqrt().max(1e-300);
+
+    for iter in 0..max_iter {
+        let ap = a * &p;
+        let alpha = rz / p.dot(&ap);
+        x.scaled_add(alpha, &p);
+        r.scaled_add(-alpha, &ap);
+        if r.dot(&r).sqrt() / b_norm < tol {
+            return (x, iter + 1);
+        }
+        z = &r * &inv_diag;
+        let rz_new = r.dot(&z);
+        p = &z + &(rz_new / rz * &p);
+        rz = rz_new;
+    }
+    (x, max_iter)
+}
+
+/// L2 誤差と H1 半ノルム誤差
+fn errors_2d(
+    mesh: &Mesh,
+    u_h: &Array1<f64>,
+    exact: &dyn Fn(f64, f64) -> f64,
+    exact_grad: &dyn Fn(f64, f64) -> [f64; 2],
+) -> (f64, f64) {
+    let (mut l2, mut h1) = (0.0, 0.0);
+    for e in &mesh.elements {
+        let p = [mesh.nodes[e[0]], mesh.nodes[e[1]], mesh.nodes[e[2]]];
+        let (area, gl) = element_geometry(p);
+        for (l, w) in triangle_quadrature() {
+            let (phi, grad) = shape_functions(mesh.order, l, gl);
+            let x = l[0] * p[0][0] + l[1] * p[1][0] + l[2] * p[2][0];
+            let y = l[0] * p[0][1] + l[1] * p[1][1] + l[2] * p[2][1];
+            let mut uh = 0.0;
+            let mut guh = [0.0; 2];
+            for (a, &i) in e.iter().enumerate() {
+                uh += u_h[i] * phi[a];
+                guh[0] += u_h[i] * grad[a][0];
+                guh[1] += u_h[i] * grad[a][1];
+            }
+            let g = exact_grad(x, y);
+            l2 += w * area * (uh - exact(x, y)).powi(2);
+            h1 += w * area * ((guh[0] - g[0]).powi(2) + (guh[1] - g[1]).powi(2));
+        }
+    }
+    (l2.sqrt(), h1.sqrt())
+}
+
+/// 1次元問題 -u'' = f (0 < x < 1), u(0) = u_0 (ディリクレ), u'(1) = g (ノイマン)
+/// n 個の要素で解き、L2 誤差と H1 半ノルム誤差を返す
+fn solve_1d(
+    n: usize,
+    order: Order,
+    f: &dyn Fn(f64) -> f64,
+    exact: &dyn Fn(f64) -> f64,
+    exact_deriv: &dyn Fn(f64) -> f64,
+) -> (f64, f64) {
+    let h = 1.0 / n as f64;
+    // 節点: P1 は要素の端点、P2 は端点 + 中点（中点は端点の後ろに番号付け）
+    let n_nodes = match order {
+        Order::P1 => n + 1,
+        Order::P2 => 2 * n + 1,
+    };
+    let element = |k: usize| match order {
+        Order::P1 => vec![k, k + 1],
+        Order::P2 => vec![k, k + 1, n + 1 + k],
+    };
+
+    let mut triplet = TriMat::new((n_nodes, n_nodes));
+    let mut rhs = Array1::<f64>::zeros(n_nodes);
+    let u0 = exact(0.0);
+
+    for k in 0..n {
+        let e = element(k);
+        let x0 = k as f64 * h;
+        for (s, w) in line_quadrature() {
+            let (phi, dphi) = shape_functions_1d(order, s);
+            let fx = f(x0 + s * h);
+            for a in 0..e.len() {
+                if e[a] == 0 {
+                    continue;
+                }
+                rhs[e[a]] += w * h * fx * phi[a];
+                for b in 0..e.len() {
+                    let kab = w * h * dphi[a] * dphi[b] / (h * h);
+                    if e[b] == 0 {
+                        rhs[e[a]] -= kab * u0;
+                    } else {
+                        triplet.add_triplet(e[a], e[b], kab);
+                    }
+                }
+            }
+        }
+    }
+    triplet.add_triplet(0, 0, 1.0);
+    rhs[0] = u0;
+    // x = 1 でのノイマン条件: 境界項 u'(1) φ_n(1)
+    rhs[n] += exact_deriv(1.0);
+
+    let (u_h, _) = conjugate_gradient(&triplet.to_csr(), &rhs, 1e-13, 10 * n_nodes);
+
+    let (mut l2, mut h1) = (0.0, 0.0);
+    for k in 0..n {
+        let e = element(k);
+        let x0 = k as f64 * h;
+        // 誤差の積分には要素をさらに細かく分けて求積する
+        for sub in 0..4 {
+            for (s, w) in line_quadrature() {
+                let s = (sub as f64 + s) / 4.0;
+                let (phi, dphi) = shape_functions_1d(order, s);
+                let uh: f64 = e.iter().zip(&phi).map(|(&i, p)| u_h[i] * p).sum();
+                let duh: f64 = e.iter().zip(&dphi).map(|(&i, d)| u_h[i] * d / h).sum();
+                let x = x0 + s * h;
+                l2 += w * h / 4.0 * (uh - exact(x)).powi(2);
+                h1 += w * h / 4.0 * (duh - exact_deriv(x)).powi(2);
+            }
+        }
+    }
+    (l2.sqrt(), h1.sqrt())
+}
+
+/// 収束次数 log2(e_h / e_{h/2}) を付けて誤差の表を表示する
+fn print_convergence(rows: &[(usize, f64, f64, f64)]) {
+    println!(
+        "{:>6} {:>10} {:>12} {:>6} {:>12} {:>6}",
+        "N", "h", "L2誤差", "次数", "H1誤差", "次数"
+    );
+    for (k, &(n, h, l2, h1)) in rows.iter().enumerate() {
+        if k == 0 {
+            println!(
+                "{:>6} {:>10.4e} {:>12.4e} {:>6} {:>12.4e} {:>6}",
+                n, h, l2, "-", h1, "-"
+            );
+        } else {
+            let (_, h_prev, l2_prev, h1_prev) = rows[k - 1];
+            let ratio = (h_prev / h).ln();
+            println!(
+                "{:>6} {:>10.4e} {:>12.4e} {:>6.2} {:>12.4e} {:>6.2}",
+                n,
+                h,
+                l2,
+                (l2_prev / l2).ln() / ratio,
+                h1,
+                (h1_prev / h1).ln() / ratio
+            );
+        }
+    }
+}
+
+fn main() {
+    println!("=== 有限要素法によるポアソン方程式 ===");
+    println!("最適な収束次数: Pk 要素で L2 誤差 O(h^(k+1)), H1 誤差 O(h^k)");
+
+    // ----------------------------------------
+    // 1. 1次元: 製造解 u = sin(πx) + x
+    // ----------------------------------------
+    let exact_1d = |x: f64| (PI * x).sin() + x;
+    let deriv_1d = |x: f64| PI * (PI * x).cos() + 1.0;
+    let f_1d = |x: f64| PI * PI * (PI * x).sin();
+
+    for order in [Order::P1, Order::P2] {
+        let name = if order == Order::P1 { "P1" } else { "P2" };
+        println!(
+            "\n--- 1次元 {} 要素 (u(0): ディリクレ, u'(1): ノイマン) ---",
+            name
+        );
+        let rows: Vec<_> = [8, 16, 32, 64, 128]
+            .iter()
+            .map(|&n| {
+                let (l2, h1) = solve_1d(n, order, &f_1d, &exact_1d, &deriv_1d);
+                (n, 1.0 / n as f64, l2, h1)
+            })
+            .collect();
+        print_convergence(&rows);
+    }
+
+    // ----------------------------------------
+    // 2. 2次元 正方形: 製造解 u = sin(πx) e^y
+    //    -Δu = (π² - 1) sin(πx) e^y
+    //    左右 (x = 0, 1) はディリクレ、上下 (y = 0, 1) はノイマン
+    // ----------------------------------------
+    let exact = |x: f64, y: f64| (PI * x).sin() * y.exp();
+    let exact_grad = |x: f64, y: f64| [PI * (PI * x).cos() * y.exp(), (PI * x).sin() * y.exp()];
+    let f = |x: f64, y: f64| (PI * PI - 1.0) * (PI * x).sin() * y.exp();
+    let neumann = |x: f64, y: f64, n: [f64; 2]| {
+        let g = exact_grad(x, y);
+        g[0] * n[0] + g[1] * n[1]
+    };
+    let is_dirichlet = |x: f64, _y: f64| !(1e-12..1.0 - 1e-12).contains(&x);
+    let bc = BoundaryData {
+        is_dirichlet: &is_dirichlet,
+        dirichlet: &exact,
+        neumann: &neumann,
+    };
+
+    for order in [Order::P1, Order::P2] {
+        let name = if order == Order::P1 { "P1" } else { "P2" };
+        println!(
+            "\n--- 2次元 正方形 {} 要素 (ディリクレ + ノイマン) ---",
+            name
+        );
+        let mut rows = Vec::new();
+        for nx in [4, 8, 16, 32, 64] {
+            let mesh = Mesh::structured(nx, nx, 1.0, 1.0, order, |_, _| true);
+            let (u_h, iters) = solve_poisson(&mesh, &f, &bc);
+            let (l2, h1) = errors_2d(&mesh, &u_h, &exact, &exact_grad);
+            println!(
+                "  N = {:>3}: 節点数 {:>6}, 要素数 {:>5}, CG 反復 {:>4}",
+                nx,
+                mesh.nodes.len(),
+                mesh.elements.len(),
+                iters
+            );
+            rows.push((nx, mesh.h_max(), l2, h1));
+        }
+        print_convergence(&rows);
+    }
+
+    // ----------------------------------------
+    // 3. 2次元 L字領域 ([0,1]² から右上の [0.5,1]² を除いたもの)
+    //    製造解 u = sin(πx) sin(πy)、全境界ディリクレ
+    // ----------------------------------------
+    let exact = |x: f64, y: f64| (PI * x).sin() * (PI * y).sin();
+    let exact_grad = |x: f64, y: f64| {
+        [
+            PI * (PI * x).cos() * (PI * y).sin(),
+            PI * (PI * x).sin() * (PI * y).cos(),
+        ]
+    };
+    let f = |x: f64, y: f64| 2.0 * PI * PI * (PI * x).sin() * (PI * y).sin();
+    let bc = BoundaryData {
+        is_dirichlet: &|_, _| true,
+        dirichlet: &exact,
+        neumann: &|_, _, _| 0.0,
+    };
+    let l_shape = |x: f64, y: f64| !(x > 0.5 && y > 0.5);
+
+    for order in [Order::P1, Order::P2] {
+        let name = if order == Order::P1 { "P1" } else { "P2" };
+        println!("\n--- 2次元 L字領域 {} 要素 (ディリクレ) ---", name);
+        let mut rows = Vec::new();
+        for nx in [4, 8, 16, 32, 64] {
+            let mesh = Mesh::structured(nx, nx, 1.0, 1.0, order, l_shape);
+            let (u_h, _) = solve_poisson(&mesh, &f, &bc);
+            let (l2, h1) = errors_2d(&mesh, &u_h, &exact, &exact_grad);
+            rows.push((nx, mesh.h_max(), l2, h1));
+        }
+        print_convergence(&rows);
+    }
+}
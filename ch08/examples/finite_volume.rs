@@ -0,0 +1,670 @@
+/// 保存則を表すトレイト（N は保存量の数）
+trait ConservationLaw<const N: usize> {
+    /// 物理流束 F(u)
+    fn flux(&self, u: &[f64; N]) -> [f64; N];
+
+    /// 特性速度（ヤコビ行列の固有値）の最小値と最大値
+    fn wave_speeds(&self, u: &[f64; N]) -> (f64, f64);
+
+    /// Roe 平均されたヤコビ行列による数値粘性 |Â| (u_R - u_L)
+    fn roe_dissipation(&self, ul: &[f64; N], ur: &[f64; N]) -> [f64; N];
+}
+
+/// Harten のエントロピー補正: |λ| が小さいとき滑らかな関数に置き換え、
+/// 遷音速膨張波で非物理的な膨張衝撃波ができるのを防ぐ
+fn entropy_fix(lambda: f64, delta: f64) -> f64 {
+    if lambda.abs() < delta {
+        (lambda * lambda + delta * delta) / (2.0 * delta)
+    } else {
+        lambda.abs()
+    }
+}
+
+// ----------------------------------------
+// 線形移流方程式: F(u) = a u
+// ----------------------------------------
+struct Advection {
+    a: f64,
+}
+
+impl ConservationLaw<1> for Advection {
+    fn flux(&self, u: &[f64; 1]) -> [f64; 1] {
+        [self.a * u[0]]
+    }
+
+    fn wave_speeds(&self, _u: &[f64; 1]) -> (f64, f64) {
+        (self.a, self.a)
+    }
+
+    fn roe_dissipation(&self, ul: &[f64; 1], ur: &[f64; 1]) -> [f64; 1] {
+        [self.a.abs() * (ur[0] - ul[0])]
+    }
+}
+
+// ----------------------------------------
+// 非粘性バーガース方程式: F(u) = u²/2
+// ----------------------------------------
+struct Burgers;
+
+impl ConservationLaw<1> for Burgers {
+    fn flux(&self, u: &[f64; 1]) -> [f64; 1] {
+        [0.5 * u[0] * u[0]]
+    }
+
+    fn wave_speeds(&self, u: &[f64; 1]) -> (f64, f64) {
+        (u[0], u[0])
+    }
+
+    fn roe_dissipation(&self, ul: &[f64; 1], ur: &[f64; 1]) -> [f64; 1] {
+        // Roe 平均速度 (u_L + u_R)/2
+        let lambda = 0.5 * (ul[0] + ur[0]);
+        let delta = (0.5 * (ur[0] - ul[0])).max(0.0);
+        [entropy_fix(lambda, delta) * (ur[0] - ul[0])]
+    }
+}
+
+// ----------------------------------------
+// 1次元浅水方程式: u = (h, hu), F = (hu, hu² + g h²/2)
+// ----------------------------------------
+struct ShallowWater {
+    g: f64,
+}
+
+impl ConservationLaw<2> for ShallowWater {
+    fn flux(&self, u: &[f64; 2]) -> [f64; 2] {
+        let (h, hu) = (u[0], u[1]);
+        let v = hu / h;
+        [hu, hu * v + 0.5 * self.g * h * h]
+    }
+
+    fn wave_speeds(&self, u: &[f64; 2]) -> (f64, f64) {
+        let v = u[1] / u[0];
+        let c = (self.g * u[0]).sqrt();
+        (v - c, v + c)
+    }
+
+    fn roe_dissipation(&self, ul: &[f64; 2], ur: &[f64; 2]) -> [f64; 2] {
+        let (sl, sr) = (ul[0].sqrt(), ur[0].sqrt());
+        let h = 0.5 * (ul[0] + ur[0]);
+        let v = (ul[1] / sl + ur[1] / sr) / (sl + sr);
+        let c = (self.g * h).sqrt();
+
+        let dh = ur[0] - ul[0];
+        let dq = ur[1] - ul[1];
+        // 波の強さ α_k（左固有ベクトルとの内積）
+        let alpha1 = ((v + c) * dh - dq) / (2.0 * c);
+        let alpha2 = (dq - (v - c) * dh) / (2.0 * c);
+        let delta = 0.1 * c;
+        let l1 = entropy_fix(v - c, delta);
+        let l2 = entropy_fix(v + c, delta);
+
+        // 右固有ベクトル r1 = (1, v - c), r2 = (1, v + c)
+        [
+            l1 * alpha1 + l2 * alpha2,
+            l1 * alpha1 * (v - c) + l2 * alpha2 * (v + c),
+        ]
+    }
+}
+
+// ----------------------------------------
+// 1次元オイラー方程式: u = (ρ, ρu, E)
+// ----------------------------------------
+struct Euler {
+    gamma: f64,
+}
+
+impl Euler {
+    fn pressure(&self, u: &[f64; 3]) -> f64 {
+        (self.gamma - 1.0) * (u[2] - 0.5 * u[1] * u[1] / u[0])
+    }
+
+    /// 原始変数 (ρ, u, p) から保存変数へ
+    fn conserved(&self, rho: f64, v: f64, p: f64) -> [f64; 3] {
+        [rho, rho * v, p / (self.gamma - 1.0) + 0.5 * rho * v * v]
+    }
+}
+
+impl ConservationLaw<3> for Euler {
+    fn flux(&self, u: &[f64; 3]) -> [f64; 3] {
+        let v = u[1] / u[0];
+        let p = self.pressure(u);
+        [u[1], u[1] * v + p, (u[2] + p) * v]
+    }
+
+    fn wave_speeds(&self, u: &[f64; 3]) -> (f64, f64) {
+        let v = u[1] / u[0];
+        let c = (self.gamma * self.pressure(u) / u[0]).sqrt();
+        (v - c, v + c)
+    }
+
+    fn roe_dissipation(&self, ul: &[f64; 3], ur: &[f64; 3]) -> [f64; 3] {
+        let (sl, sr) = (ul[0].sqrt(), ur[0].sqrt());
+        // 比エンタルピー H = (E + p)/ρ
+        let hl = (ul[2] + self.pressure(ul)) / ul[0];
+        let hr = (ur[2] + self.pressure(ur)) / ur[0];
+        let v = (ul[1] / sl + ur[1] / sr) / (sl + sr);
+        let h = (sl * hl + sr * hr) / (sl + sr);
+        let c = ((self.gamma - 1.0) * (h - 0.5 * v * v)).sqrt();
+
+        let d = [ur[0] - ul[0], ur[1] - ul[1], ur[2] - ul[2]];
+        let alpha2 = (self.gamma - 1.0) / (c * c) * (d[0] * (h - v * v) + v * d[1] - d[2]);
+        let alpha1 = (d[0] * (v + c) - d[1] - c * alpha2) / (2.0 * c);
+        let alpha3 = d[0] - alpha1 - alpha2;
+
+        let delta = 0.1 * c;
+        let waves = [
+            (entropy_fix(v - c, delta) * alpha1, [1.0, v - c, h - v * c]),
+            (v.abs() * alpha2, [1.0, v, 0.5 * v * v]),
+            (entropy_fix(v + c, delta) * alpha3, [1.0, v + c, h + v * c]),
+        ];
+
+        let mut diss = [0.0; 3];
+        for (strength, r) in waves {
+            for k in 0..3 {
+                diss[k] += strength * r[k];
+            }
+        }
+        diss
+    }
+}
+
+// ----------------------------------------
+// 数値流束（スキーム）
+// ----------------------------------------
+
+/// 近似リーマン解法
+#[derive(Clone, Copy)]
+enum Riemann {
+    /// Harten–Lax–van Leer: 最も遅い波と最も速い波の2波で近似
+    Hll,
+    /// Roe の線形化リーマン解法（線形移流では風上差分に一致する）
+    Roe,
+}
+
+/// 勾配制限関数（スロープリミッター）
+#[derive(Clone, Copy)]
+enum Limiter {
+    Minmod,
+    VanLeer,
+    Superbee,
+}
+
+impl Limiter {
+    /// 左右の差分 a = u_i - u_{i-1}, b = u_{i+1} - u_i から制限付き勾配を求める
+    fn slope(self, a: f64, b: f64) -> f64 {
+        if a * b <= 0.0 {
+            return 0.0;
+        }
+        match self {
+            Limiter::Minmod => a.signum() * a.abs().min(b.abs()),
+            Limiter::VanLeer => 2.0 * a * b / (a + b),
+            Limiter::Superbee => {
+                let (abs_a, abs_b) = (a.abs(), b.abs());
+                a.signum() * (2.0 * abs_a).min(abs_b).max(abs_a.min(2.0 * abs_b))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Scheme {
+    /// 1次精度の風上型（ゴドノフ型）スキーム
+    Upwind(Riemann),
+    /// Lax–Friedrichs: 中心流束 + 大きな数値粘性
+    LaxFriedrichs,
+    /// Lax–Wendroff (Richtmyer の2段階法): 2次精度だが不連続で振動する
+    LaxWendroff,
+    /// MUSCL: 勾配制限付きの区分線形再構成 + リーマン解法 + 2段の SSP ルンゲ・クッタ
+    Muscl(Limiter, Riemann),
+}
+
+impl Scheme {
+    fn name(&self) -> String {
+        let riemann = |r: &Riemann| match r {
+            Riemann::Hll => "HLL",
+            Riemann::Roe => "Roe",
+        };
+        let limiter = |l: &Limiter| match l {
+            Limiter::Minmod => "minmod",
+            Limiter::VanLeer => "van Leer",
+            Limiter::Superbee => "superbee",
+        };
+        match self {
+            Scheme::Upwind(r) => format!("Upwind ({})", riemann(r)),
+            Scheme::LaxFriedrichs => "Lax-Friedrichs".to_string(),
+            Scheme::LaxWendroff => "Lax-Wendroff".to_string(),
+            Scheme::Muscl(l, r) => format!("MUSCL ({}, {})", limiter(l), riemann(r)),
+        }
+    }
+}
+
+/// 近似リーマン解法による境界流束
+fn riemann_flux<L: ConservationLaw<N>, const N: usize>(
+    law: &L,
+    solver: Riemann,
+    ul: &[f64; N],
+    ur: &[f64; N],
+) -> [f64; N] {
+    let fl = law.flux(ul);
+    let fr = law.flux(ur);
+    match solver {
+        Riemann::Hll => {
+            // Davis の波速推定
+            let (sl_min, sl_max) = law.wave_speeds(ul);
+            let (sr_min, sr_max) = law.wave_speeds(ur);
+            let sl = sl_min.min(sr_min);
+            let sr = sl_max.max(sr_max);
+            if sl >= 0.0 {
+                fl
+            } else if sr <= 0.0 {
+                fr
+            } else {
+                std::array::from_fn(|k| {
+                    (sr * fl[k] - sl * fr[k] + sl * sr * (ur[k] - ul[k])) / (sr - sl)
+                })
+            }
+        }
+        Riemann::Roe => {
+            let diss = law.roe_dissipation(ul, ur);
+            std::array::from_fn(|k| 0.5 * (fl[k] + fr[k]) - 0.5 * diss[k])
+        }
+    }
+}
+
+/// 境界条件
+#[derive(Clone, Copy)]
+enum BoundaryCondition {
+    Periodic,
+    /// 透過境界（ゼロ勾配）: 波が領域外へそのまま出ていく
+    Transmissive,
+}
+
+/// 両側に ng 個のゴーストセルを付加した配列を作る
+fn with_ghost_cells<const N: usize>(
+    u: &[[f64; N]],
+    ng: usize,
+    bc: BoundaryCondition,
+) -> Vec<[f64; N]> {
+    let n = u.len();
+    (0..n + 2 * ng)
+        .map(|i| {
+            let j = i as isize - ng as isize;
+            let idx = match bc {
+                BoundaryCondition::Periodic => j.rem_euclid(n as isize) as usize,
+                BoundaryCondition::Transmissive => j.clamp(0, n as isize - 1) as usize,
+            };
+            u[idx]
+        })
+        .collect()
+}
+
+/// 空間離散化: 各セルの時間微分 dū_i/dt = -(F_{i+1/2} - F_{i-1/2}) / Δx
+fn residual<L: ConservationLaw<N>, const N: usize>(
+    law: &L,
+    u: &[[f64; N]],
+    dx: f64,
+    dt: f64,
+    scheme: Scheme,
+    bc: BoundaryCondition,
+) -> Vec<[f64; N]> {
+    let n = u.len();
+    let ng = 2;
+    let ext = with_ghost_cells(u, ng, bc);
+
+    // セル境界 i+1/2 (i = ng-1, ..., ng+n-1) での数値流束
+    let fluxes: Vec<[f64; N]> = (ng - 1..ng + n)
+        .map(|i| {
+            let (ul, ur) = (&ext[i], &ext[i + 1]);
+            match scheme {
+                Scheme::Upwind(solver) => riemann_flux(law, solver, ul, ur),
+                Scheme::LaxFriedrichs => {
+                    let (fl, fr) = (law.flux(ul), law.flux(ur));
+                    std::array::from_fn(|k| 0.5 * (fl[k] + fr[k]) - 0.5 * dx / dt * (ur[k] - ul[k]))
+                }
+                Scheme::LaxWendroff => {
+                    // 半ステップ進めた境界値 u_{i+1/2}^(n+1/2) の流束
+                    let (fl, fr) = (law.flux(ul), law.flux(ur));
+                    let u_half: [f64; N] = std::array::from_fn(|k| {
+                        0.5 * (ul[k] + ur[k]) - 0.5 * dt / dx * (fr[k] - fl[k])
+                    });
+                    law.flux(&u_half)
+                }
+                Scheme::Muscl(limiter, solver) => {
+                    // 区分線形再構成によるセル境界の左右の値
+                    let recon_l: [f64; N] = std::array::from_fn(|k| {
+                        let s = limiter.slope(ext[i][k] - ext[i - 1][k], ext[i + 1][k] - ext[i][k]);
+                        ext[i][k] + 0.5 * s
+                    });
+                    let recon_r: [f64; N] = std::array::from_fn(|k| {
+                        let s =
+                            limiter.slope(ext[i + 1][k] - ext[i][k], ext[i + 2][k] - ext[i + 1][k]);
+                        ext[i + 1][k] - 0.5 * s
+                    });
+                    riemann_flux(law, solver, &recon_l, &recon_r)
+                }
+            }
+        })
+        .collect();
+
+    (0..n)
+        .map(|i| std::array::from_fn(|k| -(fluxes[i + 1][k] - fluxes[i][k]) / dx))
+        .collect()
+}
+
+/// 時刻 t_end まで時間発展させる
+/// Δt は CFL 条件 Δt = CFL · Δx / max|λ| から毎ステップ決める
+fn solve<L: ConservationLaw<N>, const N: usize>(
+    law: &L,
+    mut u: Vec<[f64; N]>,
+    dx: f64,
+    t_end: f64,
+    cfl: f64,
+    scheme: Scheme,
+    bc: BoundaryCondition,
+) -> Vec<[f64; N]> {
+    let mut t = 0.0;
+    while t < t_end {
+        let max_speed = u
+            .iter()
+            .map(|c| {
+                let (s_min, s_max) = law.wave_speeds(c);
+                s_min.abs().max(s_max.abs())
+            })
+            .fold(1e-12, f64::max);
+        let dt = (cfl * dx / max_speed).min(t_end - t);
+
+        let l0 = residual(law, &u, dx, dt, scheme, bc);
+        let u1: Vec<[f64; N]> = u
+            .iter()
+            .zip(&l0)
+            .map(|(c, l)| std::array::from_fn(|k| c[k] + dt * l[k]))
+            .collect();
+
+        u = match scheme {
+            // 2次精度の再構成には2次精度の時間積分（Heun 法 = SSP-RK2）を組み合わせる
+            Scheme::Muscl(..) => {
+                let l1 = residual(law, &u1, dx, dt, scheme, bc);
+                u.iter()
+                    .zip(&u1)
+                    .zip(&l1)
+                    .map(|((c0, c1), l)| {
+                        std::array::from_fn(|k| 0.5 * c0[k] + 0.5 * (c1[k] + dt * l[k]))
+                    })
+                    .collect()
+            }
+            _ => u1,
+        };
+        t += dt;
+    }
+    u
+}
+
+/// L1 誤差 Σ|u_i - u_exact(x_i)| Δx
+fn l1_error(numerical: &[f64], exact: &[f64], dx: f64) -> f64 {
+    numerical
+        .iter()
+        .zip(exact)
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f64>()
+        * dx
+}
+
+// ----------------------------------------
+// 厳密解
+// ----------------------------------------
+
+/// 浅水方程式の片側の波の関数 f_K(h)（膨張波なら h < h_K、衝撃波なら h > h_K）
+fn shallow_water_wave(g: f64, h: f64, hk: f64) -> f64 {
+    if h <= hk {
+        2.0 * ((g * h).sqrt() - (g * hk).sqrt())
+    } else {
+        (h - hk) * (0.5 * g * (h + hk) / (h * hk)).sqrt()
+    }
+}
+
+/// ダム崩壊問題（静止水, h_L > h_R）の厳密解: 左向き膨張波 + 右向き衝撃波
+fn dam_break_exact(g: f64, hl: f64, hr: f64, xi: f64) -> f64 {
+    // 中間状態の水深 h* を二分法で求める: f_L(h*) + f_R(h*) = 0
+    let (mut lo, mut hi) = (hr, hl);
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        if shallow_water_wave(g, mid, hl) + shallow_water_wave(g, mid, hr) > 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    let h_star = 0.5 * (lo + hi);
+    let u_star = -shallow_water_wave(g, h_star, hl);
+
+    let cl = (g * hl).sqrt();
+    let c_star = (g * h_star).sqrt();
+    // 衝撃波の速度（ランキン・ユゴニオ条件）
+    let shock = h_star * u_star / (h_star - hr);
+
+    if xi < -cl {
+        hl
+    } else if xi < u_star - c_star {
+        // 膨張波の内部: u + 2c = 2c_L, u - c = ξ
+        let c = (2.0 * cl - xi) / 3.0;
+        c * c / g
+    } else if xi < shock {
+        h_star
+    } else {
+        hr
+    }
+}
+
+/// Sod の衝撃波管問題の厳密解（密度）
+/// 左向き膨張波・接触不連続・右向き衝撃波の構造を仮定する (Toro, 4章)
+fn sod_exact(gamma: f64, left: (f64, f64, f64), right: (f64, f64, f64), xi: f64) -> f64 {
+    let (rl, ul, pl) = left;
+    let (rr, ur, pr) = right;
+    let cl = (gamma * pl / rl).sqrt();
+    let cr = (gamma * pr / rr).sqrt();
+
+    // 圧力関数 f_K(p) とその微分
+    let f = |p: f64, rk: f64, pk: f64, ck: f64| -> (f64, f64) {
+        if p > pk {
+            let a = 2.0 / ((gamma + 1.0) * rk);
+            let b = (gamma - 1.0) / (gamma + 1.0) * pk;
+            let q = (a / (p + b)).sqrt();
+            ((p - pk) * q, q * (1.0 - 0.5 * (p - pk) / (p + b)))
+        } else {
+            let e = (gamma - 1.0) / (2.0 * gamma);
+            let r = (p / pk).powf(e);
+            (
+                2.0 * ck / (gamma - 1.0) * (r - 1.0),
+                (p / pk).powf(-(gamma + 1.0) / (2.0 * gamma)) / (rk * ck),
+            )
+        }
+    };
+
+    // ニュートン法で中間状態の圧力 p* を求める
+    let mut p = 0.5 * (pl + pr);
+    for _ in 0..50 {
+        let (fl, dfl) = f(p, rl, pl, cl);
+        let (fr, dfr) = f(p, rr, pr, cr);
+        let dp = (fl + fr + ur - ul) / (dfl + dfr);
+        p = (p - dp).max(1e-10);
+        if dp.abs() < 1e-14 {
+            break;
+        }
+    }
+    let u_star = 0.5 * (ul + ur) + 0.5 * (f(p, rr, pr, cr).0 - f(p, rl, pl, cl).0);
+
+    let g1 = (gamma - 1.0) / (gamma + 1.0);
+    let rho_star_l = rl * (p / pl).powf(1.0 / gamma);
+    let rho_star_r = rr * (p / pr + g1) / (g1 * p / pr + 1.0);
+    let c_star_l = cl * (p / pl).powf((gamma - 1.0) / (2.0 * gamma));
+    let shock =
+        ur + cr * ((gamma + 1.0) / (2.0 * gamma) * p / pr + (gamma - 1.0) / (2.0 * gamma)).sqrt();
+
+    if xi < ul - cl {
+        rl
+    } else if xi < u_star - c_star_l {
+        // 膨張波の内部
+        let c = 2.0 / (gamma + 1.0) * (cl + 0.5 * (gamma - 1.0) * (ul - xi));
+        rl * (c / cl).powf(2.0 / (gamma - 1.0))
+    } else if xi < u_star {
+        rho_star_l
+    } else if xi < shock {
+        rho_star_r
+    } else {
+        rr
+    }
+}
+
+fn main() {
+    let n = 200;
+    let dx = 1.0 / n as f64;
+    let x: Vec<f64> = (0..n).map(|i| (i as f64 + 0.5) * dx).collect();
+
+    let schemes = [
+        Scheme::Upwind(Riemann::Roe),
+        Scheme::Upwind(Riemann::Hll),
+        Scheme::LaxFriedrichs,
+        Scheme::LaxWendroff,
+        Scheme::Muscl(Limiter::Minmod, Riemann::Roe),
+        Scheme::Muscl(Limiter::VanLeer, Riemann::Hll),
+        Scheme::Muscl(Limiter::Superbee, Riemann::Roe),
+    ];
+
+    // ----------------------------------------
+    // 1. 線形移流: 矩形波を周期境界で1周させる
+    // ----------------------------------------
+    println!("=== 1. 線形移流方程式 (a = 1, 矩形波を1周期) ===");
+    println!(
+        "{:<28} {:>12} {:>12} {:>12}",
+        "スキーム", "L1誤差", "最大値", "最小値"
+    );
+    let advection = Advection { a: 1.0 };
+    let square = |x: f64| if (0.25..0.5).contains(&x) { 1.0 } else { 0.0 };
+    let u0: Vec<[f64; 1]> = x.iter().map(|&x| [square(x)]).collect();
+    let exact: Vec<f64> = x.iter().map(|&x| square(x)).collect();
+    for scheme in schemes {
+        let u = solve(
+            &advection,
+            u0.clone(),
+            dx,
+            1.0,
+            0.5,
+            scheme,
+            BoundaryCondition::Periodic,
+        );
+        let u: Vec<f64> = u.iter().map(|c| c[0]).collect();
+        let max = u.iter().cloned().fold(f64::MIN, f64::max);
+        let min = u.iter().cloned().fold(f64::MAX, f64::min);
+        println!(
+            "{:<28} {:>12.4e} {:>12.4} {:>12.4}",
+            scheme.name(),
+            l1_error(&u, &exact, dx),
+            max,
+            min
+        );
+    }
+    println!("  (Lax-Wendroff は不連続で振動し、最大値が 1 を超える)");
+
+    // ----------------------------------------
+    // 2. バーガース方程式: 膨張波と衝撃波
+    // ----------------------------------------
+    println!("\n=== 2. 非粘性バーガース方程式 (膨張波 + 衝撃波, t = 0.4) ===");
+    let t_end = 0.4;
+    let burgers_init = |x: f64| if (0.2..0.5).contains(&x) { 1.0 } else { 0.0 };
+    // 厳密解: x = 0.2 から膨張波、x = 0.5 から速度 1/2 の衝撃波
+    let burgers_exact = |x: f64| {
+        if x < 0.2 {
+            0.0
+        } else if x < 0.2 + t_end {
+            (x - 0.2) / t_end
+        } else if x < 0.5 + 0.5 * t_end {
+            1.0
+        } else {
+            0.0
+        }
+    };
+    let u0: Vec<[f64; 1]> = x.iter().map(|&x| [burgers_init(x)]).collect();
+    let exact: Vec<f64> = x.iter().map(|&x| burgers_exact(x)).collect();
+    println!("{:<28} {:>12}", "スキーム", "L1誤差");
+    for scheme in schemes {
+        let u = solve(
+            &Burgers,
+            u0.clone(),
+            dx,
+            t_end,
+            0.5,
+            scheme,
+            BoundaryCondition::Transmissive,
+        );
+        let u: Vec<f64> = u.iter().map(|c| c[0]).collect();
+        println!("{:<28} {:>12.4e}", scheme.name(), l1_error(&u, &exact, dx));
+    }
+
+    // ----------------------------------------
+    // 3. 浅水方程式: ダム崩壊問題
+    // ----------------------------------------
+    println!("\n=== 3. 浅水方程式 (ダム崩壊 h_L = 2, h_R = 1, t = 0.1) ===");
+    let sw = ShallowWater { g: 9.81 };
+    let t_end = 0.1;
+    let (hl, hr) = (2.0, 1.0);
+    let u0: Vec<[f64; 2]> = x
+        .iter()
+        .map(|&x| [if x < 0.5 { hl } else { hr }, 0.0])
+        .collect();
+    let exact: Vec<f64> = x
+        .iter()
+        .map(|&x| dam_break_exact(sw.g, hl, hr, (x - 0.5) / t_end))
+        .collect();
+    println!("{:<28} {:>12}", "スキーム", "L1誤差 (h)");
+    for scheme in schemes {
+        let u = solve(
+            &sw,
+            u0.clone(),
+            dx,
+            t_end,
+            0.5,
+            scheme,
+            BoundaryCondition::Transmissive,
+        );
+        let h: Vec<f64> = u.iter().map(|c| c[0]).collect();
+        println!("{:<28} {:>12.4e}", scheme.name(), l1_error(&h, &exact, dx));
+    }
+
+    // ----------------------------------------
+    // 4. オイラー方程式: Sod の衝撃波管
+    // ----------------------------------------
+    println!("\n=== 4. オイラー方程式 (Sod の衝撃波管, t = 0.2) ===");
+    let euler = Euler { gamma: 1.4 };
+    let t_end = 0.2;
+    let left = (1.0, 0.0, 1.0);
+    let right = (0.125, 0.0, 0.1);
+    let u0: Vec<[f64; 3]> = x
+        .iter()
+        .map(|&x| {
+            let (rho, v, p) = if x < 0.5 { left } else { right };
+            euler.conserved(rho, v, p)
+        })
+        .collect();
+    let exact: Vec<f64> = x
+        .iter()
+        .map(|&x| sod_exact(euler.gamma, left, right, (x - 0.5) / t_end))
+        .collect();
+    println!("{:<28} {:>12}", "スキーム", "L1誤差 (ρ)");
+    for scheme in schemes {
+        let u = solve(
+            &euler,
+            u0.clone(),
+            dx,
+            t_end,
+            0.5,
+            scheme,
+            BoundaryCondition::Transmissive,
+        );
+        let rho: Vec<f64> = u.iter().map(|c| c[0]).collect();
+        println!(
+            "{:<28} {:>12.4e}",
+            scheme.name(),
+            l1_error(&rho, &exact, dx)
+        );
+    }
+}
@@ -0,0 +1,533 @@
+use ndarray::{Array2, Zip};
+
+// 2次元非圧縮性ナビエ・ストークス方程式（無次元形）
+//   ∂u/∂t + (u·∇)u = -∇p + (1/Re) ∇²u,  ∇·u = 0
+// をスタッガード格子 (MAC 格子) 上の射影法 (Chorin) で解く。
+//
+// 1. 圧力を無視して仮の速度 F, G を陽的に求める
+// 2. ∇·u^(n+1) = 0 となるように圧力ポアソン方程式 ∇²p = (1/Δt) ∇·(F, G) を解く
+// 3. u^(n+1) = F - Δt ∂p/∂x, v^(n+1) = G - Δt ∂p/∂y で速度を補正（射影）する
+//
+// 格子の配置 (i, j はセル番号, 1..=nx, 1..=ny が内部セル):
+//   p[i, j]: セル中心 ((i - 1/2)Δx, (j - 1/2)Δy)
+//   u[i, j]: セルの右側の面 (iΔx, (j - 1/2)Δy)
+//   v[i, j]: セルの上側の面 ((i - 1/2)Δx, jΔy)
+// 境界の外側には仮想セル（ゴーストセル）を1層置いて境界条件を表す。
+
+/// 各辺の速度境界条件
+enum Side {
+    /// 滑りなし壁（壁に沿った速度を指定。0 なら静止壁、動く蓋なら蓋の速度）
+    Wall(f64),
+    /// 流入境界（境界に沿った座標の関数として法線方向の速度を与える）
+    Inflow(Box<dyn Fn(f64) -> f64>),
+    /// 流出境界（圧力 p = 0、仮の速度の法線方向の勾配をゼロとする）
+    Outflow,
+}
+
+impl Side {
+    fn is_outflow(&self) -> bool {
+        matches!(self, Side::Outflow)
+    }
+}
+
+struct Boundaries {
+    west: Side,
+    east: Side,
+    south: Side,
+    north: Side,
+}
+
+struct NavierStokes {
+    nx: usize,
+    ny: usize,
+    dx: f64,
+    dy: f64,
+    re: f64,
+    u: Array2<f64>,
+    v: Array2<f64>,
+    p: Array2<f64>,
+    bc: Boundaries,
+    time: f64,
+}
+
+impl NavierStokes {
+    fn new(nx: usize, ny: usize, lx: f64, ly: f64, re: f64, bc: Boundaries) -> Self {
+        Self {
+            nx,
+            ny,
+            dx: lx / nx as f64,
+            dy: ly / ny as f64,
+            re,
+            u: Array2::zeros((nx + 1, ny + 2)),
+            v: Array2::zeros((nx + 2, ny + 1)),
+            p: Array2::zeros((nx + 2, ny + 2)),
+            bc,
+            time: 0.0,
+        }
+    }
+
+    /// 速度の境界条件をゴーストセルと境界面に設定する
+    fn apply_velocity_bc(&mut self) {
+        let (nx, ny) = (self.nx, self.ny);
+        let (u, v) = (&mut self.u, &mut self.v);
+
+        // 西 (x = 0) と東 (x = L_x): 法線速度 u は境界面上、接線速度 v はゴーストセルで与える
+        for j in 0..=ny + 1 {
+            let y = (j as f64 - 0.5) * self.dy;
+            match &self.bc.west {
+                Side::Wall(_) => u[[0, j]] = 0.0,
+                Side::Inflow(profile) => u[[0, j]] = profile(y),
+                // 流出境界の法線速度は射影で決まる
+                Side::Outflow => {}
+            }
+            match &self.bc.east {
+                Side::Wall(_) => u[[nx, j]] = 0.0,
+                Side::Inflow(profile) => u[[nx, j]] = -profile(y),
+                Side::Outflow => {}
+            }
+        }
+        for j in 0..=ny {
+            // 壁面上での接線速度が指定値になるよう、ゴーストセルの値を鏡像で決める
+            v[[0, j]] = match &self.bc.west {
+                Side::Wall(vt) => 2.0 * vt - v[[1, j]],
+                Side::Inflow(_) => -v[[1, j]],
+                Side::Outflow => v[[1, j]],
+            };
+            v[[nx + 1, j]] = match &self.bc.east {
+                Side::Wall(vt) => 2.0 * vt - v[[nx, j]],
+                Side::Inflow(_) => -v[[nx, j]],
+                Side::Outflow => v[[nx, j]],
+            };
+        }
+
+        // 南 (y = 0) と北 (y = L_y)
+        for i in 0..=nx + 1 {
+            let x = (i as f64 - 0.5) * self.dx;
+            match &self.bc.south {
+                Side::Wall(_) => v[[i, 0]] = 0.0,
+                Side::Inflow(profile) => v[[i, 0]] = profile(x),
+                Side::Outflow => {}
+            }
+            match &self.bc.north {
+                Side::Wall(_) => v[[i, ny]] = 0.0,
+                Side::Inflow(profile) => v[[i, ny]] = -profile(x),
+                Side::Outflow => {}
+            }
+        }
+        for i in 0..=nx {
+            u[[i, 0]] = match &self.bc.south {
+                Side::Wall(ut) => 2.0 * ut - u[[i, 1]],
+                Side::Inflow(_) => -u[[i, 1]],
+                Side::Outflow => u[[i, 1]],
+            };
+            u[[i, ny + 1]] = match &self.bc.north {
+                Side::Wall(ut) => 2.0 * ut - u[[i, ny]],
+                Side::Inflow(_) => -u[[i, ny]],
+                Side::Outflow => u[[i, ny]],
+            };
+        }
+    }
+
+    /// 安定条件から時間刻みを決める（拡散と移流の両方の制約）
+    fn stable_dt(&self, safety: f64) -> f64 {
+        let u_max = self.u.iter().fold(1e-10, |m: f64, &x| m.max(x.abs()));
+        let v_max = self.v.iter().fold(1e-10, |m: f64, &x| m.max(x.abs()));
+        let diffusion = 0.5 * self.re / (1.0 / self.dx.powi(2) + 1.0 / self.dy.powi(2));
+        let convection = (self.dx / u_max).min(self.dy / v_max);
+        safety * diffusion.min(convection)
+    }
+
+    /// 仮の速度 F, G（圧力勾配を除いた運動量方程式を1ステップ進めたもの）
+    /// 移流項は中心差分と風上差分を重み γ で混ぜる (Griebel et al. のドナーセル法)
+    fn tentative_velocity(&self, dt: f64) -> (Array2<f64>, Array2<f64>) {
+        let (nx, ny, dx, dy) = (self.nx, self.ny, self.dx, self.dy);
+        let (u, v) = (&self.u, &self.v);
+
+        let u_max = u.iter().fold(0.0, |m: f64, &x| m.max(x.abs()));
+        let v_max = v.iter().fold(0.0, |m: f64, &x| m.max(x.abs()));
+        let gamma = (u_max * dt / dx).max(v_max * dt / dy).min(1.0);
+
+        let mut f = u.clone();
+        let mut g = v.clone();
+
+        for i in 1..nx {
+            for j in 1..=ny {
+                let lap = (u[[i + 1, j]] - 2.0 * u[[i, j]] + u[[i - 1, j]]) / dx.powi(2)
+                    + (u[[i, j + 1]] - 2.0 * u[[i, j]] + u[[i, j - 1]]) / dy.powi(2);
+
+                let ue = 0.5 * (u[[i, j]] + u[[i + 1, j]]);
+                let uw = 0.5 * (u[[i - 1, j]] + u[[i, j]]);
+                let du2dx = (ue * ue - uw * uw) / dx
+                    + gamma / dx
+                        * (ue.abs() * 0.5 * (u[[i, j]] - u[[i + 1, j]])
+                            - uw.abs() * 0.5 * (u[[i - 1, j]] - u[[i, j]]));
+
+                let vn = 0.5 * (v[[i, j]] + v[[i + 1, j]]);
+                let vs = 0.5 * (v[[i, j - 1]] + v[[i + 1, j - 1]]);
+                let duvdy = (vn * 0.5 * (u[[i, j]] + u[[i, j + 1]])
+                    - vs * 0.5 * (u[[i, j - 1]] + u[[i, j]]))
+                    / dy
+                    + gamma / dy
+                        * (vn.abs() * 0.5 * (u[[i, j]] - u[[i, j + 1]])
+                            - vs.abs() * 0.5 * (u[[i, j - 1]] - u[[i, j]]));
+
+                f[[i, j]] = u[[i, j]] + dt * (lap / self.re - du2dx - duvdy);
+            }
+        }
+
+        for i in 1..=nx {
+            for j in 1..ny {
+                let lap = (v[[i + 1, j]] - 2.0 * v[[i, j]] + v[[i - 1, j]]) / dx.powi(2)
+                    + (v[[i, j + 1]] - 2.0 * v[[i, j]] + v[[i, j - 1]]) / dy.powi(2);
+
+                let ue = 0.5 * (u[[i, j]] + u[[i, j + 1]]);
+                let uw = 0.5 * (u[[i - 1, j]] + u[[i - 1, j + 1]]);
+                let duvdx = (ue * 0.5 * (v[[i, j]] + v[[i + 1, j]])
+                    - uw * 0.5 * (v[[i - 1, j]] + v[[i, j]]))
+                    / dx
+                    + gamma / dx
+                        * (ue.abs() * 0.5 * (v[[i, j]] - v[[i + 1, j]])
+                            - uw.abs() * 0.5 * (v[[i - 1, j]] - v[[i, j]]));
+
+                let vn = 0.5 * (v[[i, j]] + v[[i, j + 1]]);
+                let vs = 0.5 * (v[[i, j - 1]] + v[[i, j]]);
+                let dv2dy = (vn * vn - vs * vs) / dy
+                    + gamma / dy
+                        * (vn.abs() * 0.5 * (v[[i, j]] - v[[i, j + 1]])
+                            - vs.abs() * 0.5 * (v[[i, j - 1]] - v[[i, j]]));
+
+                g[[i, j]] = v[[i, j]] + dt * (lap / self.re - duvdx - dv2dy);
+            }
+        }
+
+        // 流出境界の面では仮の速度を内側から外挿する（法線方向の勾配ゼロ）
+        for j in 1..=ny {
+            if self.bc.west.is_outflow() {
+                f[[0, j]] = f[[1, j]];
+            }
+            if self.bc.east.is_outflow() {
+                f[[nx, j]] = f[[nx - 1, j]];
+            }
+        }
+        for i in 1..=nx {
+            if self.bc.south.is_outflow() {
+                g[[i, 0]] = g[[i, 1]];
+            }
+            if self.bc.north.is_outflow() {
+                g[[i, ny]] = g[[i, ny - 1]];
+            }
+        }
+        (f, g)
+    }
+
+    /// 圧力のラプラシアンの符号を反転したもの -∇²p
+    /// 壁・流入境界では ∂p/∂n = 0、流出境界では境界面上で p = 0（ゴーストセルに -p を置く）
+    /// 対称半正定値な作用素なので、共役勾配法で解ける
+    fn neg_laplacian(&self, p: &Array2<f64>, out: &mut Array2<f64>) {
+        let (nx, ny) = (self.nx, self.ny);
+        let (cx, cy) = (1.0 / self.dx.powi(2), 1.0 / self.dy.powi(2));
+        for i in 1..=nx {
+            for j in 1..=ny {
+                let c = p[[i, j]];
+                // ノイマン条件の面は流束ゼロなので寄与しない
+                // ディリクレ条件の面ではゴーストセルの値 -c との差 2c が流束になる
+                let mut s = 0.0;
+                if i > 1 {
+                    s += cx * (c - p[[i - 1, j]]);
+                } else if self.bc.west.is_outflow() {
+                    s += 2.0 * cx * c;
+                }
+                if i < nx {
+                    s += cx * (c - p[[i + 1, j]]);
+                } else if self.bc.east.is_outflow() {
+                    s += 2.0 * cx * c;
+                }
+                if j > 1 {
+                    s += cy * (c - p[[i, j - 1]]);
+                } else if self.bc.south.is_outflow() {
+                    s += 2.0 * cy * c;
+                }
+                if j < ny {
+                    s += cy * (c - p[[i, j + 1]]);
+                } else if self.bc.north.is_outflow() {
+                    s += 2.0 * cy * c;
+                }
+                out[[i, j]] = s;
+            }
+        }
+    }
+
+    /// 圧力ポアソン方程式 -∇²p = -(1/Δt) ∇·(F, G) を共役勾配法で解く
+    /// 前のステップの圧力を初期値に使う。戻り値は反復回数
+    fn solve_pressure(&mut self, f: &Array2<f64>, g: &Array2<f64>, dt: f64) -> usize {
+        let (nx, ny) = (self.nx, self.ny);
+        let mut b = Array2::<f64>::zeros(self.p.dim());
+        for i in 1..=nx {
+            for j in 1..=ny {
+                let div =
+                    (f[[i, j]] - f[[i - 1, j]]) / self.dx + (g[[i, j]] - g[[i, j - 1]]) / self.dy;
+                b[[i, j]] = -div / dt;
+            }
+        }
+        // 全境界がノイマン条件のとき、解が存在するためには右辺の総和がゼロでなければならない
+        // （丸め誤差による不釣り合いを取り除く。流出境界があれば p = 0 で解が一意に決まる）
+        let bc = &self.bc;
+        let has_outflow = [&bc.west, &bc.east, &bc.south, &bc.north]
+            .iter()
+            .any(|side| side.is_outflow());
+        if !has_outflow {
+            let mean = b.sum() / (nx * ny) as f64;
+            for i in 1..=nx {
+                for j in 1..=ny {
+                    b[[i, j]] -= mean;
+                }
+            }
+        }
+
+        let dot = |x: &Array2<f64>, y: &Array2<f64>| {
+            Zip::from(x).and(y).fold(0.0, |acc, &a, &b| acc + a * b)
+        };
+        let mut x = self.p.clone();
+        let mut ap = Array2::<f64>::zeros(x.dim());
+        self.neg_laplacian(&x, &mut ap);
+        let mut r = &b - &ap;
+        let mut p = r.clone();
+        let mut rr = dot(&r, &r);
+        let b_norm = dot(&b, &b).sqrt().max(1e-300);
+
+        let mut iters = 0;
+        while rr.sqrt() / b_norm > 1e-6 && iters < 5000 {
+            self.neg_laplacian(&p, &mut ap);
+            let alpha = rr / dot(&p, &ap);
+            x.scaled_add(alpha, &p);
+            r.scaled_add(-alpha, &ap);
+            let rr_new = dot(&r, &r);
+            let beta = rr_new / rr;
+            Zip::from(&mut p)
+                .and(&r)
+                .for_each(|p, &r| *p = r + beta * *p);
+            rr = rr_new;
+            iters += 1;
+        }
+        // 流出境界のゴーストセル（境界面上で p = 0）
+        for j in 1..=ny {
+            if self.bc.west.is_outflow() {
+                x[[0, j]] = -x[[1, j]];
+            }
+            if self.bc.east.is_outflow() {
+                x[[nx + 1, j]] = -x[[nx, j]];
+            }
+        }
+        for i in 1..=nx {
+            if self.bc.south.is_outflow() {
+                x[[i, 0]] = -x[[i, 1]];
+            }
+            if self.bc.north.is_outflow() {
+                x[[i, ny + 1]] = -x[[i, ny]];
+            }
+        }
+        self.p = x;
+        iters
+    }
+
+    /// 1ステップ進め、速度の最大変化率 max|Δu|/Δt を返す（定常状態の判定に使う）
+    fn step(&mut self, dt: f64) -> f64 {
+        self.apply_velocity_bc();
+        let (f, g) = self.tentative_velocity(dt);
+        self.solve_pressure(&f, &g, dt);
+
+        // 射影: 圧力勾配で速度を補正し、発散ゼロにする（流出境界の面も補正する）
+        let (nx, ny) = (self.nx, self.ny);
+        let i_range = usize::from(!self.bc.west.is_outflow())
+            ..=nx - usize::from(!self.bc.east.is_outflow());
+        let j_range = usize::from(!self.bc.south.is_outflow())
+            ..=ny - usize::from(!self.bc.north.is_outflow());
+        let mut change: f64 = 0.0;
+        for i in i_range {
+            for j in 1..=ny {
+                let new = f[[i, j]] - dt * (self.p[[i + 1, j]] - self.p[[i, j]]) / self.dx;
+                change = change.max((new - self.u[[i, j]]).abs());
+                self.u[[i, j]] = new;
+            }
+        }
+        for i in 1..=nx {
+            for j in j_range.clone() {
+                let new = g[[i, j]] - dt * (self.p[[i, j + 1]] - self.p[[i, j]]) / self.dy;
+                change = change.max((new - self.v[[i, j]]).abs());
+                self.v[[i, j]] = new;
+            }
+        }
+        self.apply_velocity_bc();
+        self.time += dt;
+        change / dt
+    }
+
+    /// 連続の式の残差 max|∇·u|
+    fn max_divergence(&self) -> f64 {
+        let mut m: f64 = 0.0;
+        for i in 1..=self.nx {
+            for j in 1..=self.ny {
+                let div = (self.u[[i, j]] - self.u[[i - 1, j]]) / self.dx
+                    + (self.v[[i, j]] - self.v[[i, j - 1]]) / self.dy;
+                m = m.max(div.abs());
+            }
+        }
+        m
+    }
+
+    /// 定常状態（max|Δu|/Δt < tol）まで時間発展させる
+    fn run_to_steady(&mut self, tol: f64, t_max: f64) {
+        let mut n = 0;
+        while self.time < t_max {
+            let dt = self.stable_dt(0.5);
+            let rate = self.step(dt);
+            n += 1;
+            if n % 2000 == 0 {
+                println!("  t = {:>7.2}: max|∂u/∂t| = {:.3e}", self.time, rate);
+            }
+            if rate < tol {
+                println!("  定常状態に到達: t = {:.2} ({} ステップ)", self.time, n);
+                return;
+            }
+        }
+        println!("  t_max = {} に到達しました", t_max);
+    }
+
+    /// 縦の中心線 x = x0 上の u(y)（セル中心の値を線形補間）
+    /// x0 は u の定義点 (iΔx) に一致している必要がある
+    fn u_on_vertical_line(&self, x0: f64, y: f64) -> f64 {
+        let i = (x0 / self.dx).round() as usize;
+        // u[i, j] は y = (j - 1/2)Δy にある。ゴーストセルとの平均が壁面の値になる
+        let s = y / self.dy + 0.5;
+        let j = (s.floor() as usize).min(self.ny);
+        let w = s - j as f64;
+        (1.0 - w) * self.u[[i, j]] + w * self.u[[i, j + 1]]
+    }
+
+    /// 横の中心線 y = y0 上の v(x)
+    fn v_on_horizontal_line(&self, y0: f64, x: f64) -> f64 {
+        let j = (y0 / self.dy).round() as usize;
+        let s = x / self.dx + 0.5;
+        let i = (s.floor() as usize).min(self.nx);
+        let w = s - i as f64;
+        (1.0 - w) * self.v[[i, j]] + w * self.v[[i + 1, j]]
+    }
+}
+
+// Ghia, Ghia & Shin (1982) のキャビティ流れの参照解
+// 縦の中心線 x = 0.5 上の u、横の中心線 y = 0.5 上の v
+const GHIA_Y: [f64; 17] = [
+    1.0000, 0.9766, 0.9688, 0.9609, 0.9531, 0.8516, 0.7344, 0.6172, 0.5000, 0.4531, 0.2813, 0.1719,
+    0.1016, 0.0703, 0.0625, 0.0547, 0.0000,
+];
+const GHIA_U_100: [f64; 17] = [
+    1.00000, 0.84123, 0.78871, 0.73722, 0.68717, 0.23151, 0.00332, -0.13641, -0.20581, -0.21090,
+    -0.15662, -0.10150, -0.06434, -0.04775, -0.04192, -0.03717, 0.00000,
+];
+const GHIA_U_1000: [f64; 17] = [
+    1.00000, 0.65928, 0.57492, 0.51117, 0.46604, 0.33304, 0.18719, 0.05702, -0.06080, -0.10648,
+    -0.27805, -0.38289, -0.29730, -0.22220, -0.20196, -0.18109, 0.00000,
+];
+const GHIA_X: [f64; 17] = [
+    1.0000, 0.9688, 0.9609, 0.9531, 0.9453, 0.9063, 0.8594, 0.8047, 0.5000, 0.2344, 0.2266, 0.1563,
+    0.0938, 0.0781, 0.0703, 0.0625, 0.0000,
+];
+const GHIA_V_100: [f64; 17] = [
+    0.00000, -0.05906, -0.07391, -0.08864, -0.10313, -0.16914, -0.22445, -0.24533, 0.05454,
+    0.17527, 0.17507, 0.16077, 0.12317, 0.10890, 0.10091, 0.09233, 0.00000,
+];
+const GHIA_V_1000: [f64; 17] = [
+    0.00000, -0.21388, -0.27669, -0.33714, -0.39188, -0.51550, -0.42665, -0.31966, 0.02526,
+    0.32235, 0.33075, 0.37095, 0.32627, 0.30353, 0.29012, 0.27485, 0.00000,
+];
+
+/// 蓋駆動キャビティ流れ（上の壁が速度 1 で右へ動く正方形キャビティ）
+fn lid_driven_cavity(re: f64, n: usize, ghia_u: &[f64; 17], ghia_v: &[f64; 17]) {
+    println!(
+        "\n=== 蓋駆動キャビティ流れ Re = {}, 格子 {}x{} ===",
+        re, n, n
+    );
+    let bc = Boundaries {
+        west: Side::Wall(0.0),
+        east: Side::Wall(0.0),
+        south: Side::Wall(0.0),
+        north: Side::Wall(1.0),
+    };
+    let mut ns = NavierStokes::new(n, n, 1.0, 1.0, re, bc);
+    ns.run_to_steady(1e-4, 200.0);
+    println!("  max|∇·u| = {:.2e}", ns.max_divergence());
+
+    println!("\n  縦の中心線 x = 0.5 上の u");
+    println!("  {:>8} {:>10} {:>10} {:>10}", "y", "計算値", "Ghia", "差");
+    let mut max_err: f64 = 0.0;
+    for (&y, &u_ref) in GHIA_Y.iter().zip(ghia_u) {
+        let u = ns.u_on_vertical_line(0.5, y);
+        max_err = max_err.max((u - u_ref).abs());
+        println!(
+            "  {:>8.4} {:>10.5} {:>10.5} {:>10.5}",
+            y,
+            u,
+            u_ref,
+            u - u_ref
+        );
+    }
+    println!("  最大誤差: {:.4}", max_err);
+
+    println!("\n  横の中心線 y = 0.5 上の v");
+    println!("  {:>8} {:>10} {:>10} {:>10}", "x", "計算値", "Ghia", "差");
+    let mut max_err: f64 = 0.0;
+    for (&x, &v_ref) in GHIA_X.iter().zip(ghia_v) {
+        let v = ns.v_on_horizontal_line(0.5, x);
+        max_err = max_err.max((v - v_ref).abs());
+        println!(
+            "  {:>8.4} {:>10.5} {:>10.5} {:>10.5}",
+            x,
+            v,
+            v_ref,
+            v - v_ref
+        );
+    }
+    println!("  最大誤差: {:.4}", max_err);
+}
+
+/// 流入・流出境界を持つ平行平板間の流れ
+/// 一様流を流入させると、下流では放物線状のポアズイユ流 u = 6U y(H - y)/H² に発達する
+fn channel_flow() {
+    println!("\n=== 平行平板間の流れ (Re = 10, 流入: 一様流 U = 1, 流出: 勾配ゼロ) ===");
+    let (lx, ly) = (5.0, 1.0);
+    let (nx, ny) = (100, 20);
+    let bc = Boundaries {
+        west: Side::Inflow(Box::new(|_| 1.0)),
+        east: Side::Outflow,
+        south: Side::Wall(0.0),
+        north: Side::Wall(0.0),
+    };
+    let mut ns = NavierStokes::new(nx, ny, lx, ly, 10.0, bc);
+    ns.run_to_steady(1e-5, 100.0);
+    println!("  max|∇·u| = {:.2e}", ns.max_divergence());
+    let flux = |i: usize| (1..=ny).map(|j| ns.u[[i, j]] * ns.dy).sum::<f64>();
+    println!("  流入流量 = {:.6}, 流出流量 = {:.6}", flux(0), flux(nx));
+
+    // 出口付近 (x = 4.5) の速度分布を解析解と比べる
+    let x0 = 4.5;
+    println!("\n  x = {} での速度分布", x0);
+    println!("  {:>8} {:>10} {:>10}", "y", "計算値", "解析解");
+    let mut max_err: f64 = 0.0;
+    for k in 0..=10 {
+        let y = k as f64 / 10.0 * ly;
+        let u = ns.u_on_vertical_line(x0, y);
+        let exact = 6.0 * y * (ly - y) / (ly * ly);
+        max_err = max_err.max((u - exact).abs());
+        println!("  {:>8.2} {:>10.5} {:>10.5}", y, u, exact);
+    }
+    // ゴーストセルによる壁の扱いは1次精度で、流量を保ったまま
+    // 離散解は解析解の 1/(1 + 2Δy²) 倍（Δy = 0.05 で 0.995 倍）になる
+    println!("  最大誤差: {:.4} (中心線速度の理論値 1.5)", max_err);
+}
+
+fn main() {
+    // Re = 1000 は定常状態まで数万ステップかかるので、--release での実行を推奨
+    lid_driven_cavity(100.0, 64, &GHIA_U_100, &GHIA_V_100);
+    lid_driven_cavity(1000.0, 128, &GHIA_U_1000, &GHIA_V_1000);
+    channel_flow();
+}
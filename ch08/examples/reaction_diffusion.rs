@@ -0,0 +1,415 @@
+use ndarray::{Array2, Zip};
+use plotters::prelude::*;
+use std::error::Error;
+use std::f64::consts::PI;
+
+// 多成分の反応拡散方程式
+//   ∂u_s/∂t = D_s ∇²u_s + R_s(u_1, ..., u_S)
+// を IMEX 法（拡散項は陰的、反応項は陽的）で解く:
+//   (I - Δt D_s ∇²) u_s^(n+1) = u_s^n + Δt R_s(u^n)
+// 拡散項を陰的に扱うので、陽解法の安定条件 D Δt/Δx² ≤ 1/4 に縛られない。
+
+/// 反応項（反応速度論）を表すトレイト（S は化学種の数）
+trait Kinetics<const S: usize> {
+    fn reaction(&self, u: [f64; S]) -> [f64; S];
+}
+
+/// Gray–Scott モデル
+///   R_u = -uv² + F(1 - u),  R_v = uv² - (F + k)v
+struct GrayScott {
+    f: f64,
+    k: f64,
+}
+
+impl Kinetics<2> for GrayScott {
+    fn reaction(&self, u: [f64; 2]) -> [f64; 2] {
+        let uvv = u[0] * u[1] * u[1];
+        [-uvv + self.f * (1.0 - u[0]), uvv - (self.f + self.k) * u[1]]
+    }
+}
+
+/// FitzHugh–Nagumo モデル（興奮性媒質, Winfree (1991) の形）
+///   R_u = (u - u³/3 - v)/ε,  R_v = ε(u + β - γv)
+struct FitzHughNagumo {
+    epsilon: f64,
+    beta: f64,
+    gamma: f64,
+}
+
+impl Kinetics<2> for FitzHughNagumo {
+    fn reaction(&self, u: [f64; 2]) -> [f64; 2] {
+        [
+            (u[0] - u[0].powi(3) / 3.0 - u[1]) / self.epsilon,
+            self.epsilon * (u[0] + self.beta - self.gamma * u[1]),
+        ]
+    }
+}
+
+impl FitzHughNagumo {
+    /// 一様な静止状態（2本のヌルクラインの交点）をニュートン法で求める
+    fn rest_state(&self) -> (f64, f64) {
+        // v = (u + β)/γ を u - u³/3 - v = 0 に代入した方程式
+        let g = |u: f64| u - u.powi(3) / 3.0 - (u + self.beta) / self.gamma;
+        let dg = |u: f64| 1.0 - u * u - 1.0 / self.gamma;
+        let mut u = -1.0;
+        for _ in 0..50 {
+            u -= g(u) / dg(u);
+        }
+        (u, (u + self.beta) / self.gamma)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Boundary {
+    Periodic,
+    /// ゼロ流束（ノイマン）境界
+    Neumann,
+}
+
+struct ReactionDiffusion<K: Kinetics<S>, const S: usize> {
+    kinetics: K,
+    diffusion: [f64; S],
+    dx: f64,
+    dt: f64,
+    boundary: Boundary,
+    fields: [Array2<f64>; S],
+    time: f64,
+}
+
+impl<K: Kinetics<S>, const S: usize> ReactionDiffusion<K, S> {
+    fn new(
+        kinetics: K,
+        diffusion: [f64; S],
+        n: usize,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Self {
+        Self {
+            kinetics,
+            diffusion,
+            dx,
+            dt,
+            boundary,
+            fields: std::array::from_fn(|_| Array2::zeros((n, n))),
+            time: 0.0,
+        }
+    }
+
+    /// 5点差分ラプラシアン（境界条件込み）を out に書き込む
+    fn laplacian(&self, u: &Array2<f64>, out: &mut Array2<f64>) {
+        let (ny, nx) = u.dim();
+        let neighbor = |i: usize, d: isize, n: usize| -> usize {
+            let j = i as isize + d;
+            match self.boundary {
+                Boundary::Periodic => j.rem_euclid(n as isize) as usize,
+                // 領域外の点は自分自身で置き換える（鏡像 = 勾配ゼロ）
+                Boundary::Neumann => {
+                    if j < 0 || j >= n as isize {
+                        i
+                    } else {
+                        j as usize
+                    }
+                }
+            }
+        };
+        let inv_dx2 = 1.0 / (self.dx * self.dx);
+        for y in 0..ny {
+            let (up, down) = (neighbor(y, -1, ny), neighbor(y, 1, ny));
+            for x in 0..nx {
+                let (left, right) = (neighbor(x, -1, nx), neighbor(x, 1, nx));
+                out[[y, x]] = (u[[up, x]] + u[[down, x]] + u[[y, left]] + u[[y, right]]
+                    - 4.0 * u[[y, x]])
+                    * inv_dx2;
+            }
+        }
+    }
+
+    /// (I - Δt D ∇²) u = b を共役勾配法で解く（行列は作らずに作用だけを計算する）
+    /// 係数行列は対称正定値で対角優位なので、数回の反復で収束する
+    fn implicit_diffusion(&self, d: f64, b: &Array2<f64>) -> (Array2<f64>, usize) {
+        let a = d * self.dt;
+        // A p = p - a ∇²p
+        let apply = |p: &Array2<f64>, out: &mut Array2<f64>| {
+            self.laplacian(p, out);
+            Zip::from(out).and(p).for_each(|o, &p| *o = p - a * *o);
+        };
+        let dot = |x: &Array2<f64>, y: &Array2<f64>| {
+            Zip::from(x).and(y).fold(0.0, |acc, &a, &b| acc + a * b)
+        };
+
+        // 初期値は右辺 b（Δt が小さければ解に近い）
+        let mut x = b.clone();
+        let mut ap = Array2::zeros(b.dim());
+        apply(&x, &mut ap);
+        let mut r = b - &ap;
+        let mut p = r.clone();
+        let mut rr = dot(&r, &r);
+        let b_norm = dot(b, b).sqrt().max(1e-300);
+
+        for iter in 0..200 {
+            if rr.sqrt() / b_norm < 1e-10 {
+                return (x, iter);
+            }
+            apply(&p, &mut ap);
+            let alpha = rr / dot(&p, &ap);
+            x.scaled_add(alpha, &p);
+            r.scaled_add(-alpha, &ap);
+            let rr_new = dot(&r, &r);
+            let beta = rr_new / rr;
+            Zip::from(&mut p)
+                .and(&r)
+                .for_each(|p, &r| *p = r + beta * *p);
+            rr = rr_new;
+        }
+        (x, 200)
+    }
+
+    fn step(&mut self) {
+        let dim = self.fields[0].dim();
+
+        // 1. 反応項を陽的に評価: b_s = u_s + Δt R_s(u)
+        let mut rhs: [Array2<f64>; S] = std::array::from_fn(|_| Array2::zeros(dim));
+        for (idx, _) in self.fields[0].indexed_iter() {
+            let local: [f64; S] = std::array::from_fn(|s| self.fields[s][idx]);
+            let r = self.kinetics.reaction(local);
+            for s in 0..S {
+                rhs[s][idx] = local[s] + self.dt * r[s];
+            }
+        }
+
+        // 2. 拡散項を陰的に解く（拡散しない種はそのまま）
+        for (s, b) in rhs.into_iter().enumerate() {
+            self.fields[s] = if self.diffusion[s] > 0.0 {
+                self.implicit_diffusion(self.diffusion[s], &b).0
+            } else {
+                b
+            };
+        }
+        self.time += self.dt;
+    }
+}
+
+/// 場を PNG 画像として保存する（1セルを scale × scale ピクセルで描画）
+fn save_frame(field: &Array2<f64>, filename: &str, scale: u32) -> Result<(), Box<dyn Error>> {
+    let (ny, nx) = field.dim();
+    let root =
+        BitMapBackend::new(filename, (nx as u32 * scale, ny as u32 * scale)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let min = field.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = field.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = (max - min).max(1e-12);
+
+    for ((y, x), &v) in field.indexed_iter() {
+        let t = (v - min) / range;
+        // 簡易カラーマップ（青 → 白 → 赤）
+        let color = RGBColor(
+            (255.0 * (2.0 * t).min(1.0)) as u8,
+            (255.0 * (1.0 - (2.0 * t - 1.0).abs())) as u8,
+            (255.0 * (2.0 - 2.0 * t).min(1.0)) as u8,
+        );
+        let (px, py) = (x as i32 * scale as i32, y as i32 * scale as i32);
+        root.draw(&Rectangle::new(
+            [(px, py), (px + scale as i32, py + scale as i32)],
+            color.filled(),
+        ))?;
+    }
+    root.present()?;
+    Ok(())
+}
+
+/// しきい値を超えた領域の連結成分（4近傍）の大きさの一覧
+fn cluster_sizes(field: &Array2<f64>, threshold: f64) -> Vec<usize> {
+    let (ny, nx) = field.dim();
+    let mut visited = Array2::from_elem((ny, nx), false);
+    let mut sizes = Vec::new();
+    for ((y, x), &v) in field.indexed_iter() {
+        if v <= threshold || visited[[y, x]] {
+            continue;
+        }
+        // 深さ優先探索で連結成分をたどる
+        let mut stack = vec![(y, x)];
+        visited[[y, x]] = true;
+        let mut size = 0;
+        while let Some((cy, cx)) = stack.pop() {
+            size += 1;
+            let neighbors = [
+                (cy.wrapping_sub(1), cx),
+                (cy + 1, cx),
+                (cy, cx.wrapping_sub(1)),
+                (cy, cx + 1),
+            ];
+            for (ty, tx) in neighbors {
+                if ty < ny && tx < nx && !visited[[ty, tx]] && field[[ty, tx]] > threshold {
+                    visited[[ty, tx]] = true;
+                    stack.push((ty, tx));
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    sizes
+}
+
+/// 位相特異点（スパイラル波の先端）の数
+/// 位相 θ = atan2(v - v̄, u - ū) が格子の各単位胞を一周するときの巻き数を数える
+fn count_phase_singularities(u: &Array2<f64>, v: &Array2<f64>) -> usize {
+    let (ny, nx) = u.dim();
+    let (u_mean, v_mean) = (u.mean().unwrap(), v.mean().unwrap());
+    let phase = Array2::from_shape_fn((ny, nx), |idx| (v[idx] - v_mean).atan2(u[idx] - u_mean));
+    let wrap = |d: f64| (d + PI).rem_euclid(2.0 * PI) - PI;
+
+    let mut count = 0;
+    for y in 0..ny - 1 {
+        for x in 0..nx - 1 {
+            let loop_phase = [
+                phase[[y, x]],
+                phase[[y, x + 1]],
+                phase[[y + 1, x + 1]],
+                phase[[y + 1, x]],
+            ];
+            let winding: f64 = (0..4)
+                .map(|k| wrap(loop_phase[(k + 1) % 4] - loop_phase[k]))
+                .sum();
+            if winding.abs() > PI {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Gray–Scott モデルを計算し、最終状態の v の連結成分からパターンを判定する
+fn run_gray_scott(name: &str, f: f64, k: f64) -> Result<(), Box<dyn Error>> {
+    let n = 128;
+    // Pearson (1993) と同じ設定: 領域の一辺 2.5 を 256 分割したときと同じ格子幅
+    let dx = 2.5 / 256.0;
+    let dt = 1.0;
+    let mut rd = ReactionDiffusion::new(
+        GrayScott { f, k },
+        [2e-5, 1e-5],
+        n,
+        dx,
+        dt,
+        Boundary::Periodic,
+    );
+
+    // 初期条件: u = 1, v = 0 の一様状態の中央に乱れを加える
+    rd.fields[0].fill(1.0);
+    for y in n / 2 - 10..n / 2 + 10 {
+        for x in n / 2 - 10..n / 2 + 10 {
+            // 決定的な擬似ノイズで対称性を崩す
+            let noise = 0.02 * (((x * 7919 + y * 104_729) % 101) as f64 / 101.0 - 0.5);
+            rd.fields[0][[y, x]] = 0.5 + noise;
+            rd.fields[1][[y, x]] = 0.25 - noise;
+        }
+    }
+
+    let n_steps = 5000;
+    for step in 1..=n_steps {
+        rd.step();
+        if step % 1250 == 0 {
+            let filename = format!("gray_scott_{}_{:05}.png", name, step);
+            save_frame(&rd.fields[1], &filename, 2)?;
+        }
+    }
+
+    let sizes = cluster_sizes(&rd.fields[1], 0.2);
+    let largest = sizes.iter().max().copied().unwrap_or(0);
+    let covered: usize = sizes.iter().sum();
+    let largest_fraction = largest as f64 / (n * n) as f64;
+    // 孤立した小さな斑点が多数なら「スポット」、大きくつながった領域なら「ストライプ（迷路）」
+    let pattern = if sizes.is_empty() {
+        "一様（パターンなし）"
+    } else if largest_fraction < 0.01 {
+        "スポット"
+    } else {
+        "ストライプ（迷路状）"
+    };
+
+    println!(
+        "{:<8} F = {:.4}, k = {:.4}: 連結成分 {:>4} 個, 被覆率 {:>5.1}%, 最大成分 {:>5.2}% → {}",
+        name,
+        f,
+        k,
+        sizes.len(),
+        100.0 * covered as f64 / (n * n) as f64,
+        100.0 * largest_fraction,
+        pattern
+    );
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("=== Gray–Scott モデル (D_u = 2e-5, D_v = 1e-5, t = 5000) ===");
+    run_gray_scott("spots", 0.0367, 0.0649)?;
+    run_gray_scott("stripes", 0.0545, 0.062)?;
+
+    // ----------------------------------------
+    // FitzHugh–Nagumo モデルのスパイラル波
+    // ----------------------------------------
+    println!("\n=== FitzHugh–Nagumo モデル (ε = 0.3, β = 0.7, γ = 0.5) ===");
+    let n = 160;
+    let fhn = FitzHughNagumo {
+        epsilon: 0.3,
+        beta: 0.7,
+        gamma: 0.5,
+    };
+    let (u_rest, v_rest) = fhn.rest_state();
+    println!("静止状態: (u, v) = ({:.4}, {:.4})", u_rest, v_rest);
+    // 抑制因子 v は拡散しない
+    let mut rd = ReactionDiffusion::new(fhn, [1.0, 0.0], n, 0.5, 0.1, Boundary::Neumann);
+
+    // 静止状態に対し、左上の1/4を興奮状態、下半分を不応状態にする（クロスフィールド法）
+    // 波面の端が不応領域で途切れ、そこを中心にスパイラルが巻き込む
+    for ((y, x), u) in rd.fields[0].indexed_iter_mut() {
+        *u = if x < n / 2 && y >= n / 2 { 2.0 } else { u_rest };
+    }
+    for ((y, _), v) in rd.fields[1].indexed_iter_mut() {
+        *v = if y < n / 2 { 0.5 } else { v_rest };
+    }
+
+    let probe = [n / 4, 3 * n / 4];
+    let mut last_u = rd.fields[0][probe];
+    let mut spikes = Vec::new();
+    let n_steps = 4000;
+    for step in 1..=n_steps {
+        rd.step();
+        let u = rd.fields[0][probe];
+        // 観測点での興奮（u が 0 を上向きに横切る時刻）を記録する
+        if last_u < 0.0 && u >= 0.0 {
+            spikes.push(rd.time);
+        }
+        last_u = u;
+        if step % 1000 == 0 {
+            let filename = format!("fitzhugh_nagumo_{:05}.png", step);
+            save_frame(&rd.fields[0], &filename, 2)?;
+            println!(
+                "t = {:>6.1}: 位相特異点（スパイラルの先端）の数 = {}",
+                rd.time,
+                count_phase_singularities(&rd.fields[0], &rd.fields[1])
+            );
+        }
+    }
+
+    // スパイラルが回転し続けていれば、観測点は周期的に興奮する
+    if spikes.len() >= 3 {
+        let periods: Vec<f64> = spikes.windows(2).map(|w| w[1] - w[0]).collect();
+        let tail = &periods[periods.len() / 2..];
+        let mean = tail.iter().sum::<f64>() / tail.len() as f64;
+        println!(
+            "観測点の興奮回数: {}, 後半の平均周期: {:.2}",
+            spikes.len(),
+            mean
+        );
+    } else {
+        println!(
+            "観測点の興奮回数: {} (スパイラルは形成されませんでした)",
+            spikes.len()
+        );
+    }
+
+    println!("\nスナップショットを gray_scott_*.png, fitzhugh_nagumo_*.png に保存しました");
+    Ok(())
+}
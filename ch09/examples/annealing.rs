@@ -0,0 +1,541 @@
+use ch09::metropolis;
+use ch09::rng::stream_rng;
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+use rayon::prelude::*;
+use std::f64::consts::PI;
+use std::time::Instant;
+
+/// 最小化したい問題（エネルギー関数と近傍状態の提案）
+/// 連続な状態でも離散的な状態でも同じ Metropolis 法で扱える
+trait Problem: Sync {
+    type State: Clone + Send;
+    fn energy(&self, state: &Self::State) -> f64;
+    /// 現在の状態の近傍から候補を1つ提案する（提案は対称であること）
+    fn propose(&self, state: &Self::State, rng: &mut ChaCha8Rng) -> Self::State;
+}
+
+/// mcmc.rs と共通の受理判定 metropolis::accept に、比 p(x')/p(x) = exp(-βΔE) を渡す1ステップ
+/// 受理したら state と energy を更新して true を返す
+fn metropolis_step<P: Problem>(
+    problem: &P,
+    state: &mut P::State,
+    energy: &mut f64,
+    beta: f64,
+    rng: &mut ChaCha8Rng,
+) -> bool {
+    let candidate = problem.propose(state, rng);
+    let e_new = problem.energy(&candidate);
+    if metropolis::accept((-beta * (e_new - *energy)).exp(), rng) {
+        *state = candidate;
+        *energy = e_new;
+        true
+    } else {
+        false
+    }
+}
+
+/// 冷却スケジュール（ステップ k / 全 n ステップでの温度）
+#[derive(Clone, Copy, Debug)]
+enum Schedule {
+    /// 等比的な冷却 T_k = T₀ (T_end/T₀)^(k/n)
+    Geometric { t0: f64, t_end: f64 },
+    /// 線形な冷却 T_k = T₀ + (T_end - T₀) k/n
+    Linear { t0: f64, t_end: f64 },
+    /// 対数的な冷却 T_k = T₀ / ln(e + k)（大域的最小への収束が保証されるが遅い）
+    Logarithmic { t0: f64 },
+}
+
+impl Schedule {
+    /// 温度が正（線形冷却の終点と T = 0 の局所探索は 0 も可）であることを確かめる
+    fn validate(&self) {
+        match *self {
+            Schedule::Geometric { t0, t_end } => assert!(
+                t0 > 0.0 && t_end > 0.0,
+                "等比冷却の温度は正であること (T₀ = {t0}, T_end = {t_end})"
+            ),
+            Schedule::Linear { t0, t_end } => assert!(
+                t0 >= 0.0 && t_end >= 0.0,
+                "線形冷却の温度は 0 以上であること (T₀ = {t0}, T_end = {t_end})"
+            ),
+            Schedule::Logarithmic { t0 } => {
+                assert!(t0 > 0.0, "対数冷却の温度は正であること (T₀ = {t0})")
+            }
+        }
+    }
+
+    fn temperature(&self, k: usize, n: usize) -> f64 {
+        let s = k as f64 / n as f64;
+        match *self {
+            Schedule::Geometric { t0, t_end } => t0 * (t_end / t0).powf(s),
+            Schedule::Linear { t0, t_end } => t0 + (t_end - t0) * s,
+            Schedule::Logarithmic { t0 } => t0 / (std::f64::consts::E + k as f64).ln(),
+        }
+    }
+}
+
+/// 焼きなまし法。これまでに見つけた最良の状態とそのエネルギーを返す
+fn simulated_annealing<P: Problem>(
+    problem: &P,
+    init: P::State,
+    schedule: Schedule,
+    n_steps: usize,
+    rng: &mut ChaCha8Rng,
+) -> (P::State, f64) {
+    schedule.validate();
+    let mut state = init;
+    let mut energy = problem.energy(&state);
+    let mut best = (state.clone(), energy);
+    for k in 0..n_steps {
+        let t = schedule.temperature(k, n_steps).max(1e-12);
+        metropolis_step(problem, &mut state, &mut energy, 1.0 / t, rng);
+        if energy < best.1 {
+            best = (state.clone(), energy);
+        }
+    }
+    best
+}
+
+/// 交換モンテカルロ法（レプリカ交換法）の結果
+struct TemperingResult<S> {
+    best: (S, f64),
+    /// 隣り合う温度の組ごとの交換の受理率
+    swap_rates: Vec<f64>,
+}
+
+/// 交換モンテカルロ法
+/// 各温度のレプリカを rayon で並列に sweep ステップ進め、隣り合う温度の間で状態の交換を試みる
+/// 交換の受理確率 min(1, exp((β_i - β_j)(E_i - E_j)))
+fn parallel_tempering<P: Problem>(
+    problem: &P,
+    init: P::State,
+    temperatures: &[f64],
+    n_rounds: usize,
+    sweep: usize,
+    seed: u64,
+) -> TemperingResult<P::State> {
+    let n_rep = temperatures.len();
+    let betas: Vec<f64> = temperatures.iter().map(|t| 1.0 / t).collect();
+    // レプリカ r はストリーム r + 1、交換判定はストリーム n_rep + 1 の乱数を使う
+    // （スレッド数によらず同じ結果になる。ストリーム 0 は seed_from_u64(seed) と同じ列なので使わない）
+    let mut replicas: Vec<(P::State, f64, ChaCha8Rng)> = (0..n_rep)
+        .map(|r| {
+            let rng = stream_rng(seed, r as u64 + 1);
+            (init.clone(), problem.energy(&init), rng)
+        })
+        .collect();
+    let mut swap_rng = stream_rng(seed, n_rep as u64 + 1);
+    let mut accepted = vec![0usize; n_rep - 1];
+    let mut attempted = vec![0usize; n_rep - 1];
+    let mut best = (init.clone(), problem.energy(&init));
+
+    for round in 0..n_rounds {
+        let round_best = replicas
+            .par_iter_mut()
+            .zip(&betas)
+            .map(|((state, energy, rng), &beta)| {
+                let mut local_best = (state.clone(), *energy);
+                for _ in 0..sweep {
+                    metropolis_step(problem, state, energy, beta, rng);
+                    if *energy < local_best.1 {
+                        local_best = (state.clone(), *energy);
+                    }
+                }
+                local_best
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        if round_best.1 < best.1 {
+            best = round_best;
+        }
+
+        // 偶数組と奇数組を交互に交換する
+        for i in ((round % 2)..n_rep - 1).step_by(2) {
+            attempted[i] += 1;
+            let delta = (betas[i] - betas[i + 1]) * (replicas[i].1 - replicas[i + 1].1);
+            if metropolis::accept(delta.exp(), &mut swap_rng) {
+                let (left, right) = replicas.split_at_mut(i + 1);
+                std::mem::swap(&mut left[i].0, &mut right[0].0);
+                std::mem::swap(&mut left[i].1, &mut right[0].1);
+                accepted[i] += 1;
+            }
+        }
+    }
+
+    TemperingResult {
+        best,
+        swap_rates: accepted
+            .iter()
+            .zip(&attempted)
+            .map(|(&a, &n)| a as f64 / n.max(1) as f64)
+            .collect(),
+    }
+}
+
+/// 等比数列の温度 T_min, ..., T_max
+fn geometric_temperatures(t_min: f64, t_max: f64, n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|i| t_min * (t_max / t_min).powf(i as f64 / (n - 1) as f64))
+        .collect()
+}
+
+// ----------------------------------------
+// 問題1: Rastrigin 関数（連続、局所解が多数）
+// ----------------------------------------
+
+/// f(x) = 10d + Σ (x_i² - 10 cos 2πx_i)、x ∈ [-5.12, 5.12]^d、最小値 f(0) = 0
+struct Rastrigin {
+    dim: usize,
+}
+
+impl Problem for Rastrigin {
+    type State = Vec<f64>;
+
+    fn energy(&self, x: &Vec<f64>) -> f64 {
+        10.0 * self.dim as f64
+            + x.iter()
+                .map(|&xi| xi * xi - 10.0 * (2.0 * PI * xi).cos())
+                .sum::<f64>()
+    }
+
+    fn propose(&self, x: &Vec<f64>, rng: &mut ChaCha8Rng) -> Vec<f64> {
+        // 1成分だけを小さく動かす。隣の谷へは熱的に障壁を越えないと移れない
+        // （f < 0.5 なら大域的最小の谷に入っている）
+        let mut y = x.clone();
+        let i = rng.random_range(0..self.dim);
+        let xi: f64 = StandardNormal.sample(rng);
+        y[i] = (y[i] + 0.2 * xi).clamp(-5.12, 5.12);
+        y
+    }
+}
+
+// ----------------------------------------
+// 問題2: 巡回セールスマン問題（離散）
+// ----------------------------------------
+
+struct Tsp {
+    cities: Vec<[f64; 2]>,
+}
+
+impl Tsp {
+    fn dist(&self, a: usize, b: usize) -> f64 {
+        let (p, q) = (self.cities[a], self.cities[b]);
+        ((p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2)).sqrt()
+    }
+
+    /// 最近傍法による初期解（比較用）
+    fn nearest_neighbor_tour(&self) -> Vec<usize> {
+        let n = self.cities.len();
+        let mut tour = vec![0];
+        let mut visited = vec![false; n];
+        visited[0] = true;
+        for _ in 1..n {
+            let last = *tour.last().unwrap();
+            let next = (0..n)
+                .filter(|&c| !visited[c])
+                .min_by(|&a, &b| self.dist(last, a).total_cmp(&self.dist(last, b)))
+                .unwrap();
+            visited[next] = true;
+            tour.push(next);
+        }
+        tour
+    }
+}
+
+impl Problem for Tsp {
+    type State = Vec<usize>;
+
+    fn energy(&self, tour: &Vec<usize>) -> f64 {
+        let n = tour.len();
+        (0..n).map(|i| self.dist(tour[i], tour[(i + 1) % n])).sum()
+    }
+
+    fn propose(&self, tour: &Vec<usize>, rng: &mut ChaCha8Rng) -> Vec<usize> {
+        // 2-opt 近傍: 区間 [i, j] を反転する
+        let n = tour.len();
+        let i = rng.random_range(0..n - 1);
+        let j = rng.random_range(i + 1..n);
+        let mut new_tour = tour.clone();
+        new_tour[i..=j].reverse();
+        new_tour
+    }
+}
+
+// ----------------------------------------
+// 問題3: Lennard-Jones クラスター
+// ----------------------------------------
+
+/// N 原子の LJ ポテンシャル E = Σ 4(r⁻¹² - r⁻⁶)（ε = σ = 1）
+struct LennardJonesCluster {
+    n: usize,
+}
+
+impl Problem for LennardJonesCluster {
+    type State = Vec<[f64; 3]>;
+
+    fn energy(&self, pos: &Vec<[f64; 3]>) -> f64 {
+        let mut e = 0.0;
+        for i in 0..self.n {
+            for j in (i + 1)..self.n {
+                let r2: f64 = (0..3).map(|k| (pos[i][k] - pos[j][k]).powi(2)).sum();
+                let r6_inv = 1.0 / (r2 * r2 * r2);
+                e += 4.0 * (r6_inv * r6_inv - r6_inv);
+            }
+        }
+        // 原子が遠くへ飛び去らないように重心から半径 2.5 の球に閉じ込める
+        let center: Vec<f64> = (0..3)
+            .map(|k| pos.iter().map(|p| p[k]).sum::<f64>() / self.n as f64)
+            .collect();
+        for p in pos {
+            let r2: f64 = (0..3).map(|k| (p[k] - center[k]).powi(2)).sum();
+            if r2 > 2.5 * 2.5 {
+                e += 100.0 * (r2.sqrt() - 2.5).powi(2);
+            }
+        }
+        e
+    }
+
+    fn propose(&self, pos: &Vec<[f64; 3]>, rng: &mut ChaCha8Rng) -> Vec<[f64; 3]> {
+        let mut new_pos = pos.clone();
+        let i = rng.random_range(0..self.n);
+        let scale = if rng.random::<bool>() { 0.3 } else { 0.03 };
+        for x in new_pos[i].iter_mut() {
+            let xi: f64 = StandardNormal.sample(rng);
+            *x += scale * xi;
+        }
+        new_pos
+    }
+}
+
+fn main() {
+    let seed = 2024;
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    // ----------------------------------------
+    // 1. Rastrigin 関数 (d = 5)
+    // ----------------------------------------
+    let rastrigin = Rastrigin { dim: 5 };
+    let n_steps = 1_000_000;
+    let n_runs = 8;
+    println!(
+        "=== 1. Rastrigin 関数 (d = 5, 最小値 0, {} ステップ × {} 回) ===",
+        n_steps, n_runs
+    );
+    println!(
+        "{:<46} {:>10} {:>10} {:>14}",
+        "方法", "平均", "最良", "f < 0.5 の回数"
+    );
+    let inits: Vec<Vec<f64>> = (0..n_runs)
+        .map(|_| (0..5).map(|_| rng.random_range(-5.12..5.12)).collect())
+        .collect();
+    let schedules = [
+        (
+            "T = 0 の局所探索（貪欲法）",
+            Schedule::Linear {
+                t0: 0.0,
+                t_end: 0.0,
+            },
+        ),
+        (
+            "焼きなまし（等比冷却 10 → 0.001）",
+            Schedule::Geometric {
+                t0: 10.0,
+                t_end: 1e-3,
+            },
+        ),
+        (
+            "焼きなまし（線形冷却 10 → 0）",
+            Schedule::Linear {
+                t0: 10.0,
+                t_end: 0.0,
+            },
+        ),
+        (
+            "焼きなまし（対数冷却 T₀ = 5）",
+            Schedule::Logarithmic { t0: 5.0 },
+        ),
+    ];
+    for (name, schedule) in schedules {
+        let results: Vec<f64> = inits
+            .iter()
+            .map(|x0| simulated_annealing(&rastrigin, x0.clone(), schedule, n_steps, &mut rng).1)
+            .collect();
+        print_summary(name, &results, 0.5);
+    }
+    // 焼きなましと同じ評価回数になるように、
+    // 8 レプリカ × 1250 ラウンド × 100 ステップ（1 レプリカあたり 125000 ステップ）
+    let temps = geometric_temperatures(0.01, 10.0, 8);
+    let sweep = 100;
+    let n_rounds = n_steps / (temps.len() * sweep);
+    let (results, swap_rates): (Vec<f64>, Vec<Vec<f64>>) = inits
+        .iter()
+        .enumerate()
+        .map(|(r, x0)| {
+            let res = parallel_tempering(
+                &rastrigin,
+                x0.clone(),
+                &temps,
+                n_rounds,
+                sweep,
+                seed + r as u64,
+            );
+            (res.best.1, res.swap_rates)
+        })
+        .unzip();
+    print_summary("交換モンテカルロ（8 温度, 0.01 〜 10）", &results, 0.5);
+    // 交換受理率は全 run の平均
+    let mean_swap_rates: Vec<f64> = (0..temps.len() - 1)
+        .map(|i| swap_rates.iter().map(|rates| rates[i]).sum::<f64>() / n_runs as f64)
+        .collect();
+    println!(
+        "  隣り合う温度間の交換受理率（{} 回の平均）: {}",
+        n_runs,
+        mean_swap_rates
+            .iter()
+            .map(|r| format!("{:.2}", r))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    // ----------------------------------------
+    // 2. 巡回セールスマン問題
+    // ----------------------------------------
+    println!("\n=== 2. 巡回セールスマン問題 ===");
+    // 円周上の都市: 最適解は角度順に回る巡回路で、長さは内接多角形の周長
+    let n_cities = 40;
+    let mut angles: Vec<f64> = (0..n_cities)
+        .map(|_| rng.random_range(0.0..2.0 * PI))
+        .collect();
+    let circle = Tsp {
+        cities: angles.iter().map(|&a| [a.cos(), a.sin()]).collect(),
+    };
+    angles.sort_by(|a, b| a.total_cmp(b));
+    let optimal: f64 = (0..n_cities)
+        .map(|i| {
+            let d = (angles[(i + 1) % n_cities] - angles[i]).rem_euclid(2.0 * PI);
+            2.0 * (0.5 * d).sin()
+        })
+        .sum();
+    let random_tour: Vec<usize> = (0..n_cities).collect();
+    let (_, sa) = simulated_annealing(
+        &circle,
+        random_tour.clone(),
+        Schedule::Geometric {
+            t0: 1.0,
+            t_end: 1e-3,
+        },
+        200_000,
+        &mut rng,
+    );
+    println!(
+        "円周上の {} 都市: 焼きなまし = {:.6}, 最適値 = {:.6}",
+        n_cities, sa, optimal
+    );
+
+    let n_cities = 60;
+    let random_cities = Tsp {
+        cities: (0..n_cities)
+            .map(|_| [rng.random(), rng.random()])
+            .collect(),
+    };
+    let nn = random_cities.nearest_neighbor_tour();
+    println!("\n単位正方形内のランダムな {} 都市:", n_cities);
+    println!("  最近傍法:         {:.4}", random_cities.energy(&nn));
+    println!("  最近傍法 + 2-opt 局所探索: {:.4}", {
+        let schedule = Schedule::Linear {
+            t0: 0.0,
+            t_end: 0.0,
+        };
+        simulated_annealing(&random_cities, nn.clone(), schedule, 300_000, &mut rng).1
+    });
+    let start = Instant::now();
+    let (_, sa) = simulated_annealing(
+        &random_cities,
+        (0..n_cities).collect(),
+        Schedule::Geometric {
+            t0: 0.5,
+            t_end: 1e-3,
+        },
+        300_000,
+        &mut rng,
+    );
+    println!(
+        "  焼きなまし:       {:.4} ({:.2} s)",
+        sa,
+        start.elapsed().as_secs_f64()
+    );
+    let start = Instant::now();
+    let pt = parallel_tempering(
+        &random_cities,
+        (0..n_cities).collect(),
+        &geometric_temperatures(0.002, 0.3, 8),
+        750,
+        50,
+        seed,
+    );
+    println!(
+        "  交換モンテカルロ: {:.4} ({:.2} s)",
+        pt.best.1,
+        start.elapsed().as_secs_f64()
+    );
+    println!(
+        "  (N 都市のランダム配置の最適巡回路長の漸近式 (N → ∞): 0.7124 √(N·面積) = {:.4})",
+        0.7124 * (n_cities as f64).sqrt()
+    );
+
+    // ----------------------------------------
+    // 3. Lennard-Jones クラスターの最安定構造
+    // ----------------------------------------
+    println!("\n=== 3. Lennard-Jones クラスター ===");
+    println!(
+        "{:>4} {:>14} {:>18} {:>14}",
+        "N", "焼きなまし", "交換モンテカルロ", "既知の最小値"
+    );
+    for (n, known) in [(7, -16.505384), (13, -44.326801)] {
+        let lj = LennardJonesCluster { n };
+        // 立方体内のランダムな配置から始める
+        let init: Vec<[f64; 3]> = (0..n)
+            .map(|_| {
+                [
+                    rng.random_range(0.0..2.0),
+                    rng.random_range(0.0..2.0),
+                    rng.random_range(0.0..2.0),
+                ]
+            })
+            .collect();
+        let (_, sa) = simulated_annealing(
+            &lj,
+            init.clone(),
+            Schedule::Geometric {
+                t0: 1.0,
+                t_end: 1e-4,
+            },
+            1_000_000,
+            &mut rng,
+        );
+        let pt = parallel_tempering(
+            &lj,
+            init,
+            &geometric_temperatures(0.005, 0.5, 8),
+            1250,
+            100,
+            seed,
+        );
+        println!("{:>4} {:>14.6} {:>18.6} {:>14.6}", n, sa, pt.best.1, known);
+    }
+}
+
+fn print_summary(name: &str, results: &[f64], threshold: f64) {
+    let mean = results.iter().sum::<f64>() / results.len() as f64;
+    let best = results.iter().cloned().fold(f64::INFINITY, f64::min);
+    let hits = results.iter().filter(|&&e| e < threshold).count();
+    println!(
+        "{:<46} {:>10.4} {:>10.4} {:>10} / {}",
+        name,
+        mean,
+        best,
+        hits,
+        results.len()
+    );
+}
@@ -0,0 +1,517 @@
+use ch03::quadrature::{Estimate, gauss_legendre};
+use ch09::qmc::{Halton, UniformCube, rqmc_integrate};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::Distribution;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+// ----------------------------------------
+// テンソル積ガウス・ルジャンドル則
+// ----------------------------------------
+
+/// [0, 1] 上の n 点ガウス・ルジャンドル則（節点, 重み）
+/// ch03 の quadrature.rs と同じ [-1, 1] の規則を変数変換する
+fn gauss_legendre_01(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let (nodes, weights) = gauss_legendre(n);
+    (
+        nodes.iter().map(|x| 0.5 * (1.0 - x)).collect(),
+        weights.iter().map(|w| 0.5 * w).collect(),
+    )
+}
+
+/// 1 次元則の直積で [0,1]^d を積分する（n^d 点）
+fn tensor_rule<F: Fn(&[f64]) -> f64>(f: &F, d: usize, nodes: &[f64], weights: &[f64]) -> f64 {
+    let n = nodes.len();
+    let mut index = vec![0usize; d];
+    let mut x = vec![nodes[0]; d];
+    let mut sum = 0.0;
+    loop {
+        let w: f64 = index.iter().map(|&i| weights[i]).product();
+        sum += w * f(&x);
+        // 多重添字を 1 つ進める（オドメーター方式）
+        let mut axis = 0;
+        loop {
+            if axis == d {
+                return sum;
+            }
+            index[axis] += 1;
+            if index[axis] < n {
+                x[axis] = nodes[index[axis]];
+                break;
+            }
+            index[axis] = 0;
+            x[axis] = nodes[0];
+            axis += 1;
+        }
+    }
+}
+
+/// テンソル積ガウス則: n 点則の値を返し、n-1 点則との差を誤差推定とする
+fn tensor_gauss<F: Fn(&[f64]) -> f64>(f: &F, d: usize, n: usize) -> Estimate {
+    let (x_n, w_n) = gauss_legendre_01(n);
+    let (x_m, w_m) = gauss_legendre_01(n - 1);
+    let q_n = tensor_rule(f, d, &x_n, &w_n);
+    let q_m = tensor_rule(f, d, &x_m, &w_m);
+    Estimate {
+        value: q_n,
+        error: (q_n - q_m).abs(),
+        n_evals: n.pow(d as u32) + (n - 1).pow(d as u32),
+    }
+}
+
+// ----------------------------------------
+// Smolyak スパースグリッド
+// ----------------------------------------
+
+/// 入れ子のクレンショウ・カーティス則（レベル l: 1 点, 3 点, 5 点, 9 点, 17 点, ...）
+///
+/// 節点は x_k = (1 - cos(π k / N)) / 2, N = 2^(max_level-1) の共通格子上にあり、
+/// 整数 k を節点の識別子として返す（レベル間で同じ点を確実に同一視するため）。
+fn clenshaw_curtis(level: usize, max_level: usize) -> Vec<(u32, f64)> {
+    let grid = 1u32 << (max_level - 1);
+    if level == 1 {
+        return vec![(grid / 2, 1.0)];
+    }
+    let n = 1usize << (level - 1); // 区間数（点数は n + 1）
+    let stride = grid / n as u32;
+    (0..=n)
+        .map(|j| {
+            // [-1, 1] 上の重み（合計 2）を [0, 1] 用に半分にする
+            let c = if j == 0 || j == n { 1.0 } else { 2.0 };
+            let mut s = 0.0;
+            for k in 1..=n / 2 {
+                let b = if 2 * k == n { 1.0 } else { 2.0 };
+                s +=
+                    b / (4.0 * (k * k) as f64 - 1.0) * (2.0 * PI * (k * j) as f64 / n as f64).cos();
+            }
+            (j as u32 * stride, 0.5 * c / n as f64 * (1.0 - s))
+        })
+        .collect()
+}
+
+/// Smolyak 則 A(q, d) を組み合わせ法で構成する: 節点（格子番号の組）→ 重み
+///
+///   A(q, d) = Σ_{q-d+1 ≤ |i| ≤ q} (-1)^{q-|i|} C(d-1, q-|i|) (U^{i_1} ⊗ ... ⊗ U^{i_d})
+///
+/// 同じ節点に複数の直積則から寄与があるので、重みをまとめて評価回数を減らす。
+fn smolyak_rule(d: usize, level: usize, max_level: usize) -> HashMap<Vec<u32>, f64> {
+    let q = d + level - 1;
+    let rules: Vec<Vec<(u32, f64)>> = (1..=level).map(|l| clenshaw_curtis(l, max_level)).collect();
+    let binomial = |n: usize, k: usize| -> f64 {
+        (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+    };
+
+    let mut weights: HashMap<Vec<u32>, f64> = HashMap::new();
+    let mut multi_index = vec![1usize; d];
+    loop {
+        let total: usize = multi_index.iter().sum();
+        if total + d > q && total <= q {
+            let diff = q - total;
+            let coeff = if diff.is_multiple_of(2) { 1.0 } else { -1.0 } * binomial(d - 1, diff);
+            // 直積則の全節点に重みを加える
+            let mut point = vec![0usize; d];
+            'tensor: loop {
+                let mut key = Vec::with_capacity(d);
+                let mut w = coeff;
+                for (axis, &p) in point.iter().enumerate() {
+                    let (k, wk) = rules[multi_index[axis] - 1][p];
+                    key.push(k);
+                    w *= wk;
+                }
+                *weights.entry(key).or_insert(0.0) += w;
+                for axis in 0..d {
+                    point[axis] += 1;
+                    if point[axis] < rules[multi_index[axis] - 1].len() {
+                        continue 'tensor;
+                    }
+                    point[axis] = 0;
+                }
+                break;
+            }
+        }
+        // |i| ≤ q の範囲で多重添字を進める
+        let mut axis = 0;
+        loop {
+            if axis == d {
+                return weights;
+            }
+            multi_index[axis] += 1;
+            if multi_index[axis] <= level && multi_index.iter().sum::<usize>() <= q {
+                break;
+            }
+            multi_index[axis] = 1;
+            axis += 1;
+        }
+    }
+}
+
+/// Smolyak スパースグリッド: レベル L の値を返し、レベル L-1 との差を誤差推定とする
+/// クレンショウ・カーティス則は入れ子なので、L-1 の節点はすべて L の節点に含まれ追加評価は不要
+fn smolyak<F: Fn(&[f64]) -> f64>(f: &F, d: usize, level: usize) -> Estimate {
+    let fine = smolyak_rule(d, level, level);
+    let coarse = smolyak_rule(d, level - 1, level);
+    let grid = (1u32 << (level - 1)) as f64;
+
+    let mut cache: HashMap<&Vec<u32>, f64> = HashMap::with_capacity(fine.len());
+    let mut x = vec![0.0; d];
+    let mut q_fine = 0.0;
+    for (key, w) in &fine {
+        for (xi, &k) in x.iter_mut().zip(key) {
+            *xi = 0.5 * (1.0 - (PI * k as f64 / grid).cos());
+        }
+        let fx = f(&x);
+        cache.insert(key, fx);
+        q_fine += w * fx;
+    }
+    let q_coarse: f64 = coarse.iter().map(|(key, w)| w * cache[key]).sum();
+
+    Estimate {
+        value: q_fine,
+        error: (q_fine - q_coarse).abs(),
+        n_evals: fine.len(),
+    }
+}
+
+// ----------------------------------------
+// Genz–Malik の適応型キュバチャー
+// ----------------------------------------
+
+/// 超直方体の小領域（中心, 半幅, 積分値, 誤差推定, 次に分割する軸）
+struct Region {
+    center: Vec<f64>,
+    half: Vec<f64>,
+    value: f64,
+    error: f64,
+    split_axis: usize,
+}
+
+/// Genz–Malik 則の節点パラメータ
+const LAMBDA2: f64 = 0.358_568_582_800_318_1; // √(9/70)
+const LAMBDA4: f64 = 0.948_683_298_050_513_8; // √(9/10)
+const LAMBDA5: f64 = 0.688_247_201_611_685_3; // √(9/19)
+
+/// 1 つの領域に Genz–Malik 則（7 次）と埋め込み 5 次則を適用する
+///
+/// 節点: 中心, 各軸上 ±λ₂ と ±λ₄, 2 軸の組 (±λ₄, ±λ₄), 全頂点方向 (±λ₅, ..., ±λ₅)。
+/// 点数は 1 + 4d + 2d(d-1) + 2^d。分割する軸は 4 階差分が最大の軸を選ぶ。
+fn genz_malik<F: Fn(&[f64]) -> f64>(f: &F, center: Vec<f64>, half: Vec<f64>) -> Region {
+    let d = center.len();
+    let df = d as f64;
+    let volume: f64 = half.iter().map(|h| 2.0 * h).product();
+    let mut x = center.clone();
+
+    let f0 = f(&x);
+    let mut sum2 = 0.0;
+    let mut sum3 = 0.0;
+    let mut diffs = vec![0.0; d];
+    for i in 0..d {
+        let mut eval = |t: f64| {
+            x[i] = center[i] + t * half[i];
+            let v = f(&x);
+            x[i] = center[i];
+            v
+        };
+        let s2 = eval(LAMBDA2) + eval(-LAMBDA2);
+        let s3 = eval(LAMBDA4) + eval(-LAMBDA4);
+        sum2 += s2;
+        sum3 += s3;
+        // λ₂²/λ₄² = 1/7 により 2 階差分の主要項を打ち消した 4 階差分
+        diffs[i] = ((s2 - 2.0 * f0) - (s3 - 2.0 * f0) / 7.0).abs();
+    }
+
+    let mut sum4 = 0.0;
+    for i in 0..d {
+        for j in i + 1..d {
+            for (si, sj) in [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+                x[i] = center[i] + si * LAMBDA4 * half[i];
+                x[j] = center[j] + sj * LAMBDA4 * half[j];
+                sum4 += f(&x);
+            }
+            x[i] = center[i];
+            x[j] = center[j];
+        }
+    }
+
+    let mut sum5 = 0.0;
+    for signs in 0..1u64 << d {
+        for (k, xk) in x.iter_mut().enumerate() {
+            let s = if (signs >> k) & 1 == 1 { 1.0 } else { -1.0 };
+            *xk = center[k] + s * LAMBDA5 * half[k];
+        }
+        sum5 += f(&x);
+    }
+
+    let w1 = (12824.0 - 9120.0 * df + 400.0 * df * df) / 19683.0;
+    let w2 = 980.0 / 6561.0;
+    let w3 = (1820.0 - 400.0 * df) / 19683.0;
+    let w4 = 200.0 / 19683.0;
+    let w5 = 6859.0 / 19683.0 / 2f64.powi(d as i32);
+    let rule7 = volume * (w1 * f0 + w2 * sum2 + w3 * sum3 + w4 * sum4 + w5 * sum5);
+
+    let v1 = (729.0 - 950.0 * df + 50.0 * df * df) / 729.0;
+    let v2 = 245.0 / 486.0;
+    let v3 = (265.0 - 100.0 * df) / 1458.0;
+    let v4 = 25.0 / 729.0;
+    let rule5 = volume * (v1 * f0 + v2 * sum2 + v3 * sum3 + v4 * sum4);
+
+    // 4 階差分が（ほぼ）同じなら最も幅の広い軸を選ぶ
+    let max_diff = diffs.iter().cloned().fold(0.0, f64::max);
+    let split_axis = (0..d)
+        .filter(|&i| diffs[i] >= max_diff * (1.0 - 1e-10))
+        .max_by(|&i, &j| half[i].total_cmp(&half[j]))
+        .unwrap();
+
+    Region {
+        center,
+        half,
+        value: rule7,
+        error: (rule7 - rule5).abs(),
+        split_axis,
+    }
+}
+
+/// 適応型キュバチャー: 推定誤差が最大の領域を選んだ軸で二分割することを繰り返す
+fn adaptive_cubature<F: Fn(&[f64]) -> f64>(
+    f: &F,
+    d: usize,
+    tol_rel: f64,
+    max_evals: usize,
+) -> Estimate {
+    let points_per_region = 1 + 4 * d + 2 * d * (d - 1) + (1 << d);
+    let mut regions = vec![genz_malik(f, vec![0.5; d], vec![0.5; d])];
+    let mut n_evals = points_per_region;
+
+    loop {
+        let value: f64 = regions.iter().map(|r| r.value).sum();
+        let error: f64 = regions.iter().map(|r| r.error).sum();
+        if error <= tol_rel * value.abs() || n_evals + 2 * points_per_region > max_evals {
+            return Estimate {
+                value,
+                error,
+                n_evals,
+            };
+        }
+
+        let (idx, _) = regions
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.error.total_cmp(&b.1.error))
+            .unwrap();
+        let region = regions.swap_remove(idx);
+        let axis = region.split_axis;
+        let mut half = region.half.clone();
+        half[axis] *= 0.5;
+        for sign in [-1.0, 1.0] {
+            let mut center = region.center.clone();
+            center[axis] += sign * half[axis];
+            regions.push(genz_malik(f, center, half.clone()));
+        }
+        n_evals += 2 * points_per_region;
+    }
+}
+
+// ----------------------------------------
+// モンテカルロ法・乱択化 QMC（比較用）
+// ----------------------------------------
+
+/// quasi_monte_carlo.rs と同じ ch09::qmc の乱択化推定（独立な n_rep 回の平均と標準誤差）
+/// make が UniformCube なら通常の MC 法、Halton::randomized ならランダムシフト Halton 列
+fn sampled<F, D, M>(f: &F, n: usize, n_rep: usize, seed: u64, make: M) -> Estimate
+where
+    F: Fn(&[f64]) -> f64,
+    D: Distribution<Vec<f64>>,
+    M: Fn(&mut ChaCha8Rng) -> D,
+{
+    let per_rep = n / n_rep;
+    let (value, error) = rqmc_integrate(f, per_rep, n_rep, seed, make);
+    Estimate {
+        value,
+        error,
+        n_evals: per_rep * n_rep,
+    }
+}
+
+// ----------------------------------------
+// 被積分関数（Genz のテスト関数族から）
+// ----------------------------------------
+
+type Integrand = Box<dyn Fn(&[f64]) -> f64>;
+
+/// 被積分関数と厳密値の組
+struct TestFunction {
+    name: &'static str,
+    f: Integrand,
+    exact: f64,
+}
+
+fn test_functions(d: usize) -> Vec<TestFunction> {
+    // 振動型: cos(2πu + Σ a_i x_i)。厳密値は Re[e^{2πiu} Π (e^{i a} - 1)/(i a)]
+    let (u, a): (f64, f64) = (0.3, 1.5);
+    // (e^{ia} - 1)/(ia) = (sin a)/a + i (1 - cos a)/a を d 回かける
+    let (re1, im1) = (a.sin() / a, (1.0 - a.cos()) / a);
+    let (mut re, mut im) = ((2.0 * PI * u).cos(), (2.0 * PI * u).sin());
+    for _ in 0..d {
+        (re, im) = (re * re1 - im * im1, re * im1 + im * re1);
+    }
+    let oscillatory = TestFunction {
+        name: "振動型 cos(2πu + aΣx)",
+        f: Box::new(move |x| (2.0 * PI * u + a * x.iter().sum::<f64>()).cos()),
+        exact: re,
+    };
+
+    // 積ピーク型: Π 1/(c⁻² + (x_i - w)²)。厳密値は Π c (atan(c(1-w)) + atan(cw))
+    let (c, w): (f64, f64) = (2.0, 0.5);
+    let product_peak = TestFunction {
+        name: "積ピーク型 Π 1/(c⁻²+(x-w)²)",
+        f: Box::new(move |x| {
+            x.iter()
+                .map(|&xi| 1.0 / (c.powi(-2) + (xi - w).powi(2)))
+                .product()
+        }),
+        exact: (c * ((c * (1.0 - w)).atan() + (c * w).atan())).powi(d as i32),
+    };
+
+    // 指数型: exp(Σ b_i x_i)。厳密値は Π (e^{b_i} - 1)/b_i
+    let b: Vec<f64> = (0..d).map(|i| 0.5 + 0.25 * i as f64 / d as f64).collect();
+    let exact = b.iter().map(|&bi| (bi.exp() - 1.0) / bi).product();
+    let exponential = TestFunction {
+        name: "指数型 exp(Σ b_i x_i)",
+        f: Box::new(move |x| x.iter().zip(&b).map(|(xi, bi)| xi * bi).sum::<f64>().exp()),
+        exact,
+    };
+
+    vec![oscillatory, product_peak, exponential]
+}
+
+fn print_row(name: &str, est: Estimate, exact: f64) {
+    let true_err = (est.value - exact).abs() / exact.abs();
+    let est_err = est.error / exact.abs();
+    println!(
+        "    {:<28} {:>9} {:>12.3e} {:>12.3e}",
+        name, est.n_evals, est_err, true_err
+    );
+}
+
+fn main() {
+    // ----------------------------------------
+    // 1. 規則の検証
+    // ----------------------------------------
+    println!("=== 1. 規則の検証 ===");
+    // Genz–Malik 則は 7 次以下の多項式を厳密に積分する
+    for d in [2, 4, 6] {
+        let poly = |x: &[f64]| {
+            let s: f64 = x.iter().sum();
+            s.powi(7) + x[0].powi(6) * x[1]
+        };
+        let reference = tensor_gauss(&poly, d, 5).value; // 5 点ガウスは 9 次まで厳密
+        let gm = genz_malik(&poly, vec![0.5; d], vec![0.5; d]).value;
+        println!(
+            "d = {}: Genz–Malik 則の 7 次多項式に対する相対誤差 {:.1e}",
+            d,
+            (gm - reference).abs() / reference
+        );
+    }
+    // スパースグリッドの重みの和は 1（定数関数を厳密に積分）
+    for (d, level) in [(2, 5), (4, 5), (8, 4)] {
+        let rule = smolyak_rule(d, level, level);
+        let sum: f64 = rule.values().sum();
+        println!(
+            "Smolyak d = {}, レベル {}: 点数 {:>6}（直積なら {:>10}）, 重み和 - 1 = {:.1e}",
+            d,
+            level,
+            rule.len(),
+            ((1usize << (level - 1)) + 1).pow(d as u32),
+            sum - 1.0
+        );
+    }
+
+    // ----------------------------------------
+    // 2. 次元ごとの比較
+    // ----------------------------------------
+    println!("\n=== 2. d = 2〜8 での比較（相対誤差） ===");
+    let mc_budget = 200_000;
+    for d in [2, 3, 4, 6, 8] {
+        // 直積ガウスの点数は n^d で増えるので、評価回数が 10^5 前後になる n を選ぶ
+        let n_gauss = ((1e5f64).powf(1.0 / d as f64).floor() as usize).clamp(3, 20);
+        let level = match d {
+            2 => 10,
+            3 => 8,
+            4 => 7,
+            _ => 6,
+        };
+        println!("\n■ d = {}", d);
+        for tf in test_functions(d) {
+            let f = |x: &[f64]| (tf.f)(x);
+            println!("  {}  厳密値 {:.12}", tf.name, tf.exact);
+            println!(
+                "    {:<28} {:>9} {:>12} {:>12}",
+                "手法", "評価数", "推定誤差", "真の誤差"
+            );
+            print_row(
+                &format!("直積ガウス n={}", n_gauss),
+                tensor_gauss(&f, d, n_gauss),
+                tf.exact,
+            );
+            print_row(
+                &format!("Smolyak CC レベル {}", level),
+                smolyak(&f, d, level),
+                tf.exact,
+            );
+            print_row(
+                "Genz–Malik 適応型",
+                adaptive_cubature(&f, d, 1e-10, mc_budget),
+                tf.exact,
+            );
+            print_row(
+                "MC (20 回)",
+                sampled(&f, mc_budget, 20, 42, |_| UniformCube { dim: d }),
+                tf.exact,
+            );
+            print_row(
+                "乱択化 Halton (20 回)",
+                sampled(&f, mc_budget, 20, 7, |rng| Halton::randomized(d, rng)),
+                tf.exact,
+            );
+        }
+    }
+
+    // ----------------------------------------
+    // 3. 評価回数に対する収束（d = 4, 積ピーク型）
+    // ----------------------------------------
+    println!("\n=== 3. 評価回数に対する収束（d = 4, 積ピーク型, 相対誤差） ===");
+    let d = 4;
+    let tf = &test_functions(d)[1];
+    let f = |x: &[f64]| (tf.f)(x);
+    let rel = |e: Estimate| (e.value - tf.exact).abs() / tf.exact;
+    println!(
+        "  {:<14} {:>9} {:>12}   {:<18} {:>9} {:>12}",
+        "手法", "評価数", "真の誤差", "手法", "評価数", "真の誤差"
+    );
+    for k in 0..5 {
+        let gauss = tensor_gauss(&f, d, 3 + 2 * k);
+        let sparse = smolyak(&f, d, 3 + k);
+        println!(
+            "  {:<14} {:>9} {:>12.3e}   {:<18} {:>9} {:>12.3e}",
+            format!("直積 n={}", 3 + 2 * k),
+            gauss.n_evals,
+            rel(gauss),
+            format!("Smolyak L={}", 3 + k),
+            sparse.n_evals,
+            rel(sparse)
+        );
+    }
+    println!();
+    for budget in [1_000, 10_000, 100_000, 1_000_000] {
+        let adaptive = adaptive_cubature(&f, d, 1e-14, budget);
+        let mc = sampled(&f, budget, 20, 1, |_| UniformCube { dim: d });
+        println!(
+            "  {:<14} {:>9} {:>12.3e}   {:<18} {:>9} {:>12.3e}",
+            "Genz–Malik",
+            adaptive.n_evals,
+            rel(adaptive),
+            "MC",
+            mc.n_evals,
+            rel(mc)
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use ndarray::Array1;
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Exp, Poisson};
+use std::fs::File;
+use std::io::Write;
+use std::time::Instant;
+
+/// 質量作用則に従う1つの反応
+struct Reaction {
+    /// (化学種の番号, 化学量論係数)
+    reactants: Vec<(usize, i64)>,
+    products: Vec<(usize, i64)>,
+    /// 確率的反応速度定数 c
+    rate: f64,
+}
+
+impl Reaction {
+    /// 反応の傾向関数 a(x) = c · Π C(x_i, ν_i)（反応物の組み合わせの数）
+    fn propensity(&self, x: &[i64]) -> f64 {
+        let mut a = self.rate;
+        for &(s, nu) in &self.reactants {
+            for k in 0..nu {
+                a *= (x[s] - k).max(0) as f64 / (k + 1) as f64;
+            }
+        }
+        a
+    }
+
+    /// 平均場（決定論的）極限での反応速度 c · Π x_i^ν_i / ν_i!
+    fn mean_field_rate(&self, x: &Array1<f64>) -> f64 {
+        let mut a = self.rate;
+        for &(s, nu) in &self.reactants {
+            for k in 0..nu {
+                a *= x[s] / (k + 1) as f64;
+            }
+        }
+        a
+    }
+
+    /// 反応を count 回起こしたときの各化学種の変化量を x に加える
+    fn apply(&self, x: &mut [i64], count: i64) {
+        for &(s, nu) in &self.reactants {
+            x[s] -= nu * count;
+        }
+        for &(s, nu) in &self.products {
+            x[s] += nu * count;
+        }
+    }
+
+    /// この反応で数が変わる化学種
+    fn changed_species(&self) -> Vec<usize> {
+        let mut species: Vec<usize> = self
+            .reactants
+            .iter()
+            .chain(&self.products)
+            .map(|&(s, _)| s)
+            .collect();
+        species.sort();
+        species.dedup();
+        species
+    }
+}
+
+/// 反応ネットワーク（化学種の名前と反応のリスト）
+struct ReactionNetwork {
+    species: Vec<String>,
+    reactions: Vec<Reaction>,
+}
+
+impl ReactionNetwork {
+    fn new(species: &[&str]) -> Self {
+        Self {
+            species: species.iter().map(|s| s.to_string()).collect(),
+            reactions: Vec::new(),
+        }
+    }
+
+    fn index(&self, name: &str) -> usize {
+        self.species
+            .iter()
+            .position(|s| s == name)
+            .unwrap_or_else(|| panic!("未定義の化学種: {}", name))
+    }
+
+    /// 反応を追加する。例: add_reaction(&[("X", 1), ("Y", 1)], &[("Y", 2)], 0.005)
+    fn add_reaction(
+        mut self,
+        reactants: &[(&str, i64)],
+        products: &[(&str, i64)],
+        rate: f64,
+    ) -> Self {
+        let reactants = reactants
+            .iter()
+            .map(|&(s, nu)| (self.index(s), nu))
+            .collect();
+        let products = products
+            .iter()
+            .map(|&(s, nu)| (self.index(s), nu))
+            .collect();
+        self.reactions.push(Reaction {
+            reactants,
+            products,
+            rate,
+        });
+        self
+    }
+
+    /// 反応 j が起きたときに傾向関数が変わる反応の一覧（依存グラフ）
+    fn dependency_graph(&self) -> Vec<Vec<usize>> {
+        self.reactions
+            .iter()
+            .map(|r| {
+                let changed = r.changed_species();
+                (0..self.reactions.len())
+                    .filter(|&k| {
+                        self.reactions[k]
+                            .reactants
+                            .iter()
+                            .any(|(s, _)| changed.contains(s))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// 平均場近似の反応速度方程式 dx/dt = Σ_j ν_j a_j(x)
+    fn mean_field(&self, x: &Array1<f64>) -> Array1<f64> {
+        let mut dx = Array1::zeros(x.len());
+        for r in &self.reactions {
+            let a = r.mean_field_rate(x);
+            for &(s, nu) in &r.reactants {
+                dx[s] -= nu as f64 * a;
+            }
+            for &(s, nu) in &r.products {
+                dx[s] += nu as f64 * a;
+            }
+        }
+        dx
+    }
+}
+
+/// シミュレーションの方法
+#[derive(Clone, Copy, Debug)]
+enum Method {
+    /// Gillespie の直接法
+    Direct,
+    /// Gibson–Bruck の次反応法（反応ごとの発火予定時刻を使い回す）
+    NextReaction,
+    /// τ リーピング法（幅 τ の間に各反応がポアソン分布に従う回数だけ起こる近似）
+    TauLeaping { tau: f64 },
+}
+
+/// 等間隔の時刻で記録した時系列
+struct Trajectory {
+    times: Vec<f64>,
+    states: Vec<Vec<i64>>,
+    /// 実行したステップ（反応イベントまたはリープ）の数
+    n_steps: usize,
+}
+
+/// 時刻 t_end まで確率シミュレーションを行い、sample_dt ごとの状態を記録する
+fn simulate(
+    network: &ReactionNetwork,
+    x0: &[i64],
+    t_end: f64,
+    sample_dt: f64,
+    method: Method,
+    rng: &mut impl Rng,
+) -> Trajectory {
+    let n_reactions = network.reactions.len();
+    let mut x = x0.to_vec();
+    let mut t = 0.0;
+    let n_samples = (t_end / sample_dt).round() as usize + 1;
+    let mut traj = Trajectory {
+        times: Vec::with_capacity(n_samples),
+        states: Vec::with_capacity(n_samples),
+        n_steps: 0,
+    };
+    let mut next_sample = 0;
+    // 時刻 t_next より前のサンプル時刻に現在の状態を記録する
+    let mut record = |t_next: f64, x: &[i64], traj: &mut Trajectory| {
+        while next_sample < n_samples && next_sample as f64 * sample_dt < t_next {
+            traj.times.push(next_sample as f64 * sample_dt);
+            traj.states.push(x.to_vec());
+            next_sample += 1;
+        }
+    };
+    let mut propensities: Vec<f64> = network.reactions.iter().map(|r| r.propensity(&x)).collect();
+
+    match method {
+        Method::Direct => loop {
+            // 次の反応までの時間 ~ Exp(a_0)、反応 j は確率 a_j / a_0 で選ぶ
+            let a0: f64 = propensities.iter().sum();
+            let dt = if a0 > 0.0 {
+                Exp::new(a0).unwrap().sample(rng)
+            } else {
+                f64::INFINITY
+            };
+            record((t + dt).min(t_end + sample_dt), &x, &mut traj);
+            if t + dt > t_end {
+                break;
+            }
+            t += dt;
+            let mut r = rng.random::<f64>() * a0;
+            let mut j = n_reactions - 1;
+            for (k, &a) in propensities.iter().enumerate() {
+                if r < a {
+                    j = k;
+                    break;
+                }
+                r -= a;
+            }
+            network.reactions[j].apply(&mut x, 1);
+            for (a, reaction) in propensities.iter_mut().zip(&network.reactions) {
+                *a = reaction.propensity(&x);
+            }
+            traj.n_steps += 1;
+        },
+        Method::NextReaction => {
+            let dependencies = network.dependency_graph();
+            let draw = |a: f64, rng: &mut dyn FnMut() -> f64| {
+                if a > 0.0 {
+                    -rng().ln() / a
+                } else {
+                    f64::INFINITY
+                }
+            };
+            let mut uniform = || 1.0 - rng.random::<f64>();
+            // 各反応の絶対的な発火予定時刻
+            let mut fire_times: Vec<f64> = propensities
+                .iter()
+                .map(|&a| draw(a, &mut uniform))
+                .collect();
+            loop {
+                // 最も早い反応を選ぶ（反応数が多いときは優先度付きキューを使う）
+                let (j, &tj) = fire_times
+                    .iter()
+                    .enumerate()
+                    .min_by(|a, b| a.1.total_cmp(b.1))
+                    .unwrap();
+                record(tj.min(t_end + sample_dt), &x, &mut traj);
+                if tj > t_end {
+                    break;
+                }
+                t = tj;
+                network.reactions[j].apply(&mut x, 1);
+                traj.n_steps += 1;
+                for &k in &dependencies[j] {
+                    let a_old = propensities[k];
+                    let a_new = network.reactions[k].propensity(&x);
+                    propensities[k] = a_new;
+                    if k == j {
+                        continue;
+                    }
+                    // 使わなかった待ち時間は傾向関数の比で縮めて再利用する
+                    fire_times[k] = if a_new <= 0.0 {
+                        f64::INFINITY
+                    } else if a_old > 0.0 && fire_times[k].is_finite() {
+                        t + (a_old / a_new) * (fire_times[k] - t)
+                    } else {
+                        t + draw(a_new, &mut uniform)
+                    };
+                }
+                propensities[j] = network.reactions[j].propensity(&x);
+                fire_times[j] = t + draw(propensities[j], &mut uniform);
+            }
+        }
+        Method::TauLeaping { tau } => {
+            while t < t_end {
+                let mut step = tau.min(t_end - t);
+                // 個数が負になったら τ を半分にしてやり直す
+                let x_new = loop {
+                    let mut trial = x.clone();
+                    for (reaction, &a) in network.reactions.iter().zip(&propensities) {
+                        if a > 0.0 {
+                            let k: f64 = Poisson::new(a * step).unwrap().sample(rng);
+                            reaction.apply(&mut trial, k as i64);
+                        }
+                    }
+                    if trial.iter().all(|&v| v >= 0) {
+                        break trial;
+                    }
+                    step *= 0.5;
+                };
+                record(t + step, &x, &mut traj);
+                t += step;
+                x = x_new;
+                for (a, reaction) in propensities.iter_mut().zip(&network.reactions) {
+                    *a = reaction.propensity(&x);
+                }
+                traj.n_steps += 1;
+            }
+            record(t_end + sample_dt, &x, &mut traj);
+        }
+    }
+    traj
+}
+
+/// 4次のルンゲ＝クッタ法による1ステップの更新（ch07 と同じ）
+fn rk4_step<F>(state: &Array1<f64>, t: f64, h: f64, f: F) -> Array1<f64>
+where
+    F: Fn(f64, &Array1<f64>) -> Array1<f64>,
+{
+    let k1 = f(t, state);
+    let k2 = f(t + h * 0.5, &(state + &k1 * (h * 0.5)));
+    let k3 = f(t + h * 0.5, &(state + &k2 * (h * 0.5)));
+    let k4 = f(t + h, &(state + &k3 * h));
+
+    state + (&k1 + &k2 * 2.0 + &k3 * 2.0 + &k4) * (h / 6.0)
+}
+
+/// 平均場の反応速度方程式を RK4 で解き、sample_dt ごとの値を返す
+fn solve_mean_field(
+    network: &ReactionNetwork,
+    x0: &[i64],
+    t_end: f64,
+    sample_dt: f64,
+) -> Vec<Array1<f64>> {
+    let substeps = 20;
+    let h = sample_dt / substeps as f64;
+    let mut state = Array1::from_iter(x0.iter().map(|&v| v as f64));
+    let mut out = vec![state.clone()];
+    let n_samples = (t_end / sample_dt).round() as usize;
+    for k in 0..n_samples {
+        for s in 0..substeps {
+            let t = k as f64 * sample_dt + s as f64 * h;
+            state = rk4_step(&state, t, h, |_t, x| network.mean_field(x));
+        }
+        out.push(state.clone());
+    }
+    out
+}
+
+/// 時系列を CSV に書き出す（確率シミュレーションと平均場の解を並べる）
+fn write_csv(path: &str, network: &ReactionNetwork, traj: &Trajectory, mean_field: &[Array1<f64>]) {
+    let mut csv_file = File::create(path).expect("CSVファイルの作成に失敗しました");
+    let mut header = vec!["time".to_string()];
+    header.extend(network.species.iter().cloned());
+    header.extend(network.species.iter().map(|s| format!("{}_ode", s)));
+    writeln!(csv_file, "{}", header.join(",")).expect("CSVヘッダーの書き込みに失敗しました");
+    for (k, (t, x)) in traj.times.iter().zip(&traj.states).enumerate() {
+        let mut row = vec![t.to_string()];
+        row.extend(x.iter().map(|v| v.to_string()));
+        row.extend(mean_field[k].iter().map(|v| v.to_string()));
+        writeln!(csv_file, "{}", row.join(",")).expect("CSVデータの書き込みに失敗しました");
+    }
+    println!("結果を '{}' に保存しました", path);
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    let methods = [
+        Method::Direct,
+        Method::NextReaction,
+        Method::TauLeaping { tau: 0.01 },
+    ];
+
+    // ----------------------------------------
+    // 1. 放射性崩壊系列 A → B → C
+    // ----------------------------------------
+    let (l1, l2) = (1.0, 0.4);
+    let decay = ReactionNetwork::new(&["A", "B", "C"])
+        .add_reaction(&[("A", 1)], &[("B", 1)], l1)
+        .add_reaction(&[("B", 1)], &[("C", 1)], l2);
+    let n0 = 1000;
+    let x0 = [n0, 0, 0];
+    let (t_end, sample_dt) = (8.0, 0.5);
+    println!(
+        "=== 1. 崩壊系列 A → B → C (λ₁ = {}, λ₂ = {}, N₀ = {}) ===",
+        l1, l2, n0
+    );
+
+    // ベイトマンの解: N_A = N₀e^(-λ₁t), N_B = N₀ λ₁/(λ₁-λ₂) (e^(-λ₂t) - e^(-λ₁t))
+    let bateman = |t: f64| {
+        let a = n0 as f64 * (-l1 * t).exp();
+        let b = n0 as f64 * l1 / (l1 - l2) * ((-l2 * t).exp() - (-l1 * t).exp());
+        (a, b)
+    };
+    let n_runs = 400;
+    for method in methods {
+        let start = Instant::now();
+        let mut sum = vec![[0.0; 2]; (t_end / sample_dt) as usize + 1];
+        let mut sum_sq_a = vec![0.0; sum.len()];
+        let mut steps = 0;
+        for _ in 0..n_runs {
+            let traj = simulate(&decay, &x0, t_end, sample_dt, method, &mut rng);
+            steps += traj.n_steps;
+            for (k, x) in traj.states.iter().enumerate() {
+                sum[k][0] += x[0] as f64;
+                sum[k][1] += x[1] as f64;
+                sum_sq_a[k] += (x[0] * x[0]) as f64;
+            }
+        }
+        println!(
+            "\n[{:?}] {} 回の平均 (1回あたり {} ステップ, {:.2} s)",
+            method,
+            n_runs,
+            steps / n_runs,
+            start.elapsed().as_secs_f64()
+        );
+        println!(
+            "{:>5} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12}",
+            "t", "<N_A>", "ベイトマン", "<N_B>", "ベイトマン", "Var(N_A)", "二項分布"
+        );
+        for k in (0..sum.len()).step_by(4) {
+            let t = k as f64 * sample_dt;
+            let (a, b) = bateman(t);
+            let mean_a = sum[k][0] / n_runs as f64;
+            let var_a = sum_sq_a[k] / n_runs as f64 - mean_a * mean_a;
+            // 各原子が独立に崩壊するので N_A は二項分布 B(N₀, e^(-λ₁t))
+            let p = (-l1 * t).exp();
+            println!(
+                "{:>5.1} {:>10.2} {:>10.2} {:>10.2} {:>10.2} {:>12.2} {:>12.2}",
+                t,
+                mean_a,
+                a,
+                sum[k][1] / n_runs as f64,
+                b,
+                var_a,
+                n0 as f64 * p * (1.0 - p)
+            );
+        }
+    }
+
+    let traj = simulate(&decay, &x0, t_end, 0.05, Method::Direct, &mut rng);
+    let ode = solve_mean_field(&decay, &x0, t_end, 0.05);
+    write_csv("gillespie_decay.csv", &decay, &traj, &ode);
+
+    // ----------------------------------------
+    // 2. ロトカ・ヴォルテラ模型
+    // ----------------------------------------
+    // X → 2X (被食者の増殖), X + Y → 2Y (捕食), Y → ∅ (捕食者の死亡)
+    let (c1, c2, c3) = (1.0, 0.005, 0.6);
+    let lotka_volterra = ReactionNetwork::new(&["X", "Y"])
+        .add_reaction(&[("X", 1)], &[("X", 2)], c1)
+        .add_reaction(&[("X", 1), ("Y", 1)], &[("Y", 2)], c2)
+        .add_reaction(&[("Y", 1)], &[], c3);
+    let x0 = [100, 150];
+    let (t_end, sample_dt) = (30.0, 0.1);
+    println!(
+        "\n=== 2. ロトカ・ヴォルテラ模型 (c₁ = {}, c₂ = {}, c₃ = {}, 初期値 X = {}, Y = {}) ===",
+        c1, c2, c3, x0[0], x0[1]
+    );
+    println!(
+        "平衡点: X* = c₃/c₂ = {}, Y* = c₁/c₂ = {}, 微小振動の周期 2π/√(c₁c₃) = {:.3}",
+        c3 / c2,
+        c1 / c2,
+        2.0 * std::f64::consts::PI / (c1 * c3).sqrt()
+    );
+
+    let ode = solve_mean_field(&lotka_volterra, &x0, t_end, sample_dt);
+    let traj = simulate(
+        &lotka_volterra,
+        &x0,
+        t_end,
+        sample_dt,
+        Method::NextReaction,
+        &mut rng,
+    );
+    println!("\n1本の軌道と平均場 ODE の比較:");
+    println!(
+        "{:>6} {:>8} {:>10} {:>8} {:>10}",
+        "t", "X (SSA)", "X (ODE)", "Y (SSA)", "Y (ODE)"
+    );
+    for k in (0..traj.times.len()).step_by(25) {
+        println!(
+            "{:>6.1} {:>8} {:>10.2} {:>8} {:>10.2}",
+            traj.times[k], traj.states[k][0], ode[k][0], traj.states[k][1], ode[k][1]
+        );
+    }
+    write_csv("gillespie_lotka_volterra.csv", &lotka_volterra, &traj, &ode);
+
+    // 平均場の解は閉じた周期軌道だが、確率的な揺らぎは振幅を広げ、いずれ絶滅に至る
+    println!("\n各方法での統計 (200 回):");
+    println!(
+        "{:<26} {:>14} {:>16} {:>12}",
+        "方法", "t = 30 の <X>", "絶滅した割合", "時間[s]"
+    );
+    for method in methods {
+        let start = Instant::now();
+        let n_runs = 200;
+        let (mut sum_x, mut extinct) = (0.0, 0);
+        for _ in 0..n_runs {
+            let traj = simulate(&lotka_volterra, &x0, t_end, sample_dt, method, &mut rng);
+            let last = traj.states.last().unwrap();
+            sum_x += last[0] as f64;
+            if last[0] == 0 || last[1] == 0 {
+                extinct += 1;
+            }
+        }
+        println!(
+            "{:<26} {:>14.2} {:>16.3} {:>12.2}",
+            format!("{:?}", method),
+            sum_x / n_runs as f64,
+            extinct as f64 / n_runs as f64,
+            start.elapsed().as_secs_f64()
+        );
+    }
+    println!(
+        "(平均場 ODE の t = 30 での X = {:.2})",
+        ode.last().unwrap()[0]
+    );
+}
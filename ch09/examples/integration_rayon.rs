@@ -1,23 +1,88 @@
+use ch09::rng::stream_rng;
 use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
 
-fn main() {
-    let m = 100_000_000;
+/// 1チャンクあたりのサンプル数
+/// 乱数列の割り当てはチャンク単位で決まるので、この値を変えると結果も変わる
+const CHUNK_SIZE: u64 = 1 << 20;
 
-    // 並列イテレータによる集計
-    let hits: u64 = (0..m)
+/// m 個のサンプルをチャンクに分け、各チャンクをそれぞれのストリームで並列に処理する
+/// チャンクごとの結果は番号順に並べて返す（和をとる順序もスレッド数によらなくなる）
+fn par_chunks<T, F>(seed: u64, m: u64, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&mut ChaCha8Rng, u64) -> T + Sync,
+{
+    let n_chunks = m.div_ceil(CHUNK_SIZE);
+    (0..n_chunks)
         .into_par_iter()
-        .map_init(
-            rand::rngs::ThreadRng::default, // 各スレッドの初期化時に一度だけ呼ばれる
-            |rng, _| {
-                // 各要素の処理で呼ばれる
+        .map(|chunk| {
+            let mut rng = stream_rng(seed, chunk);
+            let len = CHUNK_SIZE.min(m - chunk * CHUNK_SIZE);
+            f(&mut rng, len)
+        })
+        .collect()
+}
+
+/// 円周率の推定（単位円の内部に入った点の数を数える）
+fn estimate_pi(seed: u64, m: u64) -> f64 {
+    let hits: u64 = par_chunks(seed, m, |rng, len| {
+        (0..len)
+            .filter(|_| {
                 let x: f64 = rng.random();
                 let y: f64 = rng.random();
-                if x * x + y * y <= 1.0 { 1 } else { 0 }
-            },
-        )
-        .sum();
+                x * x + y * y <= 1.0
+            })
+            .count() as u64
+    })
+    .iter()
+    .sum();
+    4.0 * (hits as f64) / (m as f64)
+}
+
+/// ∫₀¹ exp(-x²) dx の推定（浮動小数点数の和）
+fn estimate_gaussian_integral(seed: u64, m: u64) -> f64 {
+    let partial_sums = par_chunks(seed, m, |rng, len| {
+        (0..len)
+            .map(|_| {
+                let x: f64 = rng.random();
+                (-x * x).exp()
+            })
+            .sum::<f64>()
+    });
+    // 並列のリダクションは分割のしかたで丸め誤差が変わるので、チャンク順に足す
+    partial_sums.iter().sum::<f64>() / m as f64
+}
 
-    let pi_est = 4.0 * (hits as f64) / (m as f64);
+fn main() {
+    let m = 100_000_000;
+    let seed = 42;
+
+    // 並列イテレータによる集計
+    let pi_est = estimate_pi(seed, m);
     println!("Estimated pi = {:.8}", pi_est);
+    let integral = estimate_gaussian_integral(seed, m);
+    println!(
+        "Estimated ∫₀¹ exp(-x²) dx = {:.8} (厳密値 0.74682413)",
+        integral
+    );
+
+    // スレッド数を変えても、同じシードならビット単位で同じ結果になる
+    println!("\nスレッド数による違い (seed = {}):", seed);
+    for n_threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(n_threads)
+            .build()
+            .unwrap();
+        let (pi, value) =
+            pool.install(|| (estimate_pi(seed, m), estimate_gaussian_integral(seed, m)));
+        println!(
+            "  {} スレッド: pi = {:.8}, 積分 = {:.16}, 一致: {}",
+            n_threads,
+            pi,
+            value,
+            pi.to_bits() == pi_est.to_bits() && value.to_bits() == integral.to_bits()
+        );
+    }
 }
@@ -0,0 +1,451 @@
+use ch09::metropolis;
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::time::Instant;
+
+/// スピン模型の種類
+#[derive(Clone, Copy)]
+enum Model {
+    /// イジング模型 H = -J Σ s_i s_j (s = ±1)
+    Ising,
+    /// q 状態ポッツ模型 H = -J Σ δ(σ_i, σ_j)
+    Potts(u8),
+}
+
+impl Model {
+    fn q(&self) -> u8 {
+        match self {
+            Model::Ising => 2,
+            Model::Potts(q) => *q,
+        }
+    }
+
+    /// 1本のボンドのエネルギー（J = 1）
+    fn bond_energy(&self, a: u8, b: u8) -> f64 {
+        match self {
+            // 状態 0, 1 をスピン -1, +1 に対応させる
+            Model::Ising => {
+                if a == b {
+                    -1.0
+                } else {
+                    1.0
+                }
+            }
+            Model::Potts(_) => {
+                if a == b {
+                    -1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// クラスター法でのボンドの接続確率 p = 1 - exp(-ΔE β)
+    /// ΔE は同じ向きのボンドを切ったときのエネルギー変化（イジング 2J、ポッツ J）
+    fn bond_probability(&self, beta: f64) -> f64 {
+        match self {
+            Model::Ising => 1.0 - (-2.0 * beta).exp(),
+            Model::Potts(_) => 1.0 - (-beta).exp(),
+        }
+    }
+}
+
+/// 更新アルゴリズム
+#[derive(Clone, Copy, Debug)]
+enum Algorithm {
+    /// 1スピンずつのメトロポリス法
+    Metropolis,
+    /// 熱浴法（局所的な条件付き分布から直接サンプリング）
+    HeatBath,
+    /// Wolff の単一クラスター法
+    Wolff,
+    /// Swendsen–Wang の多クラスター法
+    SwendsenWang,
+}
+
+/// d 次元の超立方格子（周期境界条件）上のスピン系
+struct Lattice {
+    model: Model,
+    n: usize,
+    /// 各サイトの最近接サイトの番号（2d 個）
+    neighbors: Vec<Vec<usize>>,
+    spins: Vec<u8>,
+}
+
+impl Lattice {
+    fn new(model: Model, dim: usize, l: usize) -> Self {
+        if let Model::Potts(q) = model {
+            assert!(q >= 2, "ポッツ模型の状態数 q は 2 以上です (q = {q})");
+        }
+        let n = l.pow(dim as u32);
+        let neighbors = (0..n)
+            .map(|site| {
+                let mut nb = Vec::with_capacity(2 * dim);
+                let mut stride = 1;
+                for _ in 0..dim {
+                    // この方向の座標
+                    let x = (site / stride) % l;
+                    let base = site - x * stride;
+                    nb.push(base + ((x + 1) % l) * stride);
+                    nb.push(base + ((x + l - 1) % l) * stride);
+                    stride *= l;
+                }
+                nb
+            })
+            .collect();
+        Self {
+            model,
+            n,
+            neighbors,
+            // 全スピンがそろった状態（T = 0 の基底状態）から始める
+            spins: vec![0; n],
+        }
+    }
+
+    fn energy(&self) -> f64 {
+        let mut e = 0.0;
+        for i in 0..self.n {
+            for &j in &self.neighbors[i] {
+                e += self.model.bond_energy(self.spins[i], self.spins[j]);
+            }
+        }
+        // 各ボンドを2回数えているので半分にする
+        0.5 * e
+    }
+
+    /// 秩序変数（サイトあたりの磁化の大きさ）
+    /// イジング: |Σs|/N、ポッツ: (q·max_σ N_σ/N - 1)/(q - 1)
+    fn magnetization(&self) -> f64 {
+        let q = self.model.q() as usize;
+        let mut counts = vec![0usize; q];
+        for &s in &self.spins {
+            counts[s as usize] += 1;
+        }
+        match self.model {
+            Model::Ising => (counts[1] as f64 - counts[0] as f64).abs() / self.n as f64,
+            Model::Potts(_) => {
+                let max = *counts.iter().max().unwrap() as f64 / self.n as f64;
+                (q as f64 * max - 1.0) / (q as f64 - 1.0)
+            }
+        }
+    }
+
+    /// サイト i のスピンを状態 s にしたときの局所エネルギー
+    fn local_energy(&self, i: usize, s: u8) -> f64 {
+        self.neighbors[i]
+            .iter()
+            .map(|&j| self.model.bond_energy(s, self.spins[j]))
+            .sum()
+    }
+
+    /// 1モンテカルロステップ（N 回の局所更新、またはクラスター更新1回）
+    /// 戻り値: 局所更新なら受理率、クラスター更新なら反転したスピンの割合
+    fn sweep(&mut self, algorithm: Algorithm, beta: f64, rng: &mut ChaCha8Rng) -> f64 {
+        match algorithm {
+            Algorithm::Metropolis => self.metropolis_sweep(beta, rng),
+            Algorithm::HeatBath => self.heat_bath_sweep(beta, rng),
+            Algorithm::Wolff => self.wolff_update(beta, rng),
+            Algorithm::SwendsenWang => self.swendsen_wang_update(beta, rng),
+        }
+    }
+
+    fn metropolis_sweep(&mut self, beta: f64, rng: &mut ChaCha8Rng) -> f64 {
+        let q = self.model.q();
+        let mut accepted = 0;
+        for _ in 0..self.n {
+            let i = rng.random_range(0..self.n);
+            let old = self.spins[i];
+            // 現在と異なる状態を一様に提案する
+            let new = (old + rng.random_range(1..q)) % q;
+            let de = self.local_energy(i, new) - self.local_energy(i, old);
+            if metropolis::accept((-beta * de).exp(), rng) {
+                self.spins[i] = new;
+                accepted += 1;
+            }
+        }
+        accepted as f64 / self.n as f64
+    }
+
+    fn heat_bath_sweep(&mut self, beta: f64, rng: &mut ChaCha8Rng) -> f64 {
+        let q = self.model.q() as usize;
+        let mut changed = 0;
+        let mut weights = vec![0.0; q];
+        for _ in 0..self.n {
+            let i = rng.random_range(0..self.n);
+            // 近傍を固定したときの条件付き確率 P(s) ∝ exp(-β E_local(s))
+            for (s, w) in weights.iter_mut().enumerate() {
+                *w = (-beta * self.local_energy(i, s as u8)).exp();
+            }
+            let total: f64 = weights.iter().sum();
+            let mut r = rng.random::<f64>() * total;
+            let mut new = q - 1;
+            for (s, &w) in weights.iter().enumerate() {
+                if r < w {
+                    new = s;
+                    break;
+                }
+                r -= w;
+            }
+            if new as u8 != self.spins[i] {
+                changed += 1;
+            }
+            self.spins[i] = new as u8;
+        }
+        changed as f64 / self.n as f64
+    }
+
+    fn wolff_update(&mut self, beta: f64, rng: &mut ChaCha8Rng) -> f64 {
+        let q = self.model.q();
+        let p_add = self.model.bond_probability(beta);
+
+        // 種となるサイトを選び、同じ状態の隣接スピンを確率 p でクラスターに加える
+        let seed = rng.random_range(0..self.n);
+        let old = self.spins[seed];
+        let new = (old + rng.random_range(1..q)) % q;
+
+        let mut stack = vec![seed];
+        self.spins[seed] = new;
+        let mut size = 1;
+        while let Some(i) = stack.pop() {
+            for k in 0..self.neighbors[i].len() {
+                let j = self.neighbors[i][k];
+                if self.spins[j] == old && rng.random::<f64>() < p_add {
+                    // クラスターに加えた時点で反転させる（二重登録の防止を兼ねる）
+                    self.spins[j] = new;
+                    stack.push(j);
+                    size += 1;
+                }
+            }
+        }
+        size as f64 / self.n as f64
+    }
+
+    fn swendsen_wang_update(&mut self, beta: f64, rng: &mut ChaCha8Rng) -> f64 {
+        let q = self.model.q();
+        let p_add = self.model.bond_probability(beta);
+
+        // 同じ状態の隣接スピン間に確率 p でボンドを張り、Union-Find でクラスターにまとめる
+        let mut parent: Vec<usize> = (0..self.n).collect();
+        for i in 0..self.n {
+            for &j in &self.neighbors[i] {
+                if i < j && self.spins[i] == self.spins[j] && rng.random::<f64>() < p_add {
+                    let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                    if ri != rj {
+                        parent[ri] = rj;
+                    }
+                }
+            }
+        }
+
+        // 各クラスターに新しい状態を一様ランダムに割り当てる
+        let mut new_state: Vec<Option<u8>> = vec![None; self.n];
+        let mut changed = 0;
+        for i in 0..self.n {
+            let root = find(&mut parent, i);
+            let s = *new_state[root].get_or_insert_with(|| rng.random_range(0..q));
+            if s != self.spins[i] {
+                changed += 1;
+            }
+            self.spins[i] = s;
+        }
+        changed as f64 / self.n as f64
+    }
+}
+
+/// Union-Find の根を探す（経路圧縮つき）
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// 熱平衡での測定結果
+struct Measurement {
+    energy: f64,
+    magnetization: f64,
+    susceptibility: f64,
+    specific_heat: f64,
+    binder: f64,
+    /// 更新ごとの受理率（またはクラスターの大きさ）の平均
+    update_rate: f64,
+}
+
+/// 熱平衡化の後、n_measure 回の更新ごとに物理量を測定する
+fn simulate(
+    lattice: &mut Lattice,
+    algorithm: Algorithm,
+    temperature: f64,
+    n_thermalize: usize,
+    n_measure: usize,
+    rng: &mut ChaCha8Rng,
+) -> Measurement {
+    let beta = 1.0 / temperature;
+    let n = lattice.n as f64;
+    for _ in 0..n_thermalize {
+        lattice.sweep(algorithm, beta, rng);
+    }
+
+    let (mut e1, mut e2, mut m1, mut m2, mut m4, mut rate) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    for _ in 0..n_measure {
+        rate += lattice.sweep(algorithm, beta, rng);
+        let e = lattice.energy() / n;
+        let m = lattice.magnetization();
+        e1 += e;
+        e2 += e * e;
+        m1 += m;
+        m2 += m * m;
+        m4 += m.powi(4);
+    }
+    let k = n_measure as f64;
+    let (e1, e2, m1, m2, m4) = (e1 / k, e2 / k, m1 / k, m2 / k, m4 / k);
+
+    Measurement {
+        energy: e1,
+        magnetization: m1,
+        // 揺らぎの公式: χ = βN(<m²> - <|m|>²), C = β²N(<e²> - <e>²)
+        susceptibility: beta * n * (m2 - m1 * m1),
+        specific_heat: beta * beta * n * (e2 - e1 * e1),
+        // ビンダーキュムラント U₄ = 1 - <m⁴>/(3<m²>²)
+        binder: 1.0 - m4 / (3.0 * m2 * m2),
+        update_rate: rate / k,
+    }
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2024);
+    // 2次元イジング模型の厳密な転移温度 (Onsager): T_c = 2/ln(1 + √2)
+    let tc_exact = 2.0 / (1.0 + 2.0_f64.sqrt()).ln();
+
+    // ----------------------------------------
+    // 1. アルゴリズムの比較（2次元イジング, L = 16, T ≈ T_c）
+    // ----------------------------------------
+    println!(
+        "=== 1. 更新アルゴリズムの比較 (2次元イジング, L = 16, T = {:.4}) ===",
+        tc_exact
+    );
+    println!(
+        "{:<14} {:>10} {:>10} {:>10} {:>10} {:>12} {:>8}",
+        "アルゴリズム", "<e>", "<|m|>", "χ", "C", "受理率/反転率", "時間[s]"
+    );
+    for algorithm in [
+        Algorithm::Metropolis,
+        Algorithm::HeatBath,
+        Algorithm::Wolff,
+        Algorithm::SwendsenWang,
+    ] {
+        let mut lattice = Lattice::new(Model::Ising, 2, 16);
+        // Wolff は1回の更新で動くスピンが少ないので回数を増やす
+        let (n_therm, n_meas) = match algorithm {
+            Algorithm::Wolff => (2000, 40_000),
+            _ => (1000, 20_000),
+        };
+        let start = Instant::now();
+        let r = simulate(&mut lattice, algorithm, tc_exact, n_therm, n_meas, &mut rng);
+        println!(
+            "{:<14} {:>10.5} {:>10.5} {:>10.3} {:>10.4} {:>12.4} {:>8.2}",
+            format!("{:?}", algorithm),
+            r.energy,
+            r.magnetization,
+            r.susceptibility,
+            r.specific_heat,
+            r.update_rate,
+            start.elapsed().as_secs_f64()
+        );
+    }
+    println!(
+        "(T_c での厳密なエネルギー（L → ∞）: e = -√2 ≈ {:.5})",
+        -(2.0_f64.sqrt())
+    );
+
+    // ----------------------------------------
+    // 2. 有限サイズスケーリング: ビンダーキュムラントの交点から T_c を求める
+    // ----------------------------------------
+    println!("\n=== 2. 有限サイズスケーリング (2次元イジング, Wolff 法) ===");
+    let sizes = [8, 16, 32];
+    let temps: Vec<f64> = (0..8).map(|k| 2.20 + 0.02 * k as f64).collect();
+    let mut binder = vec![vec![0.0; temps.len()]; sizes.len()];
+
+    println!(
+        "{:>6} {:>4} {:>10} {:>10} {:>10} {:>10}",
+        "T", "L", "<|m|>", "χ", "C", "U₄"
+    );
+    for (t_idx, &t) in temps.iter().enumerate() {
+        for (l_idx, &l) in sizes.iter().enumerate() {
+            let mut lattice = Lattice::new(Model::Ising, 2, l);
+            let r = simulate(&mut lattice, Algorithm::Wolff, t, 2000, 30_000, &mut rng);
+            binder[l_idx][t_idx] = r.binder;
+            println!(
+                "{:>6.3} {:>4} {:>10.5} {:>10.3} {:>10.4} {:>10.5}",
+                t, l, r.magnetization, r.susceptibility, r.specific_heat, r.binder
+            );
+        }
+    }
+
+    // U₄ は T < T_c で 2/3、T > T_c で 0 に近づき、T_c では L によらない値をとる
+    // 隣り合うサイズの U₄(T) の差の符号が変わる点を線形補間で求める
+    println!("\nビンダーキュムラントの交点:");
+    let mut estimates = Vec::new();
+    for l_idx in 0..sizes.len() - 1 {
+        let diff: Vec<f64> = (0..temps.len())
+            .map(|k| binder[l_idx + 1][k] - binder[l_idx][k])
+            .collect();
+        for k in 0..temps.len() - 1 {
+            if diff[k] > 0.0 && diff[k + 1] <= 0.0 {
+                let t_cross =
+                    temps[k] + (temps[k + 1] - temps[k]) * diff[k] / (diff[k] - diff[k + 1]);
+                println!(
+                    "  L = {:>2} と L = {:>2}: T_c ≈ {:.4}",
+                    sizes[l_idx],
+                    sizes[l_idx + 1],
+                    t_cross
+                );
+                estimates.push(t_cross);
+                break;
+            }
+        }
+    }
+    if !estimates.is_empty() {
+        let mean = estimates.iter().sum::<f64>() / estimates.len() as f64;
+        println!("  推定値: T_c ≈ {:.4} (厳密解: {:.4})", mean, tc_exact);
+    }
+
+    // ----------------------------------------
+    // 3. 他の模型: 3状態ポッツ模型 (2次元) と3次元イジング模型
+    // ----------------------------------------
+    println!("\n=== 3. 3状態ポッツ模型 (2次元, L = 32, Swendsen–Wang 法) ===");
+    let tc_potts = 1.0 / (1.0 + 3.0_f64.sqrt()).ln();
+    println!("厳密な転移温度: T_c = 1/ln(1 + √3) = {:.4}", tc_potts);
+    println!("{:>6} {:>10} {:>10} {:>10}", "T", "<e>", "<m>", "χ");
+    for t in [0.90, 0.95, 1.00, 1.05, 1.10] {
+        let mut lattice = Lattice::new(Model::Potts(3), 2, 32);
+        let r = simulate(
+            &mut lattice,
+            Algorithm::SwendsenWang,
+            t,
+            500,
+            5000,
+            &mut rng,
+        );
+        println!(
+            "{:>6.3} {:>10.5} {:>10.5} {:>10.3}",
+            t, r.energy, r.magnetization, r.susceptibility
+        );
+    }
+
+    println!("\n=== 3次元イジング模型 (L = 12, 熱浴法と Wolff 法) ===");
+    println!("数値的に知られた転移温度: T_c ≈ 4.5115");
+    println!("{:>6} {:>14} {:>14}", "T", "<|m|> 熱浴", "<|m|> Wolff");
+    for t in [4.0, 4.4, 4.6, 5.0] {
+        let mut lattice = Lattice::new(Model::Ising, 3, 12);
+        let hb = simulate(&mut lattice, Algorithm::HeatBath, t, 500, 2000, &mut rng);
+        let mut lattice = Lattice::new(Model::Ising, 3, 12);
+        let wolff = simulate(&mut lattice, Algorithm::Wolff, t, 1000, 10_000, &mut rng);
+        println!(
+            "{:>6.2} {:>14.5} {:>14.5}",
+            t, hb.magnetization, wolff.magnetization
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use ch09::metropolis;
 use rand::RngExt;
 
 fn main() {
@@ -34,10 +35,9 @@ fn main() {
         // 2. 受理確率 A = min(1, p(x') / p(x)) の計算
         // 比をとることで、規格化定数がキャンセルされる
         let ratio = p_unnormalized(x_next) / p_unnormalized(x);
-        let acceptance_prob = ratio.min(1.0);
 
-        // 3. 受理判定
-        if rng.random::<f64>() < acceptance_prob {
+        // 3. 受理判定（一様乱数 u < A なら受理）
+        if metropolis::accept(ratio, &mut rng) {
             x = x_next;
             accepted += 1;
         }
@@ -0,0 +1,375 @@
+use ch09::metropolis;
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Normal};
+
+// ----------------------------------------
+// 診断ツール（任意の f64 の系列に使える）
+// ----------------------------------------
+
+fn mean(x: &[f64]) -> f64 {
+    x.iter().sum::<f64>() / x.len() as f64
+}
+
+/// 不偏分散
+fn variance(x: &[f64]) -> f64 {
+    let m = mean(x);
+    x.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (x.len() as f64 - 1.0)
+}
+
+/// 規格化された自己相関関数 ρ(t) = C(t) / C(0)
+/// 平均 m と C(0) はラグによらないので、呼び出し側で一度だけ計算して渡す
+fn autocorrelation(x: &[f64], m: f64, c0: f64, t: usize) -> f64 {
+    let n = x.len();
+    let ct = (0..n - t).map(|i| (x[i] - m) * (x[i + t] - m)).sum::<f64>() / (n - t) as f64;
+    ct / c0
+}
+
+/// 積分自己相関時間の推定結果
+struct AutocorrelationTime {
+    /// τ_int = 1/2 + Σ_{t=1}^{W} ρ(t)
+    tau: f64,
+    /// τ_int の統計誤差（Sokal の近似式）
+    tau_error: f64,
+    /// 和を打ち切った窓の大きさ W
+    window: usize,
+}
+
+/// Sokal の自己無撞着な窓による積分自己相関時間
+/// W ≥ c·τ_int(W) を満たす最小の W で和を打ち切る（c = 5〜10 が標準的）
+fn integrated_autocorrelation_time(x: &[f64], c: f64) -> AutocorrelationTime {
+    let n = x.len();
+    let m = mean(x);
+    let c0 = x.iter().map(|v| (v - m).powi(2)).sum::<f64>() / n as f64;
+    let mut tau = 0.5;
+    let mut window = n / 2;
+    for t in 1..n / 2 {
+        tau += autocorrelation(x, m, c0, t);
+        if t as f64 >= c * tau {
+            window = t;
+            break;
+        }
+    }
+    AutocorrelationTime {
+        tau,
+        tau_error: tau * (2.0 * (2.0 * window as f64 + 1.0) / n as f64).sqrt(),
+        window,
+    }
+}
+
+/// 実効サンプル数 N_eff = N / (2τ_int)
+/// τ_int は integrated_autocorrelation_time で一度だけ求めたものを渡す
+fn effective_sample_size(x: &[f64], act: &AutocorrelationTime) -> f64 {
+    x.len() as f64 / (2.0 * act.tau)
+}
+
+/// 自己相関を考慮した平均の標準誤差 √(2τ_int σ² / N)
+fn corrected_standard_error(x: &[f64], act: &AutocorrelationTime) -> f64 {
+    (2.0 * act.tau * variance(x) / x.len() as f64).sqrt()
+}
+
+/// Gelman–Rubin の収束診断 R̂（すべてのチェーンは同じ長さとする）
+/// チェーン間分散 B とチェーン内分散 W を比べ、収束していれば 1 に近づく
+fn gelman_rubin(chains: &[Vec<f64>]) -> f64 {
+    let m = chains.len() as f64;
+    let n = chains[0].len() as f64;
+    let chain_means: Vec<f64> = chains.iter().map(|c| mean(c)).collect();
+    let w = chains.iter().map(|c| variance(c)).sum::<f64>() / m;
+    let b = n * variance(&chain_means);
+    let var_plus = (n - 1.0) / n * w + b / n;
+    (var_plus / w).sqrt()
+}
+
+/// ブロッキング法の1段分の結果
+struct BlockingLevel {
+    block_size: usize,
+    n_blocks: usize,
+    error: f64,
+    /// 誤差の推定値自体の誤差 error / √(2(n_blocks - 1))
+    error_of_error: f64,
+}
+
+/// Flyvbjerg–Petersen のブロッキング法
+/// 隣り合う2点を平均して系列を半分にすることを繰り返し、各段で平均の標準誤差を推定する
+fn blocking(x: &[f64]) -> Vec<BlockingLevel> {
+    let mut levels = Vec::new();
+    let mut data = x.to_vec();
+    let mut block_size = 1;
+    while data.len() >= 4 {
+        let n = data.len() as f64;
+        let error = (variance(&data) / n).sqrt();
+        levels.push(BlockingLevel {
+            block_size,
+            n_blocks: data.len(),
+            error,
+            error_of_error: error / (2.0 * (n - 1.0)).sqrt(),
+        });
+        data = data.chunks_exact(2).map(|p| 0.5 * (p[0] + p[1])).collect();
+        block_size *= 2;
+    }
+    levels
+}
+
+/// ブロッキング法の誤差がプラトーに達した段を選ぶ
+/// 次の段との差がその段の誤差棒に収まる最初の段を採用する
+/// 系列が短くて段が1つもなければ None
+fn blocking_plateau(levels: &[BlockingLevel]) -> Option<&BlockingLevel> {
+    levels
+        .windows(2)
+        .find(|w| w[1].error - w[0].error < w[1].error_of_error)
+        .map(|w| &w[0])
+        .or(levels.last())
+}
+
+/// ブロック・ジャックナイフ法
+/// 系列を n_blocks 個のブロックに分け、1ブロックずつ除いて f を再計算する
+fn jackknife<F: Fn(&[f64]) -> f64>(x: &[f64], n_blocks: usize, f: F) -> (f64, f64) {
+    let block_len = x.len() / n_blocks;
+    let x = &x[..block_len * n_blocks];
+    let full = f(x);
+    let partial: Vec<f64> = (0..n_blocks)
+        .map(|k| {
+            let rest: Vec<f64> = x[..k * block_len]
+                .iter()
+                .chain(&x[(k + 1) * block_len..])
+                .copied()
+                .collect();
+            f(&rest)
+        })
+        .collect();
+    let nb = n_blocks as f64;
+    let partial_mean = mean(&partial);
+    // バイアス補正した推定値と誤差
+    let estimate = nb * full - (nb - 1.0) * partial_mean;
+    let error = ((nb - 1.0) / nb
+        * partial
+            .iter()
+            .map(|p| (p - partial_mean).powi(2))
+            .sum::<f64>())
+    .sqrt();
+    (estimate, error)
+}
+
+/// ブロック・ブートストラップ法
+/// ブロックを復元抽出して系列を作り直し、f のばらつきから誤差を求める
+fn bootstrap<F: Fn(&[f64]) -> f64>(
+    x: &[f64],
+    n_blocks: usize,
+    n_resamples: usize,
+    f: F,
+    rng: &mut ChaCha8Rng,
+) -> (f64, f64) {
+    let block_len = x.len() / n_blocks;
+    let mut resample = Vec::with_capacity(block_len * n_blocks);
+    let values: Vec<f64> = (0..n_resamples)
+        .map(|_| {
+            resample.clear();
+            for _ in 0..n_blocks {
+                let k = rng.random_range(0..n_blocks);
+                resample.extend_from_slice(&x[k * block_len..(k + 1) * block_len]);
+            }
+            f(&resample)
+        })
+        .collect();
+    (f(&x[..block_len * n_blocks]), variance(&values).sqrt())
+}
+
+/// 系列の要約を表示する
+fn report(name: &str, x: &[f64]) {
+    let act = integrated_autocorrelation_time(x, 5.0);
+    let naive = (variance(x) / x.len() as f64).sqrt();
+    let levels = blocking(x);
+    println!("[{}] N = {}", name, x.len());
+    println!("  平均                     = {:.6}", mean(x));
+    println!(
+        "  τ_int                    = {:.3} ± {:.3} (窓 W = {})",
+        act.tau, act.tau_error, act.window
+    );
+    println!(
+        "  実効サンプル数 N_eff     = {:.1}",
+        effective_sample_size(x, &act)
+    );
+    println!("  標準誤差（独立と仮定）   = {:.6}", naive);
+    println!(
+        "  標準誤差（τ_int で補正） = {:.6}",
+        corrected_standard_error(x, &act)
+    );
+    match blocking_plateau(&levels) {
+        Some(plateau) => println!(
+            "  標準誤差（ブロッキング） = {:.6} ± {:.6} (ブロック長 {})",
+            plateau.error, plateau.error_of_error, plateau.block_size
+        ),
+        None => println!("  標準誤差（ブロッキング） = 系列が短すぎて求められません"),
+    }
+}
+
+// ----------------------------------------
+// テスト用の系列
+// ----------------------------------------
+
+/// AR(1) 過程 x_{t+1} = φ x_t + ξ_t（ξ ~ N(0, 1)）
+/// τ_int = (1 + φ) / (2(1 - φ)) が厳密に分かる
+fn ar1_series(phi: f64, n: usize, rng: &mut ChaCha8Rng) -> Vec<f64> {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let mut x = normal.sample(rng) / (1.0 - phi * phi).sqrt();
+    (0..n)
+        .map(|_| {
+            x = phi * x + normal.sample(rng);
+            x
+        })
+        .collect()
+}
+
+/// mcmc.rs と同じ一様ランダムウォークのメトロポリス法（目標は N(0, 1)）
+fn metropolis_chain(x_init: f64, delta: f64, n_steps: usize, rng: &mut ChaCha8Rng) -> Vec<f64> {
+    let p_unnormalized = |x: f64| (-0.5 * x * x).exp();
+    let mut x = x_init;
+    (0..n_steps)
+        .map(|_| {
+            let x_next = x + rng.random_range(-delta..delta);
+            if metropolis::accept(p_unnormalized(x_next) / p_unnormalized(x), rng) {
+                x = x_next;
+            }
+            x
+        })
+        .collect()
+}
+
+/// ch10 の分子動力学の出力 CSV から列を読み込む
+fn read_csv_column(path: &str, column: &str) -> Option<Vec<f64>> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let mut lines = text.lines();
+    let header: Vec<&str> = lines.next()?.split(',').collect();
+    let idx = header.iter().position(|h| h.trim() == column)?;
+    lines
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.split(',').nth(idx)?.trim().parse().ok())
+        .collect()
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+
+    // ----------------------------------------
+    // 1. AR(1) 過程: 厳密な τ_int との比較
+    // ----------------------------------------
+    println!("=== 1. AR(1) 過程 (厳密解との比較) ===");
+    let n = 200_000;
+    println!(
+        "{:>6} {:>10} {:>16} {:>12} {:>12} {:>12}",
+        "φ", "τ_int 厳密", "τ_int 推定", "誤差 厳密", "誤差 ブロック", "誤差 独立"
+    );
+    for phi in [0.0, 0.5, 0.9, 0.99] {
+        let x = ar1_series(phi, n, &mut rng);
+        let tau_exact = (1.0 + phi) / (2.0 * (1.0 - phi));
+        // 平均の誤差の厳密値 √(2τ σ²/N)、σ² = 1/(1 - φ²)
+        let err_exact = (2.0 * tau_exact / (1.0 - phi * phi) / n as f64).sqrt();
+        let act = integrated_autocorrelation_time(&x, 5.0);
+        let levels = blocking(&x);
+        println!(
+            "{:>6.2} {:>10.3} {:>9.3} ± {:<5.3} {:>12.6} {:>12.6} {:>12.6}",
+            phi,
+            tau_exact,
+            act.tau,
+            act.tau_error,
+            err_exact,
+            blocking_plateau(&levels).map_or(f64::NAN, |p| p.error),
+            (variance(&x) / n as f64).sqrt()
+        );
+    }
+
+    println!("\nブロッキング法の各段 (φ = 0.9):");
+    let x = ar1_series(0.9, n, &mut rng);
+    println!(
+        "{:>10} {:>10} {:>12} {:>12}",
+        "ブロック長", "ブロック数", "誤差", "誤差の誤差"
+    );
+    for level in blocking(&x).iter().take(14) {
+        println!(
+            "{:>10} {:>10} {:>12.6} {:>12.6}",
+            level.block_size, level.n_blocks, level.error, level.error_of_error
+        );
+    }
+
+    // ----------------------------------------
+    // 2. メトロポリス法の複数チェーン: R̂ と実効サンプル数
+    // ----------------------------------------
+    println!("\n=== 2. メトロポリス法 (目標 N(0, 1), δ = 1) ===");
+    let inits = [-10.0, -3.0, 3.0, 10.0];
+    let n_steps = 20_000;
+    let chains: Vec<Vec<f64>> = inits
+        .iter()
+        .map(|&x0| metropolis_chain(x0, 1.0, n_steps, &mut rng))
+        .collect();
+
+    println!("{:>12} {:>10}", "捨てた長さ", "R̂");
+    for burn_in in [0, 100, 1000, 2000] {
+        // R̂ はバーンインを含めると初期値の影響で 1 より大きくなる
+        let truncated: Vec<Vec<f64>> = chains
+            .iter()
+            .map(|c| c[burn_in..burn_in + 500].to_vec())
+            .collect();
+        println!("{:>12} {:>10.4}", burn_in, gelman_rubin(&truncated));
+    }
+    println!("(各チェーンの先頭から指定の長さを捨て、続く 500 ステップで評価)");
+    let after_burn_in: Vec<Vec<f64>> = chains.iter().map(|c| c[2000..].to_vec()).collect();
+    println!(
+        "バーンイン 2000 後の全体の R̂ = {:.5}\n",
+        gelman_rubin(&after_burn_in)
+    );
+    for (k, c) in after_burn_in.iter().enumerate() {
+        report(&format!("チェーン {} (x₀ = {})", k, inits[k]), c);
+    }
+
+    // ----------------------------------------
+    // 3. 派生量の誤差: ジャックナイフ法とブートストラップ法
+    // ----------------------------------------
+    println!("\n=== 3. 派生量の誤差 (チェーン 0) ===");
+    let c = &after_burn_in[0];
+    // 正規分布では <x⁴>/<x²>² = 3 (尖度)
+    let kurtosis = |x: &[f64]| {
+        let m2 = x.iter().map(|v| v * v).sum::<f64>() / x.len() as f64;
+        let m4 = x.iter().map(|v| v.powi(4)).sum::<f64>() / x.len() as f64;
+        m4 / (m2 * m2)
+    };
+    let second_moment = |x: &[f64]| x.iter().map(|v| v * v).sum::<f64>() / x.len() as f64;
+    println!(
+        "{:<18} {:>10} {:>22} {:>22}",
+        "量", "理論値", "ジャックナイフ", "ブートストラップ"
+    );
+    for (name, exact, f) in [
+        ("<x²>", 1.0, &second_moment as &dyn Fn(&[f64]) -> f64),
+        ("<x⁴>/<x²>²", 3.0, &kurtosis),
+    ] {
+        let (jk, jk_err) = jackknife(c, 50, f);
+        let (bs, bs_err) = bootstrap(c, 50, 500, f, &mut rng);
+        println!(
+            "{:<18} {:>10.3} {:>12.4} ± {:<7.4} {:>12.4} ± {:<7.4}",
+            name, exact, jk, jk_err, bs, bs_err
+        );
+    }
+    println!("(ブロック数 50、1ブロックは自己相関時間より十分長い)");
+
+    // ----------------------------------------
+    // 4. ch10 の分子動力学の時系列
+    // ----------------------------------------
+    println!("\n=== 4. 分子動力学の時系列 (ch10/molecular_dynamics.csv) ===");
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../ch10/molecular_dynamics.csv"
+    );
+    match (
+        read_csv_column(path, "potential_energy"),
+        read_csv_column(path, "kinetic_energy"),
+    ) {
+        (Some(pe), Some(ke)) => {
+            report("ポテンシャルエネルギー", &pe);
+            report("運動エネルギー", &ke);
+            println!("(系列が短いので τ_int とブロッキングの誤差は大きめに見積もること)");
+        }
+        _ => println!(
+            "{} が読み込めません（ch10 の molecular_dynamics を先に実行してください）",
+            path
+        ),
+    }
+}
@@ -0,0 +1,517 @@
+use ch09::rng::stream_rng;
+use ndarray::{Array1, Array2};
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+use rayon::prelude::*;
+use std::time::Instant;
+
+/// 目標分布（規格化されていない対数密度）
+/// MALA と HMC を使う場合は勾配 ∇log π(x) も実装する
+trait LogDensity: Sync {
+    fn dim(&self) -> usize;
+    fn log_density(&self, x: &Array1<f64>) -> f64;
+    fn gradient(&self, _x: &Array1<f64>) -> Option<Array1<f64>> {
+        None
+    }
+}
+
+/// 提案分布
+#[derive(Clone, Copy, Debug)]
+enum Proposal {
+    /// ガウス型ランダムウォーク x' = x + σξ
+    RandomWalk { step: f64 },
+    /// 適応的メトロポリス法 (Haario et al. 2001)
+    /// adapt_start ステップ以降はそれまでの標本共分散から提案共分散を作る
+    Adaptive {
+        initial_step: f64,
+        adapt_start: usize,
+    },
+    /// Metropolis-adjusted Langevin algorithm: x' = x + (h/2)∇log π(x) + √h ξ
+    Mala { step: f64 },
+    /// ハミルトニアン・モンテカルロ法（リープフロッグ法 n_leapfrog ステップ）
+    Hmc { step: f64, n_leapfrog: usize },
+}
+
+/// サンプラーの設定
+struct SamplerConfig {
+    proposal: Proposal,
+    /// 保存するサンプル数（チェーンごと）
+    n_samples: usize,
+    /// 捨てる初期ステップ数
+    burn_in: usize,
+    /// 何ステップごとに1つ保存するか
+    thin: usize,
+    seed: u64,
+}
+
+/// 1本のチェーンの結果
+struct Chain {
+    samples: Vec<Array1<f64>>,
+    acceptance_rate: f64,
+}
+
+/// 適応的メトロポリス法のための逐次的な平均・共分散
+struct RunningCovariance {
+    count: usize,
+    mean: Array1<f64>,
+    /// Σ (x - mean)(x - mean)ᵀ
+    m2: Array2<f64>,
+}
+
+impl RunningCovariance {
+    fn new(dim: usize) -> Self {
+        Self {
+            count: 0,
+            mean: Array1::zeros(dim),
+            m2: Array2::zeros((dim, dim)),
+        }
+    }
+
+    /// Welford の方法で1点追加する
+    fn push(&mut self, x: &Array1<f64>) {
+        self.count += 1;
+        let delta = x - &self.mean;
+        self.mean.scaled_add(1.0 / self.count as f64, &delta);
+        let delta2 = x - &self.mean;
+        let d = x.len();
+        for i in 0..d {
+            for j in 0..d {
+                self.m2[[i, j]] += delta[i] * delta2[j];
+            }
+        }
+    }
+
+    fn covariance(&self) -> Array2<f64> {
+        &self.m2 / (self.count as f64 - 1.0).max(1.0)
+    }
+}
+
+/// 対称正定値行列のコレスキー分解 A = LLᵀ（下三角 L を返す）
+fn cholesky(a: &Array2<f64>) -> Array2<f64> {
+    let n = a.nrows();
+    let mut l = Array2::zeros((n, n));
+    for i in 0..n {
+        for j in 0..=i {
+            let mut sum = a[[i, j]];
+            for k in 0..j {
+                sum -= l[[i, k]] * l[[j, k]];
+            }
+            if i == j {
+                l[[i, i]] = sum.max(1e-300).sqrt();
+            } else {
+                l[[i, j]] = sum / l[[j, j]];
+            }
+        }
+    }
+    l
+}
+
+fn standard_normal_vector(dim: usize, rng: &mut ChaCha8Rng) -> Array1<f64> {
+    Array1::from_shape_fn(dim, |_| StandardNormal.sample(rng))
+}
+
+/// 1本のチェーンを走らせる
+fn run_chain<T: LogDensity>(
+    target: &T,
+    config: &SamplerConfig,
+    x_init: Array1<f64>,
+    rng: &mut ChaCha8Rng,
+) -> Chain {
+    let d = target.dim();
+    let total_steps = config.burn_in + config.n_samples * config.thin;
+    let grad = |x: &Array1<f64>| {
+        target
+            .gradient(x)
+            .expect("MALA と HMC には対数密度の勾配が必要です")
+    };
+
+    let mut x = x_init;
+    let mut log_p = target.log_density(&x);
+    let mut samples = Vec::with_capacity(config.n_samples);
+    let mut accepted = 0;
+
+    // 適応的メトロポリス法の状態（スケール s_d = 2.38²/d は Gelman らの最適値）
+    let s_d = 2.38 * 2.38 / d as f64;
+    let mut history = RunningCovariance::new(d);
+
+    for step in 0..total_steps {
+        let (x_new, log_p_new, log_q_ratio) = match config.proposal {
+            Proposal::RandomWalk { step } => {
+                let x_new = &x + &(standard_normal_vector(d, rng) * step);
+                let lp = target.log_density(&x_new);
+                (x_new, lp, 0.0)
+            }
+            Proposal::Adaptive {
+                initial_step,
+                adapt_start,
+            } => {
+                history.push(&x);
+                // 提案共分散 C_t = s_d (Cov + εI)、適応前は固定幅 initial_step
+                let chol = if step >= adapt_start {
+                    let mut cov = history.covariance();
+                    for i in 0..d {
+                        cov[[i, i]] += 1e-8;
+                    }
+                    cholesky(&(cov * s_d))
+                } else {
+                    Array2::eye(d) * initial_step
+                };
+                let x_new = &x + &chol.dot(&standard_normal_vector(d, rng));
+                let lp = target.log_density(&x_new);
+                (x_new, lp, 0.0)
+            }
+            Proposal::Mala { step } => {
+                // 提案が非対称なので q(x|x')/q(x'|x) を受理確率に含める
+                let g = grad(&x);
+                let mean_fwd = &x + &(&g * (0.5 * step));
+                let x_new = &mean_fwd + &(standard_normal_vector(d, rng) * step.sqrt());
+                let lp = target.log_density(&x_new);
+                let g_new = grad(&x_new);
+                let mean_bwd = &x_new + &(&g_new * (0.5 * step));
+                let log_q_fwd = -(&x_new - &mean_fwd).mapv(|v| v * v).sum() / (2.0 * step);
+                let log_q_bwd = -(&x - &mean_bwd).mapv(|v| v * v).sum() / (2.0 * step);
+                (x_new, lp, log_q_bwd - log_q_fwd)
+            }
+            Proposal::Hmc { step, n_leapfrog } => {
+                // 運動量 p ~ N(0, I) を引き、H = -log π(x) + |p|²/2 を近似的に保存して動かす
+                let p0 = standard_normal_vector(d, rng);
+                let mut q = x.clone();
+                let mut p = &p0 + &(grad(&q) * (0.5 * step));
+                for i in 0..n_leapfrog {
+                    q.scaled_add(step, &p);
+                    let factor = if i + 1 < n_leapfrog { step } else { 0.5 * step };
+                    p.scaled_add(factor, &grad(&q));
+                }
+                let lp = target.log_density(&q);
+                // 受理確率 min(1, exp(-ΔH)) の運動エネルギー部分
+                let kinetic = 0.5 * (p0.dot(&p0) - p.dot(&p));
+                (q, lp, kinetic)
+            }
+        };
+
+        let log_alpha = log_p_new - log_p + log_q_ratio;
+        if log_alpha >= 0.0 || rng.random::<f64>().ln() < log_alpha {
+            x = x_new;
+            log_p = log_p_new;
+            accepted += 1;
+        }
+
+        // バーンイン後、thin ステップごとに保存する
+        if step >= config.burn_in && (step - config.burn_in + 1).is_multiple_of(config.thin) {
+            samples.push(x.clone());
+        }
+    }
+
+    Chain {
+        samples,
+        acceptance_rate: accepted as f64 / total_steps as f64,
+    }
+}
+
+/// 複数のチェーンを rayon で並列に走らせる
+/// チェーン k は ChaCha8 の独立なストリーム k を使うので、結果はスレッド数によらない
+fn run_chains<T: LogDensity>(
+    target: &T,
+    config: &SamplerConfig,
+    inits: &[Array1<f64>],
+) -> Vec<Chain> {
+    inits
+        .par_iter()
+        .enumerate()
+        .map(|(k, x0)| {
+            let mut rng = stream_rng(config.seed, k as u64);
+            run_chain(target, config, x0.clone(), &mut rng)
+        })
+        .collect()
+}
+
+/// 全チェーンをまとめた標本平均と標本分散（各成分）
+fn pooled_moments(chains: &[Chain]) -> (Array1<f64>, Array1<f64>) {
+    let all: Vec<&Array1<f64>> = chains.iter().flat_map(|c| c.samples.iter()).collect();
+    let n = all.len() as f64;
+    let d = all[0].len();
+    let mut mean = Array1::zeros(d);
+    for x in &all {
+        mean += *x;
+    }
+    mean /= n;
+    let mut var = Array1::zeros(d);
+    for x in &all {
+        var += &(*x - &mean).mapv(|v| v * v);
+    }
+    var /= n - 1.0;
+    (mean, var)
+}
+
+/// 成分0のラグ1自己相関（チェーンごとに計算して平均）
+fn lag1_autocorrelation(chains: &[Chain]) -> f64 {
+    let rho: f64 = chains
+        .iter()
+        .map(|c| {
+            let xs: Vec<f64> = c.samples.iter().map(|x| x[0]).collect();
+            let n = xs.len() as f64;
+            let mean = xs.iter().sum::<f64>() / n;
+            let var = xs.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+            let cov = xs
+                .windows(2)
+                .map(|w| (w[0] - mean) * (w[1] - mean))
+                .sum::<f64>();
+            cov / var
+        })
+        .sum();
+    rho / chains.len() as f64
+}
+
+// ----------------------------------------
+// 目標分布の例
+// ----------------------------------------
+
+/// 多変量正規分布 N(μ, Σ)、精度行列 P = Σ⁻¹ で与える
+struct Gaussian {
+    mean: Array1<f64>,
+    precision: Array2<f64>,
+}
+
+impl LogDensity for Gaussian {
+    fn dim(&self) -> usize {
+        self.mean.len()
+    }
+    fn log_density(&self, x: &Array1<f64>) -> f64 {
+        let r = x - &self.mean;
+        -0.5 * r.dot(&self.precision.dot(&r))
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Option<Array1<f64>> {
+        Some(-self.precision.dot(&(x - &self.mean)))
+    }
+}
+
+/// Haario らの「バナナ型」分布: x₁ ~ N(0, 100), x₂ | x₁ ~ N(b(x₁² - 100), 1)
+/// 平均は (0, 0)、分散は (100, 1 + 2·b²·100²)
+struct Banana {
+    b: f64,
+}
+
+impl LogDensity for Banana {
+    fn dim(&self) -> usize {
+        2
+    }
+    fn log_density(&self, x: &Array1<f64>) -> f64 {
+        let y = x[1] - self.b * (x[0] * x[0] - 100.0);
+        -x[0] * x[0] / 200.0 - 0.5 * y * y
+    }
+    fn gradient(&self, x: &Array1<f64>) -> Option<Array1<f64>> {
+        let y = x[1] - self.b * (x[0] * x[0] - 100.0);
+        Some(Array1::from(vec![
+            -x[0] / 100.0 + 2.0 * self.b * x[0] * y,
+            -y,
+        ]))
+    }
+}
+
+/// 各提案分布でサンプリングして、真の平均・分散と比較する
+fn compare_proposals<T: LogDensity>(
+    target: &T,
+    proposals: &[Proposal],
+    true_mean: &Array1<f64>,
+    true_var: &Array1<f64>,
+    inits: &[Array1<f64>],
+) {
+    println!(
+        "{:<52} {:>7} {:>11} {:>11} {:>9} {:>7}",
+        "提案分布", "受理率", "平均の誤差", "分散相対誤差", "ρ(1)", "時間[s]"
+    );
+    for &proposal in proposals {
+        let config = SamplerConfig {
+            proposal,
+            n_samples: 20_000,
+            burn_in: 5_000,
+            thin: 5,
+            seed: 42,
+        };
+        let start = Instant::now();
+        let chains = run_chains(target, &config, inits);
+        let elapsed = start.elapsed().as_secs_f64();
+
+        let (mean, var) = pooled_moments(&chains);
+        let mean_err = (&mean - true_mean)
+            .iter()
+            .zip(true_var.iter())
+            .map(|(e, v)| e.abs() / v.sqrt())
+            .fold(0.0, f64::max);
+        let var_err = (&var - true_var)
+            .iter()
+            .zip(true_var.iter())
+            .map(|(e, v)| (e / v).abs())
+            .fold(0.0, f64::max);
+        let acceptance =
+            chains.iter().map(|c| c.acceptance_rate).sum::<f64>() / chains.len() as f64;
+        println!(
+            "{:<52} {:>7.3} {:>11.4} {:>11.4} {:>9.4} {:>7.2}",
+            format!("{:?}", proposal),
+            acceptance,
+            mean_err,
+            var_err,
+            lag1_autocorrelation(&chains),
+            elapsed
+        );
+    }
+    println!("(平均の誤差は標準偏差で割った値、分散は各成分の相対誤差の最大値)");
+}
+
+fn main() {
+    let n_chains = 4;
+    println!("チェーン数: {} (rayon で並列実行)", n_chains);
+    println!("各チェーン: バーンイン 5000 ステップ, 間引き 5, 保存 20000 サンプル\n");
+
+    // ----------------------------------------
+    // 1. 強い相関のある2次元正規分布
+    // ----------------------------------------
+    let (s1, s2, rho) = (1.0, 3.0, 0.95);
+    let det = (s1 * s2) * (s1 * s2) * (1.0 - rho * rho);
+    let cov = Array2::from_shape_vec((2, 2), vec![s1 * s1, rho * s1 * s2, rho * s1 * s2, s2 * s2])
+        .unwrap();
+    let precision = Array2::from_shape_vec(
+        (2, 2),
+        vec![
+            cov[[1, 1]] / det,
+            -cov[[0, 1]] / det,
+            -cov[[1, 0]] / det,
+            cov[[0, 0]] / det,
+        ],
+    )
+    .unwrap();
+    let gaussian = Gaussian {
+        mean: Array1::from(vec![1.0, -2.0]),
+        precision,
+    };
+    // 初期値は分布の中心から離れた点にばらまく
+    let inits: Vec<Array1<f64>> = (0..n_chains)
+        .map(|k| Array1::from(vec![5.0 - 3.0 * k as f64, 8.0 - 5.0 * k as f64]))
+        .collect();
+
+    println!("=== 1. 2次元正規分布 (σ = (1, 3), ρ = 0.95) ===");
+    compare_proposals(
+        &gaussian,
+        &[
+            Proposal::RandomWalk { step: 0.5 },
+            Proposal::Adaptive {
+                initial_step: 0.5,
+                adapt_start: 1000,
+            },
+            Proposal::Mala { step: 0.3 },
+            Proposal::Hmc {
+                step: 0.2,
+                n_leapfrog: 10,
+            },
+        ],
+        &gaussian.mean,
+        &Array1::from(vec![s1 * s1, s2 * s2]),
+        &inits,
+    );
+
+    // ----------------------------------------
+    // 2. スケールの大きく異なる10次元正規分布
+    // ----------------------------------------
+    let d = 10;
+    let scales = Array1::from_shape_fn(d, |i| 10f64.powf(-1.0 + 2.0 * i as f64 / (d - 1) as f64));
+    let gaussian10 = Gaussian {
+        mean: Array1::zeros(d),
+        precision: Array2::from_diag(&scales.mapv(|s| 1.0 / (s * s))),
+    };
+    let inits10: Vec<Array1<f64>> = (0..n_chains)
+        .map(|k| Array1::from_elem(d, 1.0 + k as f64))
+        .collect();
+
+    println!("\n=== 2. 10次元正規分布 (標準偏差 0.1 〜 10) ===");
+    compare_proposals(
+        &gaussian10,
+        &[
+            Proposal::RandomWalk { step: 0.1 },
+            Proposal::Adaptive {
+                initial_step: 0.1,
+                adapt_start: 2000,
+            },
+            Proposal::Mala { step: 0.005 },
+            Proposal::Hmc {
+                step: 0.05,
+                n_leapfrog: 40,
+            },
+        ],
+        &gaussian10.mean,
+        &scales.mapv(|s| s * s),
+        &inits10,
+    );
+
+    // ----------------------------------------
+    // 3. バナナ型分布
+    // ----------------------------------------
+    let banana = Banana { b: 0.03 };
+    let inits_banana: Vec<Array1<f64>> = (0..n_chains)
+        .map(|k| Array1::from(vec![-6.0 + 4.0 * k as f64, 0.0]))
+        .collect();
+    let banana_var = Array1::from(vec![100.0, 1.0 + 2.0 * banana.b * banana.b * 1.0e4]);
+
+    println!("\n=== 3. バナナ型分布 (b = 0.03) ===");
+    compare_proposals(
+        &banana,
+        &[
+            Proposal::RandomWalk { step: 2.0 },
+            Proposal::Adaptive {
+                initial_step: 2.0,
+                adapt_start: 2000,
+            },
+            Proposal::Mala { step: 1.0 },
+            Proposal::Hmc {
+                step: 0.3,
+                n_leapfrog: 20,
+            },
+        ],
+        &Array1::zeros(2),
+        &banana_var,
+        &inits_banana,
+    );
+
+    // ----------------------------------------
+    // 4. チェーンごとの結果と再現性
+    // ----------------------------------------
+    println!("\n=== 4. チェーンごとの平均 (2次元正規分布, HMC) ===");
+    let config = SamplerConfig {
+        proposal: Proposal::Hmc {
+            step: 0.2,
+            n_leapfrog: 10,
+        },
+        n_samples: 5_000,
+        burn_in: 1_000,
+        thin: 1,
+        seed: 7,
+    };
+    let chains = run_chains(&gaussian, &config, &inits);
+    for (k, chain) in chains.iter().enumerate() {
+        let n = chain.samples.len() as f64;
+        let mean = chain
+            .samples
+            .iter()
+            .fold(Array1::<f64>::zeros(2), |acc, x| acc + x)
+            / n;
+        println!(
+            "  チェーン {} (初期値 {:?}): 平均 = ({:>8.4}, {:>8.4}), 受理率 = {:.3}",
+            k,
+            inits[k].to_vec(),
+            mean[0],
+            mean[1],
+            chain.acceptance_rate
+        );
+    }
+    // 同じシードで1スレッドだけで実行しても同じ結果になる
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let chains_serial = pool.install(|| run_chains(&gaussian, &config, &inits));
+    let identical = chains
+        .iter()
+        .zip(&chains_serial)
+        .all(|(a, b)| a.samples == b.samples);
+    println!("  1スレッドでの再実行と一致: {}", identical);
+}
@@ -0,0 +1,421 @@
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Exp1};
+use std::f64::consts::PI;
+
+/// 一様な物質の層
+#[derive(Clone, Copy)]
+struct Layer {
+    thickness: f64,
+    /// 全断面積 μ_t（平均自由行程の逆数）
+    sigma_t: f64,
+    /// 散乱アルベド ω = μ_s / μ_t（衝突したときに散乱する確率）
+    albedo: f64,
+    /// Henyey–Greenstein 位相関数の非対称因子 g = <cos θ>（0 なら等方散乱）
+    g: f64,
+}
+
+/// 粒子の運動の次元
+#[derive(Clone, Copy, Debug)]
+enum Geometry {
+    /// 1次元のロッド模型（方向は ±z のみ。散乱すると確率 (1+g)/2 で前方へ進む）
+    Rod,
+    /// 3次元（z 方向に積み重なった無限平板）
+    ThreeD,
+}
+
+/// 入射条件
+#[derive(Clone, Copy, Debug)]
+enum Incidence {
+    /// 垂直入射
+    Normal,
+    /// 等方的な入射（入射する流れが cos θ に比例する余弦則）
+    Isotropic,
+}
+
+/// z = 0 から +z 方向に積み重ねた多層平板
+struct Slab {
+    layers: Vec<Layer>,
+    /// 各層の境界の z 座標（layers.len() + 1 個）
+    boundaries: Vec<f64>,
+}
+
+impl Slab {
+    fn new(layers: &[Layer]) -> Self {
+        let mut boundaries = vec![0.0];
+        for layer in layers {
+            boundaries.push(boundaries.last().unwrap() + layer.thickness);
+        }
+        Self {
+            layers: layers.to_vec(),
+            boundaries,
+        }
+    }
+}
+
+/// 透過・反射・各層での吸収の回数
+struct Tally {
+    n: usize,
+    transmitted: usize,
+    reflected: usize,
+    absorbed: Vec<usize>,
+}
+
+impl Tally {
+    /// 割合と二項分布の標準誤差 √(p(1-p)/N)
+    fn fraction(&self, count: usize) -> (f64, f64) {
+        let p = count as f64 / self.n as f64;
+        (p, (p * (1.0 - p) / self.n as f64).sqrt())
+    }
+}
+
+/// Henyey–Greenstein 分布から散乱角の余弦 cos θ をサンプリングする
+fn sample_henyey_greenstein(g: f64, rng: &mut impl Rng) -> f64 {
+    let u: f64 = rng.random();
+    if g.abs() < 1e-6 {
+        2.0 * u - 1.0
+    } else {
+        let s = (1.0 - g * g) / (1.0 - g + 2.0 * g * u);
+        (1.0 + g * g - s * s) / (2.0 * g)
+    }
+}
+
+/// 方向 (ux, uy, uz) を散乱角 θ、方位角 φ だけ回転させる
+fn rotate(dir: [f64; 3], cos_theta: f64, phi: f64) -> [f64; 3] {
+    let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    let [ux, uy, uz] = dir;
+    if uz.abs() > 0.99999 {
+        [
+            sin_theta * cos_phi,
+            sin_theta * sin_phi,
+            uz.signum() * cos_theta,
+        ]
+    } else {
+        let tmp = (1.0 - uz * uz).sqrt();
+        [
+            sin_theta * (ux * uz * cos_phi - uy * sin_phi) / tmp + ux * cos_theta,
+            sin_theta * (uy * uz * cos_phi + ux * sin_phi) / tmp + uy * cos_theta,
+            -sin_theta * cos_phi * tmp + uz * cos_theta,
+        ]
+    }
+}
+
+/// n 個の粒子を z = 0 の面から入射させ、平板中での飛行を追跡する
+fn transport(
+    slab: &Slab,
+    geometry: Geometry,
+    incidence: Incidence,
+    n: usize,
+    rng: &mut impl Rng,
+) -> Tally {
+    let n_layers = slab.layers.len();
+    let mut tally = Tally {
+        n,
+        transmitted: 0,
+        reflected: 0,
+        absorbed: vec![0; n_layers],
+    };
+
+    for _ in 0..n {
+        let mut dir = match (geometry, incidence) {
+            (Geometry::Rod, _) | (_, Incidence::Normal) => [0.0, 0.0, 1.0],
+            (Geometry::ThreeD, Incidence::Isotropic) => {
+                // 余弦則: μ = √u
+                let mu = rng.random::<f64>().sqrt();
+                rotate([0.0, 0.0, 1.0], mu, 2.0 * PI * rng.random::<f64>())
+            }
+        };
+        let mut z = 0.0;
+        let mut layer = 0;
+
+        'flight: loop {
+            // 光学的距離 τ ~ Exp(1) だけ進む（層をまたぐときは各層の μ_t で換算する）
+            let mut tau: f64 = Exp1.sample(rng);
+            loop {
+                let l = &slab.layers[layer];
+                let to_boundary = if dir[2] > 0.0 {
+                    (slab.boundaries[layer + 1] - z) / dir[2]
+                } else {
+                    (slab.boundaries[layer] - z) / dir[2]
+                };
+                if l.sigma_t * to_boundary > tau {
+                    z += dir[2] * tau / l.sigma_t;
+                    break;
+                }
+                // 境界を越えて隣の層（または外）へ
+                tau -= l.sigma_t * to_boundary;
+                if dir[2] > 0.0 {
+                    if layer + 1 == n_layers {
+                        tally.transmitted += 1;
+                        break 'flight;
+                    }
+                    layer += 1;
+                    z = slab.boundaries[layer];
+                } else {
+                    if layer == 0 {
+                        tally.reflected += 1;
+                        break 'flight;
+                    }
+                    z = slab.boundaries[layer];
+                    layer -= 1;
+                }
+            }
+
+            // 衝突: 確率 1 - ω で吸収、ω で散乱
+            let l = &slab.layers[layer];
+            if rng.random::<f64>() >= l.albedo {
+                tally.absorbed[layer] += 1;
+                break;
+            }
+            dir = match geometry {
+                Geometry::Rod => {
+                    let forward = rng.random::<f64>() < 0.5 * (1.0 + l.g);
+                    [0.0, 0.0, if forward { dir[2] } else { -dir[2] }]
+                }
+                Geometry::ThreeD => {
+                    let cos_theta = sample_henyey_greenstein(l.g, rng);
+                    rotate(dir, cos_theta, 2.0 * PI * rng.random::<f64>())
+                }
+            };
+        }
+    }
+    tally
+}
+
+// ----------------------------------------
+// 解析解
+// ----------------------------------------
+
+/// ロッド模型の均一な層の透過率と反射率（2流束方程式の厳密解）
+/// ψ₊' = -aψ₊ + bψ₋, ψ₋' = aψ₋ - bψ₊,  a = μ_t - μ_s p_f, b = μ_s p_b
+fn rod_analytic(layer: &Layer) -> (f64, f64) {
+    let mu_s = layer.sigma_t * layer.albedo;
+    let p_forward = 0.5 * (1.0 + layer.g);
+    let a = layer.sigma_t - mu_s * p_forward;
+    let b = mu_s * (1.0 - p_forward);
+    let k = (a * a - b * b).max(0.0).sqrt();
+    let l = layer.thickness;
+    // sinh(kL)/k は k → 0 で L になる
+    let sinh_over_k = if k * l < 1e-8 { l } else { (k * l).sinh() / k };
+    let denom = (k * l).cosh() + a * sinh_over_k;
+    (1.0 / denom, b * sinh_over_k / denom)
+}
+
+/// 2つの層を重ねたときの (透過率, 前面の反射率, 背面の反射率)（層の間の多重反射を足し合わせる）
+/// 透過率は相反性からどちら向きでも等しいが、重ねた層は非対称なので反射率は面ごとに持つ
+fn combine_layers(
+    (t1, r1, r1_back): (f64, f64, f64),
+    (t2, r2, r2_back): (f64, f64, f64),
+) -> (f64, f64, f64) {
+    let multiple = 1.0 / (1.0 - r1_back * r2);
+    (
+        t1 * t2 * multiple,
+        r1 + t1 * t1 * r2 * multiple,
+        r2_back + t2 * t2 * r1_back * multiple,
+    )
+}
+
+/// 均一な層（左右対称）の (T, R, R)
+fn symmetric((t, r): (f64, f64)) -> (f64, f64, f64) {
+    (t, r, r)
+}
+
+/// 指数積分 E_n(x) = ∫₀¹ μ^(n-2) exp(-x/μ) dμ（シンプソン則で数値積分）
+fn exponential_integral(n: i32, x: f64) -> f64 {
+    let m = 2000;
+    let h = 1.0 / m as f64;
+    let f = |mu: f64| {
+        if mu <= 0.0 {
+            0.0
+        } else {
+            mu.powi(n - 2) * (-x / mu).exp()
+        }
+    };
+    let mut sum = f(0.0) + f(1.0);
+    for i in 1..m {
+        let w = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += w * f(i as f64 * h);
+    }
+    sum * h / 3.0
+}
+
+fn print_tally(label: &str, tally: &Tally, expected: Option<(f64, f64)>) {
+    let (t, t_err) = tally.fraction(tally.transmitted);
+    let (r, r_err) = tally.fraction(tally.reflected);
+    let (a, a_err) = tally.fraction(tally.absorbed.iter().sum());
+    print!(
+        "{:<30} T = {:.5} ± {:.5}  R = {:.5} ± {:.5}  A = {:.5} ± {:.5}",
+        label, t, t_err, r, r_err, a, a_err
+    );
+    match expected {
+        Some((t_ex, r_ex)) => println!(
+            "  | 解析解 T = {:.5}, R = {:.5} ({:+.1}σ, {:+.1}σ)",
+            t_ex,
+            r_ex,
+            (t - t_ex) / t_err,
+            (r - r_ex) / r_err.max(1e-12)
+        ),
+        None => println!(),
+    }
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(12345);
+    let n = 1_000_000;
+    println!("粒子数: {}\n", n);
+
+    // ----------------------------------------
+    // 1. 純吸収体: T = exp(-μ_t L)（垂直入射）, T = 2E₃(μ_t L)（等方入射）
+    // ----------------------------------------
+    println!("=== 1. 純吸収体 (ω = 0) ===");
+    for tau in [0.5, 1.0, 2.0] {
+        let slab = Slab::new(&[Layer {
+            thickness: tau,
+            sigma_t: 1.0,
+            albedo: 0.0,
+            g: 0.0,
+        }]);
+        let tally = transport(&slab, Geometry::ThreeD, Incidence::Normal, n, &mut rng);
+        print_tally(
+            &format!("垂直入射 τ = {}", tau),
+            &tally,
+            Some(((-tau).exp(), 0.0)),
+        );
+        let tally = transport(&slab, Geometry::ThreeD, Incidence::Isotropic, n, &mut rng);
+        print_tally(
+            &format!("等方入射 τ = {}", tau),
+            &tally,
+            Some((2.0 * exponential_integral(3, tau), 0.0)),
+        );
+    }
+
+    // ----------------------------------------
+    // 2. ロッド模型: 散乱のある層と多層平板の厳密解
+    // ----------------------------------------
+    println!("\n=== 2. ロッド模型 (1次元) ===");
+    let cases = [
+        ("τ = 1, ω = 0.5, 等方", 1.0, 0.5, 0.0),
+        ("τ = 2, ω = 0.9, 等方", 2.0, 0.9, 0.0),
+        ("τ = 5, ω = 1.0, 等方", 5.0, 1.0, 0.0),
+        ("τ = 3, ω = 0.95, g = 0.6", 3.0, 0.95, 0.6),
+    ];
+    for (label, tau, albedo, g) in cases {
+        let layer = Layer {
+            thickness: tau,
+            sigma_t: 1.0,
+            albedo,
+            g,
+        };
+        let tally = transport(
+            &Slab::new(&[layer]),
+            Geometry::Rod,
+            Incidence::Normal,
+            n,
+            &mut rng,
+        );
+        print_tally(label, &tally, Some(rod_analytic(&layer)));
+    }
+
+    let layer1 = Layer {
+        thickness: 0.5,
+        sigma_t: 2.0,
+        albedo: 0.9,
+        g: 0.0,
+    };
+    let layer2 = Layer {
+        thickness: 2.0,
+        sigma_t: 0.5,
+        albedo: 0.3,
+        g: 0.5,
+    };
+    let layer3 = Layer {
+        thickness: 1.0,
+        sigma_t: 3.0,
+        albedo: 0.99,
+        g: -0.2,
+    };
+    let tally = transport(
+        &Slab::new(&[layer1, layer2, layer3]),
+        Geometry::Rod,
+        Incidence::Normal,
+        n,
+        &mut rng,
+    );
+    let (t_ex, r_ex, _) = combine_layers(
+        combine_layers(
+            symmetric(rod_analytic(&layer1)),
+            symmetric(rod_analytic(&layer2)),
+        ),
+        symmetric(rod_analytic(&layer3)),
+    );
+    print_tally("3層平板", &tally, Some((t_ex, r_ex)));
+
+    // ----------------------------------------
+    // 3. 3次元: Henyey–Greenstein 散乱と相似則
+    // ----------------------------------------
+    println!("\n=== 3. 3次元の散乱 ===");
+    println!("Henyey–Greenstein 分布の <cos θ>:");
+    for g in [-0.5, 0.0, 0.5, 0.9] {
+        let m = 200_000;
+        let mean: f64 = (0..m)
+            .map(|_| sample_henyey_greenstein(g, &mut rng))
+            .sum::<f64>()
+            / m as f64;
+        println!("  g = {:>5.2}: <cos θ> = {:>8.5}", g, mean);
+    }
+
+    // 厚い層では (μ_s, g) の散乱は輸送断面積 μ_s(1 - g) の等方散乱とほぼ等価になる
+    println!("\n相似則 μ_s' = μ_s(1 - g) の確認 (μ_a = 0.01, 厚さ 10, 垂直入射):");
+    let mu_a = 0.01;
+    for (mu_s, g) in [(1.0, 0.0), (2.0, 0.5), (5.0, 0.8), (10.0, 0.9)] {
+        let sigma_t = mu_a + mu_s;
+        let slab = Slab::new(&[Layer {
+            thickness: 10.0,
+            sigma_t,
+            albedo: mu_s / sigma_t,
+            g,
+        }]);
+        let tally = transport(&slab, Geometry::ThreeD, Incidence::Normal, n / 4, &mut rng);
+        print_tally(&format!("μ_s = {:>4}, g = {}", mu_s, g), &tally, None);
+    }
+
+    // ----------------------------------------
+    // 4. 多層平板での各層の吸収
+    // ----------------------------------------
+    println!("\n=== 4. 3次元の多層平板 (垂直入射) ===");
+    let layers = [
+        Layer {
+            thickness: 0.1,
+            sigma_t: 10.0,
+            albedo: 0.9,
+            g: 0.8,
+        },
+        Layer {
+            thickness: 1.0,
+            sigma_t: 20.0,
+            albedo: 0.99,
+            g: 0.9,
+        },
+        Layer {
+            thickness: 2.0,
+            sigma_t: 10.0,
+            albedo: 0.95,
+            g: 0.7,
+        },
+    ];
+    let tally = transport(
+        &Slab::new(&layers),
+        Geometry::ThreeD,
+        Incidence::Normal,
+        n,
+        &mut rng,
+    );
+    print_tally("3層平板", &tally, None);
+    for (i, &count) in tally.absorbed.iter().enumerate() {
+        let (a, err) = tally.fraction(count);
+        println!("  第{}層での吸収: {:.5} ± {:.5}", i + 1, a, err);
+    }
+    let total = tally.transmitted + tally.reflected + tally.absorbed.iter().sum::<usize>();
+    println!("  T + R + ΣA = {} / {} (粒子数の保存)", total, tally.n);
+}
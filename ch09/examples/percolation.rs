@@ -0,0 +1,559 @@
+use ch09::rng::stream_rng;
+use rand::RngExt;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+/// 素集合データ構造（Union-Find）
+/// 根ごとにクラスターの大きさと、x 方向の両端の面に触れているかのフラグを持つ
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    /// bit 0: x = 0 の面に接している、bit 1: x = L-1 の面に接している
+    faces: Vec<u8>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            faces: vec![0; n],
+        }
+    }
+
+    /// 経路半分法つきの根の探索
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// 大きさによる合併。新しい根を返す
+    fn union(&mut self, a: usize, b: usize) -> usize {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return ra;
+        }
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        self.faces[ra] |= self.faces[rb];
+        ra
+    }
+}
+
+/// 一辺 L の d 次元単純立方格子（自由境界）
+struct Lattice {
+    l: usize,
+    d: usize,
+    n_sites: usize,
+}
+
+impl Lattice {
+    fn new(l: usize, d: usize) -> Self {
+        Self {
+            l,
+            d,
+            n_sites: l.pow(d as u32),
+        }
+    }
+
+    /// 軸 axis 方向の座標
+    fn coord(&self, i: usize, axis: usize) -> usize {
+        (i / self.l.pow(axis as u32)) % self.l
+    }
+
+    /// 各軸の負の向きの隣接サイト（Hoshen–Kopelman 法で走査済みのもの）
+    fn backward_neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.d)
+            .filter(move |&axis| self.coord(i, axis) > 0)
+            .map(move |axis| i - self.l.pow(axis as u32))
+    }
+
+    /// すべての隣接サイト
+    fn neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.d).flat_map(move |axis| {
+            let stride = self.l.pow(axis as u32);
+            let c = self.coord(i, axis);
+            let down = (c > 0).then(|| i - stride);
+            let up = (c + 1 < self.l).then(|| i + stride);
+            down.into_iter().chain(up)
+        })
+    }
+
+    /// すべてのボンド（各軸の正の向き）
+    fn bonds(&self) -> Vec<(usize, usize)> {
+        (0..self.n_sites)
+            .flat_map(|i| {
+                (0..self.d)
+                    .filter(move |&axis| self.coord(i, axis) + 1 < self.l)
+                    .map(move |axis| (i, i + self.l.pow(axis as u32)))
+            })
+            .collect()
+    }
+
+    /// x = 0 と x = L-1 の面に接しているかのフラグ
+    fn face_flags(&self, i: usize) -> u8 {
+        let x = self.coord(i, 0);
+        (x == 0) as u8 | (((x + 1 == self.l) as u8) << 1)
+    }
+}
+
+// ----------------------------------------
+// 固定した占有確率でのクラスター解析
+// ----------------------------------------
+
+/// Hoshen–Kopelman 法によるラベル付け
+/// サイトを順に走査し、走査済みの隣接サイトのラベルを Union-Find で統合する
+/// 戻り値: 各サイトのクラスター番号（空サイトは None）とクラスターの大きさ
+fn hoshen_kopelman(lattice: &Lattice, occupied: &[bool]) -> (Vec<Option<usize>>, Vec<usize>) {
+    let mut labels: Vec<Option<usize>> = vec![None; lattice.n_sites];
+    // 仮ラベルの同値関係
+    let mut uf = UnionFind::new(0);
+    for i in 0..lattice.n_sites {
+        if !occupied[i] {
+            continue;
+        }
+        let mut label: Option<usize> = None;
+        for j in lattice.backward_neighbors(i) {
+            if let Some(lj) = labels[j] {
+                label = Some(match label {
+                    None => uf.find(lj),
+                    Some(li) => uf.union(li, lj),
+                });
+            }
+        }
+        labels[i] = Some(label.unwrap_or_else(|| {
+            uf.parent.push(uf.parent.len());
+            uf.size.push(0);
+            uf.faces.push(0);
+            uf.parent.len() - 1
+        }));
+    }
+
+    // 2回目の走査で仮ラベルを連番の最終ラベルに置き換える
+    let mut final_label = vec![usize::MAX; uf.parent.len()];
+    let mut sizes = Vec::new();
+    for label in labels.iter_mut().flatten() {
+        let root = uf.find(*label);
+        if final_label[root] == usize::MAX {
+            final_label[root] = sizes.len();
+            sizes.push(0);
+        }
+        *label = final_label[root];
+        sizes[*label] += 1;
+    }
+    (labels, sizes)
+}
+
+/// Union-Find で直接クラスターを求める（Hoshen–Kopelman 法の検算用）
+/// 戻り値: クラスターの大きさの一覧（降順）と、x 方向に貫通するクラスターがあるか
+fn union_find_clusters(lattice: &Lattice, occupied: &[bool]) -> (Vec<usize>, bool) {
+    let mut uf = UnionFind::new(lattice.n_sites);
+    for i in 0..lattice.n_sites {
+        uf.faces[i] = lattice.face_flags(i);
+    }
+    for (a, b) in lattice.bonds() {
+        if occupied[a] && occupied[b] {
+            uf.union(a, b);
+        }
+    }
+    let mut sizes = Vec::new();
+    let mut spanning = false;
+    for (i, &occ) in occupied.iter().enumerate() {
+        if occ && uf.find(i) == i {
+            sizes.push(uf.size[i]);
+            spanning |= uf.faces[i] == 3;
+        }
+    }
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    (sizes, spanning)
+}
+
+/// ボンドパーコレーション: 全サイトがあり、各ボンドが確率 p で開いている
+/// 戻り値: 最大クラスターの大きさと、x 方向に貫通するか
+fn bond_percolation(lattice: &Lattice, p: f64, rng: &mut ChaCha8Rng) -> (usize, bool) {
+    let mut uf = UnionFind::new(lattice.n_sites);
+    for i in 0..lattice.n_sites {
+        uf.faces[i] = lattice.face_flags(i);
+    }
+    for (a, b) in lattice.bonds() {
+        if rng.random::<f64>() < p {
+            uf.union(a, b);
+        }
+    }
+    let (mut largest, mut spanning) = (0, false);
+    for i in 0..lattice.n_sites {
+        if uf.find(i) == i {
+            largest = largest.max(uf.size[i]);
+            spanning |= uf.faces[i] == 3;
+        }
+    }
+    (largest, spanning)
+}
+
+// ----------------------------------------
+// Newman–Ziff 法
+// ----------------------------------------
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Site,
+    Bond,
+}
+
+/// 1回の試行の結果
+struct Sweep {
+    /// 初めて x 方向に貫通したときの占有数
+    n_span: usize,
+    /// 加えたサイト（ボンド）の総数
+    n_total: usize,
+}
+
+/// Newman–Ziff 法: サイト（ボンド）をランダムな順に1つずつ加え、Union-Find を更新していく
+/// 1回の試行ですべての占有数 n についての観測量が O(N) で得られる
+fn newman_ziff(lattice: &Lattice, kind: Kind, rng: &mut ChaCha8Rng) -> Sweep {
+    let mut uf = UnionFind::new(lattice.n_sites);
+    for i in 0..lattice.n_sites {
+        uf.faces[i] = lattice.face_flags(i);
+    }
+    let mut n_span = usize::MAX;
+    let n_total = match kind {
+        Kind::Site => {
+            let mut order: Vec<usize> = (0..lattice.n_sites).collect();
+            order.shuffle(rng);
+            let mut occupied = vec![false; lattice.n_sites];
+            for (n, &i) in order.iter().enumerate() {
+                occupied[i] = true;
+                let mut root = uf.find(i);
+                for j in lattice.neighbors(i) {
+                    if occupied[j] {
+                        root = uf.union(root, j);
+                    }
+                }
+                if n_span == usize::MAX && uf.faces[root] == 3 {
+                    n_span = n + 1;
+                }
+            }
+            order.len()
+        }
+        Kind::Bond => {
+            let mut bonds = lattice.bonds();
+            bonds.shuffle(rng);
+            for (n, &(a, b)) in bonds.iter().enumerate() {
+                let root = uf.union(a, b);
+                if n_span == usize::MAX && uf.faces[root] == 3 {
+                    n_span = n + 1;
+                }
+            }
+            bonds.len()
+        }
+    };
+    Sweep { n_span, n_total }
+}
+
+/// 占有数 n ごとの量 Q_n から、占有確率 p での期待値 Σ_n B(N, n; p) Q_n を求める
+/// 2項分布の重みは最頻値から漸化式で外側へ計算する
+fn convolve(q: &[f64], p: f64) -> f64 {
+    let n_total = q.len() - 1;
+    let mode = ((n_total as f64 * p).round() as usize).min(n_total);
+    let mut weights = vec![0.0; n_total + 1];
+    weights[mode] = 1.0;
+    let ratio = p / (1.0 - p);
+    for n in mode..n_total {
+        weights[n + 1] = weights[n] * (n_total - n) as f64 / (n + 1) as f64 * ratio;
+        if weights[n + 1] < 1e-300 {
+            break;
+        }
+    }
+    for n in (1..=mode).rev() {
+        weights[n - 1] = weights[n] * n as f64 / (n_total - n + 1) as f64 / ratio;
+        if weights[n - 1] < 1e-300 {
+            break;
+        }
+    }
+    let total: f64 = weights.iter().sum();
+    weights.iter().zip(q).map(|(w, qn)| w * qn).sum::<f64>() / total
+}
+
+/// 重み付き最小二乗法による直線 y = a + b x の当てはめ（a, b と a の標準誤差）
+fn weighted_linear_fit(x: &[f64], y: &[f64], sigma: &[f64]) -> (f64, f64, f64) {
+    let w: Vec<f64> = sigma.iter().map(|s| 1.0 / (s * s)).collect();
+    let s: f64 = w.iter().sum();
+    let sx: f64 = w.iter().zip(x).map(|(w, x)| w * x).sum();
+    let sy: f64 = w.iter().zip(y).map(|(w, y)| w * y).sum();
+    let sxx: f64 = w.iter().zip(x).map(|(w, x)| w * x * x).sum();
+    let sxy: f64 = w.iter().zip(x).zip(y).map(|((w, x), y)| w * x * y).sum();
+    let delta = s * sxx - sx * sx;
+    let a = (sxx * sy - sx * sxy) / delta;
+    let b = (s * sxy - sx * sy) / delta;
+    (a, b, (sxx / delta).sqrt())
+}
+
+/// 乱数ストリームの区画の大きさ
+/// 節ごとに区画 k の先頭 k · STREAM_BLOCK からストリームを割り当て、試行どうしが同じ乱数列を使わないようにする
+const STREAM_BLOCK: u64 = 1 << 32;
+
+/// 有限サイズスケーリングによる臨界確率の推定
+/// 各 L で初めて貫通する占有率 p_span の平均を求め、<p_span>(L) = p_c + a L^{-1/ν} を外挿する
+/// L の番号 k、試行 t にはストリーム first_stream + k · n_trials + t を使う（重み付き当てはめは各点の独立性を仮定する）
+fn estimate_threshold(
+    d: usize,
+    kind: Kind,
+    sizes: &[usize],
+    n_trials: usize,
+    seed: u64,
+    first_stream: u64,
+    probes: &[f64],
+) -> (f64, f64) {
+    // 2次元と3次元の相関長の臨界指数
+    let nu = if d == 2 { 4.0 / 3.0 } else { 0.8765 };
+    println!(
+        "{:>5} {:>22} {}",
+        "L",
+        "<p_span> ± 誤差",
+        probes
+            .iter()
+            .map(|p| format!("{:>11}", format!("R({:.4})", p)))
+            .collect::<String>()
+    );
+    let (mut xs, mut ys, mut errs) = (Vec::new(), Vec::new(), Vec::new());
+    for (k, &l) in sizes.iter().enumerate() {
+        let lattice = Lattice::new(l, d);
+        let streams = first_stream + (k * n_trials) as u64;
+        let sweeps: Vec<Sweep> = (0..n_trials)
+            .into_par_iter()
+            .map(|t| newman_ziff(&lattice, kind, &mut stream_rng(seed, streams + t as u64)))
+            .collect();
+        let n_total = sweeps[0].n_total;
+        let p_span: Vec<f64> = sweeps
+            .iter()
+            .map(|s| s.n_span as f64 / n_total as f64)
+            .collect();
+        let mean = p_span.iter().sum::<f64>() / n_trials as f64;
+        let var = p_span.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n_trials - 1) as f64;
+        let err = (var / n_trials as f64).sqrt();
+
+        // 貫通確率 R_n = (n_span ≤ n となる試行の割合) を2項分布で p の関数に直す
+        let mut spanning = vec![0.0; n_total + 1];
+        for s in &sweeps {
+            spanning[s.n_span] += 1.0 / n_trials as f64;
+        }
+        for n in 1..=n_total {
+            spanning[n] += spanning[n - 1];
+        }
+        println!(
+            "{:>5} {:>12.5} ± {:>7.5} {}",
+            l,
+            mean,
+            err,
+            probes
+                .iter()
+                .map(|&p| format!("{:>11.4}", convolve(&spanning, p)))
+                .collect::<String>()
+        );
+        xs.push((l as f64).powf(-1.0 / nu));
+        ys.push(mean);
+        errs.push(err);
+    }
+    let (p_c, slope, p_c_err) = weighted_linear_fit(&xs, &ys, &errs);
+    println!(
+        "外挿 <p_span> = p_c + a L^(-1/ν) (ν = {:.4}): p_c = {:.5} ± {:.5}, a = {:.3}",
+        nu, p_c, p_c_err, slope
+    );
+    (p_c, p_c_err)
+}
+
+fn main() {
+    let seed = 42;
+    let p_c_square = 0.592_746;
+
+    // ----------------------------------------
+    // 1. Hoshen–Kopelman 法と Union-Find の比較
+    // ----------------------------------------
+    println!("=== 1. 正方格子のサイトパーコレーション: クラスター解析 ===");
+    let l = 1024;
+    let lattice = Lattice::new(l, 2);
+    let mut rng = stream_rng(seed, 0);
+    let occupied: Vec<bool> = (0..lattice.n_sites)
+        .map(|_| rng.random::<f64>() < p_c_square)
+        .collect();
+    let (labels, hk_sizes) = hoshen_kopelman(&lattice, &occupied);
+    let (uf_sizes, spanning) = union_find_clusters(&lattice, &occupied);
+    let mut sorted = hk_sizes.clone();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    println!(
+        "L = {}, p = {} ({} サイト占有)",
+        l,
+        p_c_square,
+        labels.iter().flatten().count()
+    );
+    println!(
+        "  Hoshen–Kopelman 法: クラスター数 {}, 最大 {}, 2番目 {}",
+        hk_sizes.len(),
+        sorted[0],
+        sorted[1]
+    );
+    println!(
+        "  Union-Find:         クラスター数 {}, 最大 {}, 2番目 {}",
+        uf_sizes.len(),
+        uf_sizes[0],
+        uf_sizes[1]
+    );
+    println!(
+        "  大きさの分布が一致: {}, x 方向に貫通: {}",
+        sorted == uf_sizes,
+        spanning
+    );
+
+    // クラスターの大きさの分布 n_s ∝ s^{-τ}（τ = 187/91）
+    // 16 個の独立な配置について、最大クラスターを除き、4 倍ずつの区間で数える
+    let n_realizations = 16;
+    let all_sizes: Vec<usize> = (0..n_realizations)
+        .into_par_iter()
+        .flat_map_iter(|t| {
+            let mut rng = stream_rng(seed, STREAM_BLOCK + t);
+            let occupied: Vec<bool> = (0..lattice.n_sites)
+                .map(|_| rng.random::<f64>() < p_c_square)
+                .collect();
+            let (_, mut sizes) = hoshen_kopelman(&lattice, &occupied);
+            sizes.sort_unstable_by(|a, b| b.cmp(a));
+            sizes.into_iter().skip(1)
+        })
+        .collect();
+    println!(
+        "\nクラスターの大きさの分布 n_s（サイトあたりの個数、{} 配置の平均、最大クラスターを除く）:",
+        n_realizations
+    );
+    println!("{:>16} {:>14}", "s の区間", "n_s");
+    let (mut log_s, mut log_n, mut log_err) = (Vec::new(), Vec::new(), Vec::new());
+    let mut lower = 1;
+    while lower * 4 <= 16_384 {
+        let upper = lower * 4;
+        let count = all_sizes
+            .iter()
+            .filter(|&&s| s >= lower && s < upper)
+            .count();
+        let n_s = count as f64
+            / ((upper - lower) as f64 * (lattice.n_sites * n_realizations as usize) as f64);
+        println!("{:>16} {:>14.4e}", format!("[{}, {})", lower, upper), n_s);
+        // 小さなクラスターは格子の影響（補正項）が大きいので当てはめから除く
+        // （それでも補正 s^{-Ω} の影響で τ は厳密値より少し小さく出る）
+        if lower >= 16 && count > 0 {
+            log_s.push(((lower * upper) as f64).sqrt().ln());
+            log_n.push(n_s.ln());
+            log_err.push(1.0 / (count as f64).sqrt());
+        }
+        lower = upper;
+    }
+    let (_, slope, _) = weighted_linear_fit(&log_s, &log_n, &log_err);
+    println!(
+        "  s ≥ 16 での傾き τ = {:.3}（厳密値 187/91 = {:.3}）",
+        -slope,
+        187.0 / 91.0
+    );
+
+    // ボンドパーコレーション（p_c = 1/2 の前後）
+    println!("\n正方格子のボンドパーコレーション (L = 256, 臨界確率 1/2):");
+    println!("{:>8} {:>16} {:>12}", "p", "最大クラスター/N", "貫通確率");
+    let lattice = Lattice::new(256, 2);
+    let n_trials = 200;
+    for (k, p) in [0.40, 0.45, 0.50, 0.55, 0.60].into_iter().enumerate() {
+        let streams = 2 * STREAM_BLOCK + k as u64 * n_trials;
+        let results: Vec<(usize, bool)> = (0..n_trials)
+            .into_par_iter()
+            .map(|t| bond_percolation(&lattice, p, &mut stream_rng(seed, streams + t)))
+            .collect();
+        let largest = results.iter().map(|r| r.0 as f64).sum::<f64>() / n_trials as f64;
+        let span = results.iter().filter(|r| r.1).count() as f64 / n_trials as f64;
+        println!(
+            "{:>8.2} {:>16.4} {:>12.3}",
+            p,
+            largest / lattice.n_sites as f64,
+            span
+        );
+    }
+
+    // ----------------------------------------
+    // 2. Newman–Ziff 法による臨界確率の推定
+    // ----------------------------------------
+    println!("\n=== 2. Newman–Ziff 法による臨界確率の推定 ===");
+    println!(
+        "\n正方格子のサイトパーコレーション（既知の値 p_c = {}）",
+        p_c_square
+    );
+    let (p_c, err) = estimate_threshold(
+        2,
+        Kind::Site,
+        &[32, 64, 128, 256],
+        4_000,
+        seed,
+        3 * STREAM_BLOCK,
+        &[0.58, 0.5927, 0.60],
+    );
+    println!(
+        "  既知の値とのずれ: {:+.5} ({:.1}σ)",
+        p_c - p_c_square,
+        (p_c - p_c_square) / err
+    );
+
+    println!("\n正方格子のボンドパーコレーション（厳密値 p_c = 1/2）");
+    let (p_c, err) = estimate_threshold(
+        2,
+        Kind::Bond,
+        &[32, 64, 128, 256],
+        2_000,
+        seed,
+        4 * STREAM_BLOCK,
+        &[0.49, 0.5, 0.51],
+    );
+    println!(
+        "  厳密値とのずれ: {:+.5} ({:.1}σ)",
+        p_c - 0.5,
+        (p_c - 0.5) / err
+    );
+
+    println!("\n単純立方格子のサイトパーコレーション（既知の値 p_c = 0.311608）");
+    let (p_c, err) = estimate_threshold(
+        3,
+        Kind::Site,
+        &[12, 16, 24, 32],
+        1_000,
+        seed,
+        5 * STREAM_BLOCK,
+        &[0.30, 0.3116, 0.32],
+    );
+    println!(
+        "  既知の値とのずれ: {:+.5} ({:.1}σ)",
+        p_c - 0.311_608,
+        (p_c - 0.311_608) / err
+    );
+
+    // 同じシードならスレッド数によらず同じ結果になることの確認
+    let lattice = Lattice::new(64, 2);
+    let run = |n_threads: usize| {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(n_threads)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            (0..100u64)
+                .into_par_iter()
+                .map(|t| {
+                    newman_ziff(
+                        &lattice,
+                        Kind::Site,
+                        &mut stream_rng(seed, 6 * STREAM_BLOCK + t),
+                    )
+                    .n_span
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+    println!("\n1 スレッドと 4 スレッドで同じ結果: {}", run(1) == run(4));
+}
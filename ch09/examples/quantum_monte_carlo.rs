@@ -0,0 +1,570 @@
+use ch09::metropolis;
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+
+/// 試行波動関数 ψ_p(r) をもつ量子系（原子単位 ħ = m = e = 1）
+/// r は全粒子の座標を並べたもの、p は変分パラメータ
+trait QuantumSystem {
+    fn dim(&self) -> usize;
+    fn potential(&self, r: &[f64]) -> f64;
+    fn ln_psi(&self, p: &[f64], r: &[f64]) -> f64;
+    /// ∇ ln ψ（拡散モンテカルロ法のドリフト速度）
+    fn grad_ln_psi(&self, p: &[f64], r: &[f64]) -> Vec<f64>;
+    /// 局所エネルギー E_L = (Hψ)/ψ
+    fn local_energy(&self, p: &[f64], r: &[f64]) -> f64;
+    /// パラメータについての対数微分 O_k = ∂ ln ψ / ∂p_k
+    fn param_log_derivatives(&self, p: &[f64], r: &[f64]) -> Vec<f64>;
+}
+
+/// 局所エネルギーを ln ψ の差分から数値的に求める（解析式の検算用）
+/// Hψ/ψ = -½ Σ (∂² ln ψ + (∂ ln ψ)²) + V
+fn numerical_local_energy<S: QuantumSystem>(system: &S, p: &[f64], r: &[f64]) -> f64 {
+    let h = 1e-4;
+    let f0 = system.ln_psi(p, r);
+    let mut kinetic = 0.0;
+    let mut x = r.to_vec();
+    for i in 0..r.len() {
+        x[i] = r[i] + h;
+        let fp = system.ln_psi(p, &x);
+        x[i] = r[i] - h;
+        let fm = system.ln_psi(p, &x);
+        x[i] = r[i];
+        let d1 = (fp - fm) / (2.0 * h);
+        let d2 = (fp - 2.0 * f0 + fm) / (h * h);
+        kinetic += -0.5 * (d2 + d1 * d1);
+    }
+    kinetic + system.potential(r)
+}
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+// ----------------------------------------
+// 1. 1次元調和振動子 V = x²/2、ψ = exp(-αx²)（厳密解 α = 1/2, E₀ = 1/2）
+// ----------------------------------------
+
+struct HarmonicOscillator;
+
+impl QuantumSystem for HarmonicOscillator {
+    fn dim(&self) -> usize {
+        1
+    }
+
+    fn potential(&self, r: &[f64]) -> f64 {
+        0.5 * r[0] * r[0]
+    }
+
+    fn ln_psi(&self, p: &[f64], r: &[f64]) -> f64 {
+        -p[0] * r[0] * r[0]
+    }
+
+    fn grad_ln_psi(&self, p: &[f64], r: &[f64]) -> Vec<f64> {
+        vec![-2.0 * p[0] * r[0]]
+    }
+
+    fn local_energy(&self, p: &[f64], r: &[f64]) -> f64 {
+        let alpha = p[0];
+        alpha + r[0] * r[0] * (0.5 - 2.0 * alpha * alpha)
+    }
+
+    fn param_log_derivatives(&self, _p: &[f64], r: &[f64]) -> Vec<f64> {
+        vec![-r[0] * r[0]]
+    }
+}
+
+// ----------------------------------------
+// 2. 水素原子 V = -1/r、ψ = exp(-αr)（厳密解 α = 1, E₀ = -1/2）
+// ----------------------------------------
+
+struct Hydrogen;
+
+impl QuantumSystem for Hydrogen {
+    fn dim(&self) -> usize {
+        3
+    }
+
+    fn potential(&self, r: &[f64]) -> f64 {
+        -1.0 / norm(r)
+    }
+
+    fn ln_psi(&self, p: &[f64], r: &[f64]) -> f64 {
+        -p[0] * norm(r)
+    }
+
+    fn grad_ln_psi(&self, p: &[f64], r: &[f64]) -> Vec<f64> {
+        let d = norm(r);
+        r.iter().map(|x| -p[0] * x / d).collect()
+    }
+
+    fn local_energy(&self, p: &[f64], r: &[f64]) -> f64 {
+        let alpha = p[0];
+        -0.5 * alpha * alpha + (alpha - 1.0) / norm(r)
+    }
+
+    fn param_log_derivatives(&self, _p: &[f64], r: &[f64]) -> Vec<f64> {
+        vec![-norm(r)]
+    }
+}
+
+// ----------------------------------------
+// 3. ヘリウム原子（Padé–Jastrow 因子つき）
+// ----------------------------------------
+
+/// ψ = exp(-α(r₁ + r₂)) exp(u(r₁₂))、u(r) = r / (2(1 + βr))
+/// u は電子間のカスプ条件 du/dr|₀ = 1/2 を満たす
+struct Helium;
+
+impl Helium {
+    /// r₁, r₂, r₁₂ と単位ベクトル r̂₁, r̂₂, r̂₁₂
+    #[allow(clippy::type_complexity)]
+    fn geometry(r: &[f64]) -> (f64, f64, f64, [f64; 3], [f64; 3], [f64; 3]) {
+        let r1 = norm(&r[0..3]);
+        let r2 = norm(&r[3..6]);
+        let d: Vec<f64> = (0..3).map(|k| r[k] - r[k + 3]).collect();
+        let r12 = norm(&d);
+        let unit = |v: &[f64], len: f64| [v[0] / len, v[1] / len, v[2] / len];
+        (
+            r1,
+            r2,
+            r12,
+            unit(&r[0..3], r1),
+            unit(&r[3..6], r2),
+            unit(&d, r12),
+        )
+    }
+}
+
+impl QuantumSystem for Helium {
+    fn dim(&self) -> usize {
+        6
+    }
+
+    fn potential(&self, r: &[f64]) -> f64 {
+        let (r1, r2, r12, ..) = Helium::geometry(r);
+        -2.0 / r1 - 2.0 / r2 + 1.0 / r12
+    }
+
+    fn ln_psi(&self, p: &[f64], r: &[f64]) -> f64 {
+        let (alpha, beta) = (p[0], p[1]);
+        let (r1, r2, r12, ..) = Helium::geometry(r);
+        -alpha * (r1 + r2) + r12 / (2.0 * (1.0 + beta * r12))
+    }
+
+    fn grad_ln_psi(&self, p: &[f64], r: &[f64]) -> Vec<f64> {
+        let (alpha, beta) = (p[0], p[1]);
+        let (_, _, r12, e1, e2, e12) = Helium::geometry(r);
+        let du = 0.5 / (1.0 + beta * r12).powi(2);
+        let mut g = vec![0.0; 6];
+        for k in 0..3 {
+            g[k] = -alpha * e1[k] + du * e12[k];
+            g[k + 3] = -alpha * e2[k] - du * e12[k];
+        }
+        g
+    }
+
+    fn local_energy(&self, p: &[f64], r: &[f64]) -> f64 {
+        let (alpha, beta) = (p[0], p[1]);
+        let (r1, r2, r12, e1, e2, e12) = Helium::geometry(r);
+        let s = 1.0 + beta * r12;
+        let du = 0.5 / (s * s);
+        let d2u = -beta / (s * s * s);
+        let dot: f64 = (0..3).map(|k| (e1[k] - e2[k]) * e12[k]).sum();
+        -alpha * alpha + (alpha - 2.0) * (1.0 / r1 + 1.0 / r2) + 1.0 / r12 + alpha * du * dot
+            - 2.0 * du / r12
+            - d2u
+            - du * du
+    }
+
+    fn param_log_derivatives(&self, p: &[f64], r: &[f64]) -> Vec<f64> {
+        let beta = p[1];
+        let (r1, r2, r12, ..) = Helium::geometry(r);
+        vec![-(r1 + r2), -0.5 * r12 * r12 / (1.0 + beta * r12).powi(2)]
+    }
+}
+
+// ----------------------------------------
+// 変分モンテカルロ法
+// ----------------------------------------
+
+/// 系列をブロックに分けたブロック平均による標準誤差（mcmc_diagnostics.rs の簡易版）
+fn block_error(series: &[f64], n_blocks: usize) -> f64 {
+    let block_len = series.len() / n_blocks;
+    let means: Vec<f64> = series
+        .chunks_exact(block_len)
+        .map(|b| b.iter().sum::<f64>() / block_len as f64)
+        .collect();
+    let mean = means.iter().sum::<f64>() / means.len() as f64;
+    let var = means.iter().map(|m| (m - mean).powi(2)).sum::<f64>() / (means.len() - 1) as f64;
+    (var / means.len() as f64).sqrt()
+}
+
+struct VmcResult {
+    energy: f64,
+    error: f64,
+    /// 局所エネルギーの分散（厳密な固有関数なら 0）
+    variance: f64,
+    acceptance: f64,
+    /// エネルギーの勾配 ∂E/∂p_k = 2(<E_L O_k> - <E_L><O_k>)
+    gradient: Vec<f64>,
+}
+
+/// |ψ_p|² を mcmc.rs と同じ Metropolis 法（全座標を [-δ, δ] で一様に動かす）でサンプリングし、
+/// 局所エネルギーを平均する
+fn vmc<S: QuantumSystem>(
+    system: &S,
+    p: &[f64],
+    n_steps: usize,
+    delta: f64,
+    rng: &mut ChaCha8Rng,
+) -> VmcResult {
+    let dim = system.dim();
+    let burn_in = n_steps / 10;
+    let mut r: Vec<f64> = (0..dim).map(|_| rng.random_range(-0.5..0.5)).collect();
+    let mut ln_psi = system.ln_psi(p, &r);
+    let mut accepted = 0;
+    let mut energies = Vec::with_capacity(n_steps);
+    let mut sum_o = vec![0.0; p.len()];
+    let mut sum_eo = vec![0.0; p.len()];
+
+    for step in 0..burn_in + n_steps {
+        let r_new: Vec<f64> = r
+            .iter()
+            .map(|x| x + rng.random_range(-delta..delta))
+            .collect();
+        let ln_psi_new = system.ln_psi(p, &r_new);
+        // 受理確率 A = min(1, |ψ(r')|² / |ψ(r)|²)
+        if metropolis::accept((2.0 * (ln_psi_new - ln_psi)).exp(), rng) {
+            r = r_new;
+            ln_psi = ln_psi_new;
+            if step >= burn_in {
+                accepted += 1;
+            }
+        }
+        if step >= burn_in {
+            let e = system.local_energy(p, &r);
+            energies.push(e);
+            for (k, o) in system.param_log_derivatives(p, &r).into_iter().enumerate() {
+                sum_o[k] += o;
+                sum_eo[k] += e * o;
+            }
+        }
+    }
+
+    let n = n_steps as f64;
+    let energy = energies.iter().sum::<f64>() / n;
+    let variance = energies.iter().map(|e| (e - energy).powi(2)).sum::<f64>() / n;
+    VmcResult {
+        energy,
+        error: block_error(&energies, 50),
+        variance,
+        acceptance: accepted as f64 / n,
+        gradient: sum_o
+            .iter()
+            .zip(&sum_eo)
+            .map(|(o, eo)| 2.0 * (eo / n - energy * o / n))
+            .collect(),
+    }
+}
+
+/// 確率的勾配降下法でエネルギーを最小化する
+/// 後半の反復のパラメータの平均を最終値とする（勾配の雑音を均す）
+fn minimize_energy<S: QuantumSystem>(
+    system: &S,
+    initial: &[f64],
+    learning_rate: f64,
+    n_iterations: usize,
+    n_steps: usize,
+    delta: f64,
+    rng: &mut ChaCha8Rng,
+) -> Vec<f64> {
+    let mut p = initial.to_vec();
+    let mut average = vec![0.0; p.len()];
+    let mut n_average = 0;
+    for iteration in 0..n_iterations {
+        let result = vmc(system, &p, n_steps, delta, rng);
+        for (pk, gk) in p.iter_mut().zip(&result.gradient) {
+            *pk -= learning_rate * gk;
+        }
+        if iteration >= n_iterations / 2 {
+            for (a, pk) in average.iter_mut().zip(&p) {
+                *a += pk;
+            }
+            n_average += 1;
+        }
+    }
+    average.iter().map(|a| a / n_average as f64).collect()
+}
+
+// ----------------------------------------
+// 拡散モンテカルロ法（重み付きサンプリング、分岐あり）
+// ----------------------------------------
+
+struct DmcResult {
+    energy: f64,
+    error: f64,
+    mean_walkers: f64,
+    acceptance: f64,
+}
+
+/// 虚時間の Schrödinger 方程式を歩行者の拡散・ドリフト・分岐で解く
+/// f = ψ_T φ の分布に従って歩行者を動かす（ドリフト速度 ∇ ln ψ_T）
+/// 1. r' = r + τ∇ln ψ_T(r) + √τ ξ を提案し、詳細つり合いを回復する Metropolis 判定をする
+/// 2. 重み w = exp(-τ(½(E_L(r) + E_L(r')) - E_T)) に従って歩行者を複製・消滅させる
+/// 3. 試行エネルギー E_T を歩行者数が目標値 N₀ に保たれるように調整する
+fn dmc<S: QuantumSystem>(
+    system: &S,
+    p: &[f64],
+    tau: f64,
+    n_target: usize,
+    n_steps: usize,
+    rng: &mut ChaCha8Rng,
+) -> DmcResult {
+    let dim = system.dim();
+    // 初期配置は |ψ_T|² からの VMC サンプル
+    let mut walkers: Vec<Vec<f64>> = Vec::with_capacity(n_target);
+    let mut r: Vec<f64> = (0..dim).map(|_| rng.random_range(-0.5..0.5)).collect();
+    let mut ln_psi = system.ln_psi(p, &r);
+    for step in 0..n_target * 20 {
+        let r_new: Vec<f64> = r.iter().map(|x| x + rng.random_range(-0.5..0.5)).collect();
+        let ln_psi_new = system.ln_psi(p, &r_new);
+        if metropolis::accept((2.0 * (ln_psi_new - ln_psi)).exp(), rng) {
+            r = r_new;
+            ln_psi = ln_psi_new;
+        }
+        if step % 20 == 19 {
+            walkers.push(r.clone());
+        }
+    }
+
+    let burn_in = n_steps / 5;
+    let mut e_trial = walkers
+        .iter()
+        .map(|w| system.local_energy(p, w))
+        .sum::<f64>()
+        / walkers.len() as f64;
+    let mut e_estimate = e_trial;
+    let mut energies = Vec::with_capacity(n_steps);
+    let (mut n_walkers_sum, mut accepted, mut attempted) = (0.0, 0usize, 0usize);
+
+    for step in 0..burn_in + n_steps {
+        let mut next = Vec::with_capacity(walkers.len() * 2);
+        let mut last = None;
+        let (mut weighted_energy, mut weight_sum) = (0.0, 0.0);
+        for r in walkers {
+            let drift = system.grad_ln_psi(p, &r);
+            let r_new: Vec<f64> = r
+                .iter()
+                .zip(&drift)
+                .map(|(x, f)| {
+                    let xi: f64 = StandardNormal.sample(rng);
+                    x + tau * f + tau.sqrt() * xi
+                })
+                .collect();
+            // グリーン関数の比 G(r←r')/G(r'←r) を含む受理確率
+            let drift_new = system.grad_ln_psi(p, &r_new);
+            let log_g_forward: f64 = (0..dim)
+                .map(|k| -(r_new[k] - r[k] - tau * drift[k]).powi(2))
+                .sum::<f64>()
+                / (2.0 * tau);
+            let log_g_backward: f64 = (0..dim)
+                .map(|k| -(r[k] - r_new[k] - tau * drift_new[k]).powi(2))
+                .sum::<f64>()
+                / (2.0 * tau);
+            let log_ratio = 2.0 * (system.ln_psi(p, &r_new) - system.ln_psi(p, &r))
+                + log_g_backward
+                - log_g_forward;
+            let e_old = system.local_energy(p, &r);
+            attempted += 1;
+            let (r, e_new) = if metropolis::accept(log_ratio.exp(), rng) {
+                accepted += 1;
+                let e = system.local_energy(p, &r_new);
+                (r_new, e)
+            } else {
+                (r, e_old)
+            };
+            let weight = (-tau * (0.5 * (e_old + e_new) - e_trial)).exp();
+            weighted_energy += weight * e_new;
+            weight_sum += weight;
+            // 期待値が w になるように floor(w + U) 個に複製する
+            let copies = (weight + rng.random::<f64>()) as usize;
+            for _ in 0..copies {
+                next.push(r.clone());
+            }
+            last = Some(r);
+        }
+        // 歩行者が全滅したら、最後の歩行者の位置から目標数だけ配置し直す
+        // （そのままでは重みの和が 0、ln(0) で E_T が発散する）
+        if next.is_empty() {
+            eprintln!("警告: ステップ {} で歩行者が全滅したので再配置します", step);
+            next = vec![last.expect("歩行者がいません"); n_target];
+        }
+        walkers = next;
+
+        // 混合推定量 <E_L>_f
+        let e_step = weighted_energy / weight_sum;
+        if step >= burn_in {
+            energies.push(e_step);
+            n_walkers_sum += walkers.len() as f64;
+            e_estimate = energies.iter().sum::<f64>() / energies.len() as f64;
+        } else {
+            e_estimate = 0.9 * e_estimate + 0.1 * e_step;
+        }
+        e_trial = e_estimate - 0.1 / tau * (walkers.len() as f64 / n_target as f64).ln();
+    }
+
+    DmcResult {
+        energy: e_estimate,
+        error: block_error(&energies, 20),
+        mean_walkers: n_walkers_sum / n_steps as f64,
+        acceptance: accepted as f64 / attempted as f64,
+    }
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2024);
+
+    // 局所エネルギーの解析式を数値微分で検算する
+    println!("=== 局所エネルギーの解析式の検算（数値微分との差）===");
+    let point: Vec<f64> = (0..6).map(|_| rng.random_range(-1.0..1.0)).collect();
+    println!(
+        "  調和振動子: {:.2e}",
+        (HarmonicOscillator.local_energy(&[0.4], &point[..1])
+            - numerical_local_energy(&HarmonicOscillator, &[0.4], &point[..1]))
+        .abs()
+    );
+    println!(
+        "  水素原子:   {:.2e}",
+        (Hydrogen.local_energy(&[0.8], &point[..3])
+            - numerical_local_energy(&Hydrogen, &[0.8], &point[..3]))
+        .abs()
+    );
+    println!(
+        "  ヘリウム:   {:.2e}",
+        (Helium.local_energy(&[1.8, 0.3], &point)
+            - numerical_local_energy(&Helium, &[1.8, 0.3], &point))
+        .abs()
+    );
+
+    // ----------------------------------------
+    // 変分原理: パラメータを動かしたときのエネルギーと分散
+    // ----------------------------------------
+    println!("\n=== 変分モンテカルロ法: パラメータ走査 ===");
+    println!("調和振動子（厳密値 E(α) = α/2 + 1/(8α)）");
+    println!(
+        "{:>6} {:>22} {:>10} {:>12} {:>8}",
+        "α", "E_VMC", "厳密値", "σ²(E_L)", "受理率"
+    );
+    for alpha in [0.3, 0.4, 0.5, 0.6, 0.7] {
+        let res = vmc(&HarmonicOscillator, &[alpha], 200_000, 1.5, &mut rng);
+        println!(
+            "{:>6.2} {:>12.5} ± {:>7.5} {:>10.5} {:>12.2e} {:>8.3}",
+            alpha,
+            res.energy,
+            res.error,
+            alpha / 2.0 + 1.0 / (8.0 * alpha),
+            res.variance,
+            res.acceptance
+        );
+    }
+    println!("\n水素原子（厳密値 E(α) = α²/2 - α）");
+    println!(
+        "{:>6} {:>22} {:>10} {:>12} {:>8}",
+        "α", "E_VMC", "厳密値", "σ²(E_L)", "受理率"
+    );
+    for alpha in [0.8, 0.9, 1.0, 1.1, 1.2] {
+        let res = vmc(&Hydrogen, &[alpha], 200_000, 1.0, &mut rng);
+        println!(
+            "{:>6.2} {:>12.5} ± {:>7.5} {:>10.5} {:>12.2e} {:>8.3}",
+            alpha,
+            res.energy,
+            res.error,
+            0.5 * alpha * alpha - alpha,
+            res.variance,
+            res.acceptance
+        );
+    }
+
+    // ----------------------------------------
+    // 変分パラメータの最適化
+    // ----------------------------------------
+    println!("\n=== 変分モンテカルロ法: 確率的勾配降下法によるエネルギー最小化 ===");
+    let p_ho = minimize_energy(&HarmonicOscillator, &[0.2], 0.2, 100, 5_000, 1.5, &mut rng);
+    let p_h = minimize_energy(&Hydrogen, &[0.5], 0.5, 100, 5_000, 1.0, &mut rng);
+    let p_he = minimize_energy(&Helium, &[1.5, 1.0], 0.5, 200, 10_000, 0.5, &mut rng);
+    println!(
+        "{:<12} {:>22} {:>24} {:>12}",
+        "系", "最適パラメータ", "E_VMC", "厳密値"
+    );
+    let res = vmc(&HarmonicOscillator, &p_ho, 500_000, 1.5, &mut rng);
+    println!(
+        "{:<12} {:>22} {:>13.5} ± {:>8.5} {:>12.5}",
+        "調和振動子",
+        format!("α = {:.4}", p_ho[0]),
+        res.energy,
+        res.error,
+        0.5
+    );
+    let res = vmc(&Hydrogen, &p_h, 500_000, 1.0, &mut rng);
+    println!(
+        "{:<12} {:>22} {:>13.5} ± {:>8.5} {:>12.5}",
+        "水素原子",
+        format!("α = {:.4}", p_h[0]),
+        res.energy,
+        res.error,
+        -0.5
+    );
+    let e_he_exact = -2.903_724;
+    let res_he = vmc(&Helium, &p_he, 1_000_000, 0.5, &mut rng);
+    println!(
+        "{:<12} {:>22} {:>13.5} ± {:>8.5} {:>12.5}",
+        "ヘリウム",
+        format!("α = {:.4}, β = {:.4}", p_he[0], p_he[1]),
+        res_he.energy,
+        res_he.error,
+        e_he_exact
+    );
+    // Jastrow 因子なし（β → ∞ で u は定数）: 有効核電荷 α = 27/16 で E = -(27/16)² の解析解
+    let res = vmc(&Helium, &[27.0 / 16.0, 1e8], 1_000_000, 0.5, &mut rng);
+    println!(
+        "{:<12} {:>22} {:>13.5} ± {:>8.5} {:>12.5}  (解析解)",
+        "He (Jastrow なし)",
+        "α = 27/16",
+        res.energy,
+        res.error,
+        -(27.0_f64 / 16.0).powi(2)
+    );
+    println!(
+        "  ヘリウムの相関エネルギーのうち Jastrow 因子で取り込めた割合: {:.1}%",
+        100.0 * (res.energy - res_he.energy) / (res.energy - e_he_exact)
+    );
+
+    // ----------------------------------------
+    // 拡散モンテカルロ法
+    // ----------------------------------------
+    println!("\n=== 拡散モンテカルロ法（試行関数の誤差によらず厳密な基底エネルギーへ）===");
+    println!(
+        "{:<12} {:>18} {:>8} {:>12} {:>22} {:>12} {:>8} {:>8}",
+        "系", "試行関数", "τ", "E_VMC", "E_DMC", "厳密値", "歩行者", "受理率"
+    );
+    let tau = 0.01;
+    let p = [0.4];
+    let e_vmc = vmc(&HarmonicOscillator, &p, 100_000, 1.5, &mut rng).energy;
+    let res = dmc(&HarmonicOscillator, &p, tau, 500, 4_000, &mut rng);
+    print_dmc("調和振動子", "α = 0.4", tau, e_vmc, &res, 0.5);
+    let p = [0.8];
+    let e_vmc = vmc(&Hydrogen, &p, 100_000, 1.0, &mut rng).energy;
+    let res = dmc(&Hydrogen, &p, tau, 500, 4_000, &mut rng);
+    print_dmc("水素原子", "α = 0.8", tau, e_vmc, &res, -0.5);
+    let res = dmc(&Helium, &p_he, tau, 1_000, 6_000, &mut rng);
+    let label = format!("α = {:.2}, β = {:.2}", p_he[0], p_he[1]);
+    print_dmc("ヘリウム", &label, tau, res_he.energy, &res, e_he_exact);
+}
+
+fn print_dmc(name: &str, label: &str, tau: f64, e_vmc: f64, res: &DmcResult, exact: f64) {
+    println!(
+        "{:<12} {:>18} {:>8.3} {:>12.5} {:>12.5} ± {:>7.5} {:>12.5} {:>8.0} {:>8.4}",
+        name, label, tau, e_vmc, res.energy, res.error, exact, res.mean_walkers, res.acceptance
+    );
+}
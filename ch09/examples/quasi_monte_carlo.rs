@@ -0,0 +1,168 @@
+use ch09::qmc::{Halton, Sobol, UniformCube, integrate, replicate, rqmc_integrate};
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::f64::consts::PI;
+
+/// 最小二乗法による log-log の傾き（収束次数）
+fn fit_slope(ns: &[usize], errors: &[f64]) -> f64 {
+    let xs: Vec<f64> = ns.iter().map(|&n| (n as f64).ln()).collect();
+    let ys: Vec<f64> = errors.iter().map(|e| e.ln()).collect();
+    let k = xs.len() as f64;
+    let (mx, my) = (xs.iter().sum::<f64>() / k, ys.iter().sum::<f64>() / k);
+    let sxy: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+    let sxx: f64 = xs.iter().map(|x| (x - mx).powi(2)).sum();
+    sxy / sxx
+}
+
+/// MC と RQMC の収束の比較表
+/// 誤差は独立な乱択化 n_rep 回の RMS 誤差
+fn convergence_table<F: Fn(&[f64]) -> f64>(name: &str, f: &F, dim: usize, exact: f64) {
+    let n_rep = 30;
+    let ns: Vec<usize> = (6..=16).step_by(2).map(|k| 1 << k).collect();
+    println!("\n--- {} (d = {}, 厳密値 {:.6}) ---", name, dim, exact);
+    println!(
+        "{:>8} {:>14} {:>14} {:>14} {:>14}",
+        "N", "MC", "Halton+シフト", "Sobol", "Sobol+スクランブル"
+    );
+
+    let mut errors = [vec![], vec![], vec![], vec![]];
+    for &n in &ns {
+        let rms = |estimates: Vec<f64>| {
+            (estimates.iter().map(|e| (e - exact).powi(2)).sum::<f64>() / estimates.len() as f64)
+                .sqrt()
+        };
+        let mc = rms(replicate(f, n, n_rep, 1000, |_| UniformCube { dim }));
+        let halton = rms(replicate(f, n, n_rep, 2000, |rng| {
+            Halton::randomized(dim, rng)
+        }));
+        // 乱択化していない Sobol 列は決定的なので誤差は1つだけ
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let sobol = (integrate(f, &Sobol::new(dim), &mut rng, n) - exact).abs();
+        let scrambled = rms(replicate(f, n, n_rep, 3000, |rng| {
+            Sobol::scrambled(dim, rng)
+        }));
+        println!(
+            "{:>8} {:>14.3e} {:>14.3e} {:>14.3e} {:>14.3e}",
+            n, mc, halton, sobol, scrambled
+        );
+        for (e, v) in errors.iter_mut().zip([mc, halton, sobol, scrambled]) {
+            e.push(v);
+        }
+    }
+    println!(
+        "{:>8} {:>14.2} {:>14.2} {:>14.2} {:>14.2}",
+        "次数",
+        fit_slope(&ns, &errors[0]),
+        fit_slope(&ns, &errors[1]),
+        fit_slope(&ns, &errors[2]),
+        fit_slope(&ns, &errors[3])
+    );
+}
+
+fn main() {
+    // ----------------------------------------
+    // 1. 点列の例
+    // ----------------------------------------
+    println!("=== 1. 2次元の点列（最初の8点） ===");
+    // 低食い違い量列では rng は使われないが、rand の分布と同じく rng.sample(&dist) で点をとれる
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let halton = Halton::new(2);
+    let sobol = Sobol::new(2);
+    println!("{:>4} {:>20} {:>20}", "n", "Halton (2, 3)", "Sobol");
+    for n in 0..8 {
+        let x = rng.sample(&halton);
+        let y = rng.sample(&sobol);
+        println!(
+            "{:>4} ({:>7.4}, {:>7.4})   ({:>7.4}, {:>7.4})",
+            n, x[0], x[1], y[0], y[1]
+        );
+    }
+
+    // ----------------------------------------
+    // 2. 円周率の推定（integration.rs と同じ問題）
+    // ----------------------------------------
+    println!("\n=== 2. 円周率の推定 (N = 2^20) ===");
+    let n = 1 << 20;
+    let quarter_circle = |x: &[f64]| {
+        if x[0] * x[0] + x[1] * x[1] <= 1.0 {
+            4.0
+        } else {
+            0.0
+        }
+    };
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let estimates = [
+        (
+            "擬似乱数",
+            integrate(&quarter_circle, &UniformCube { dim: 2 }, &mut rng, n),
+        ),
+        (
+            "Halton",
+            integrate(&quarter_circle, &Halton::new(2), &mut rng, n),
+        ),
+        (
+            "Sobol",
+            integrate(&quarter_circle, &Sobol::new(2), &mut rng, n),
+        ),
+    ];
+    for (name, pi_est) in estimates {
+        println!(
+            "{:<10} π ≈ {:.8}  (誤差 {:.2e})",
+            name,
+            pi_est,
+            (pi_est - PI).abs()
+        );
+    }
+
+    // ----------------------------------------
+    // 3. 乱択化 QMC による誤差評価
+    // ----------------------------------------
+    println!("\n=== 3. 乱択化 QMC の誤差評価 (d = 6, N = 4096, 乱択化 20 回) ===");
+    let dim = 6;
+    let smooth = |x: &[f64]| {
+        x.iter()
+            .map(|&xi| 0.5 * PI * (PI * xi).sin())
+            .product::<f64>()
+    };
+    let (mean, se) = rqmc_integrate(&smooth, 4096, 20, 0, |rng| Sobol::scrambled(dim, rng));
+    println!(
+        "スクランブル Sobol: {:.8} ± {:.2e} (真の誤差 {:.2e})",
+        mean,
+        se,
+        (mean - 1.0).abs()
+    );
+    let (mean, se) = rqmc_integrate(&smooth, 4096, 20, 0, |rng| Halton::randomized(dim, rng));
+    println!(
+        "シフト Halton:      {:.8} ± {:.2e} (真の誤差 {:.2e})",
+        mean,
+        se,
+        (mean - 1.0).abs()
+    );
+    let (mean, se) = rqmc_integrate(&smooth, 4096, 20, 0, |_| UniformCube { dim });
+    println!(
+        "擬似乱数 (MC):      {:.8} ± {:.2e} (真の誤差 {:.2e})",
+        mean,
+        se,
+        (mean - 1.0).abs()
+    );
+
+    // ----------------------------------------
+    // 4. 収束の比較: MC は O(N^-1/2)、QMC はおよそ O(N^-1)
+    // ----------------------------------------
+    println!("\n=== 4. 収束の比較（RMS 誤差） ===");
+    convergence_table("滑らかな積 Π (π/2) sin(πx_i)", &smooth, 4, 1.0);
+    // Sobol の g 関数: 各因子の積分は 1
+    let g_function = |x: &[f64]| {
+        x.iter()
+            .enumerate()
+            .map(|(i, &xi)| {
+                let a = i as f64;
+                ((4.0 * xi - 2.0).abs() + a) / (1.0 + a)
+            })
+            .product::<f64>()
+    };
+    convergence_table("Sobol の g 関数", &g_function, 8, 1.0);
+    convergence_table("円の指示関数（不連続）", &quarter_circle, 2, PI);
+    println!("\n(次数は log(誤差) の log N に対する傾き。MC ≈ -0.5、滑らかな関数の QMC ≈ -1、");
+    println!(" 不連続な関数では QMC の利点が小さくなる)");
+}
@@ -0,0 +1,748 @@
+use ch09::special::{erf, gamma_p, ln_gamma};
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::Distribution;
+use std::f64::consts::PI;
+use std::time::Instant;
+
+// ----------------------------------------
+// 1. 逆関数法（累積分布関数の方程式 F(x) = u を数値的に解く）
+// ----------------------------------------
+
+/// Brent 法で区間 [a, b] 内の f(x) = 0 の解を求める（f(a) と f(b) は異符号）
+fn brent<F: Fn(f64) -> f64>(f: F, mut a: f64, mut b: f64, tol: f64) -> f64 {
+    let mut fa = f(a);
+    let mut fb = f(b);
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = b - a;
+    let mut e = d;
+    for _ in 0..200 {
+        if fb * fc > 0.0 {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let m = 0.5 * (c - b);
+        if m.abs() <= tol1 || fb == 0.0 {
+            return b;
+        }
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // 逆2次補間（または割線法）
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * m * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            if 2.0 * p < (3.0 * m * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = d;
+            }
+        } else {
+            // 二分法
+            d = m;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { tol1.copysign(m) };
+        fb = f(b);
+    }
+    b
+}
+
+/// 累積分布関数 F だけが分かっている分布の逆関数法
+/// u ~ U(0, 1) に対して F(x) = u を Brent 法で解く
+struct InverseCdf<'a> {
+    cdf: &'a dyn Fn(f64) -> f64,
+    lo: f64,
+    hi: f64,
+}
+
+impl Distribution<f64> for InverseCdf<'_> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.random();
+        brent(|x| (self.cdf)(x) - u, self.lo, self.hi, 1e-12)
+    }
+}
+
+// ----------------------------------------
+// 2. 適応的棄却法（Gilks–Wild、対数凹な密度）
+// ----------------------------------------
+
+/// log f が凹関数のとき、接線の上側包絡線と弦の下側包絡線（スクイーズ）で挟んで棄却法を行う
+/// 棄却のたびにその点を接点に加えるので、包絡線はしだいに f に近づく
+struct AdaptiveRejection<'a> {
+    /// h(x) = ln f(x)（規格化されていなくてよい）
+    h: &'a dyn Fn(f64) -> f64,
+    dh: &'a dyn Fn(f64) -> f64,
+    lo: f64,
+    hi: f64,
+    /// 接点（昇順）と h(x), h'(x)
+    points: Vec<(f64, f64, f64)>,
+    max_points: usize,
+    /// 接線の交点に両端を加えた区間の境界と、区間ごとの上側包絡線の面積の累積和
+    z: Vec<f64>,
+    cumulative: Vec<f64>,
+    /// h の評価回数（初期化を除く）
+    n_evals: usize,
+}
+
+impl<'a> AdaptiveRejection<'a> {
+    /// 初期接点は密度の最頻値をはさむように選ぶ（hi = ∞ なら最後の傾きが負であること）
+    fn new(
+        h: &'a dyn Fn(f64) -> f64,
+        dh: &'a dyn Fn(f64) -> f64,
+        lo: f64,
+        hi: f64,
+        initial: &[f64],
+    ) -> Self {
+        let mut points: Vec<(f64, f64, f64)> = initial.iter().map(|&x| (x, h(x), dh(x))).collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut ars = Self {
+            h,
+            dh,
+            lo,
+            hi,
+            points,
+            max_points: 50,
+            z: Vec::new(),
+            cumulative: Vec::new(),
+            n_evals: 0,
+        };
+        ars.update_envelope();
+        ars
+    }
+
+    /// 接点が変わるたびに区間の境界と面積を計算し直す
+    fn update_envelope(&mut self) {
+        let mut z = vec![self.lo];
+        for w in self.points.windows(2) {
+            let ((x0, h0, d0), (x1, h1, d1)) = (w[0], w[1]);
+            if (d0 - d1).abs() < 1e-12 {
+                z.push(0.5 * (x0 + x1));
+            } else {
+                z.push((h1 - h0 - x1 * d1 + x0 * d0) / (d0 - d1));
+            }
+        }
+        z.push(self.hi);
+        let h_max = self
+            .points
+            .iter()
+            .map(|p| p.1)
+            .fold(f64::NEG_INFINITY, f64::max);
+        // 各区間 [z_j, z_{j+1}] での exp(u_j(x) - h_max) の積分
+        let mut total = 0.0;
+        self.cumulative = self
+            .points
+            .iter()
+            .enumerate()
+            .map(|(j, &(x, hx, d))| {
+                let (a, b) = (z[j], z[j + 1]);
+                let ua = hx + d * (a - x) - h_max;
+                total += if d.abs() < 1e-12 {
+                    ua.exp() * (b - a)
+                } else if b.is_infinite() {
+                    -ua.exp() / d
+                } else {
+                    ua.exp() * (d * (b - a)).exp_m1() / d
+                };
+                total
+            })
+            .collect();
+        self.z = z;
+    }
+
+    fn sample<R: Rng + ?Sized>(&mut self, rng: &mut R) -> f64 {
+        loop {
+            // 面積に比例して区間を選び、その区間内で指数分布に従う点を引く
+            let total = *self.cumulative.last().unwrap();
+            let r = rng.random::<f64>() * total;
+            let j = self
+                .cumulative
+                .partition_point(|&c| c < r)
+                .min(self.points.len() - 1);
+            let (xj, hj, dj) = self.points[j];
+            let (a, b) = (self.z[j], self.z[j + 1]);
+            let w: f64 = rng.random();
+            let x = if dj.abs() < 1e-12 {
+                a + w * (b - a)
+            } else if dj > 0.0 {
+                b + (w + (1.0 - w) * (-dj * (b - a)).exp()).ln() / dj
+            } else if b.is_infinite() {
+                a + (1.0 - w).ln() / dj
+            } else {
+                a + (w * (dj * (b - a)).exp_m1()).ln_1p() / dj
+            };
+            let upper = hj + dj * (x - xj);
+
+            // スクイーズ検定（h を評価せずに受理できる）
+            let v: f64 = rng.random();
+            let k = self.points.partition_point(|p| p.0 <= x);
+            if k > 0 && k < self.points.len() {
+                let (x0, h0, _) = self.points[k - 1];
+                let (x1, h1, _) = self.points[k];
+                let lower = h0 + (h1 - h0) * (x - x0) / (x1 - x0);
+                if v.ln() <= lower - upper {
+                    return x;
+                }
+            }
+
+            // h を評価して棄却判定し、その点を接点に加える
+            let hx = (self.h)(x);
+            self.n_evals += 1;
+            if self.points.len() < self.max_points {
+                self.points.insert(k, (x, hx, (self.dh)(x)));
+                self.update_envelope();
+            }
+            if v.ln() <= hx - upper {
+                return x;
+            }
+        }
+    }
+}
+
+// ----------------------------------------
+// 3. 比一様法（ratio-of-uniforms）
+// ----------------------------------------
+
+/// (u, v) を {0 < u ≤ √f(v/u)} から一様に選ぶと x = v/u は密度 f に従う
+/// 外接する長方形 [0, sup √f] × [inf x√f, sup x√f] から棄却法で選ぶ
+struct RatioOfUniforms<'a> {
+    f: &'a dyn Fn(f64) -> f64,
+    lo: f64,
+    hi: f64,
+    u_max: f64,
+    v_min: f64,
+    v_max: f64,
+}
+
+impl<'a> RatioOfUniforms<'a> {
+    /// 外接長方形の辺は [lo, hi] 上の細かい格子での最大・最小値に余裕をもたせて決める
+    fn new(f: &'a dyn Fn(f64) -> f64, lo: f64, hi: f64) -> Self {
+        let n_grid = 100_000;
+        let (mut u_max, mut v_min, mut v_max) = (0.0_f64, 0.0_f64, 0.0_f64);
+        for i in 0..=n_grid {
+            let x = lo + (hi - lo) * i as f64 / n_grid as f64;
+            let s = f(x).sqrt();
+            u_max = u_max.max(s);
+            v_min = v_min.min(x * s);
+            v_max = v_max.max(x * s);
+        }
+        let margin = 1.0 + 1e-3;
+        Self {
+            f,
+            lo,
+            hi,
+            u_max: u_max * margin,
+            v_min: v_min * margin,
+            v_max: v_max * margin,
+        }
+    }
+
+    /// 受理率 = (∫f dx / 2) / (長方形の面積)
+    fn acceptance_rate(&self, normalization: f64) -> f64 {
+        0.5 * normalization / (self.u_max * (self.v_max - self.v_min))
+    }
+}
+
+impl Distribution<f64> for RatioOfUniforms<'_> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        loop {
+            let u = self.u_max * (1.0 - rng.random::<f64>());
+            let v = self.v_min + (self.v_max - self.v_min) * rng.random::<f64>();
+            let x = v / u;
+            if x >= self.lo && x <= self.hi && u * u <= (self.f)(x) {
+                return x;
+            }
+        }
+    }
+}
+
+// ----------------------------------------
+// 4. Walker のエイリアス法
+// ----------------------------------------
+
+/// 離散分布を O(1) で引くための表（Vose の構成法）
+struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        let total: f64 = weights.iter().sum();
+        let mut prob: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut alias = vec![0; n];
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| prob[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            alias[s] = l;
+            prob[l] -= 1.0 - prob[s];
+            if prob[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // 丸め誤差で残ったものは確率 1
+        for i in small.into_iter().chain(large) {
+            prob[i] = 1.0;
+        }
+        Self { prob, alias }
+    }
+}
+
+impl Distribution<usize> for AliasTable {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let i = rng.random_range(0..self.prob.len());
+        if rng.random::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// 表で与えられた密度 (x_i, p_i) を区分線形に補間した連続分布
+/// 区間をエイリアス法で選び、区間内は1次関数の密度を逆関数法で引く
+struct TabulatedPdf {
+    x: Vec<f64>,
+    p: Vec<f64>,
+    /// 区間ごとの確率と累積確率
+    cumulative: Vec<f64>,
+    table: AliasTable,
+}
+
+impl TabulatedPdf {
+    fn new(x: Vec<f64>, p: Vec<f64>) -> Self {
+        let areas: Vec<f64> = (0..x.len() - 1)
+            .map(|i| 0.5 * (p[i] + p[i + 1]) * (x[i + 1] - x[i]))
+            .collect();
+        let total: f64 = areas.iter().sum();
+        let mut cumulative = vec![0.0];
+        for a in &areas {
+            cumulative.push(cumulative.last().unwrap() + a / total);
+        }
+        let p = p.iter().map(|pi| pi / total).collect();
+        Self {
+            x,
+            p,
+            cumulative,
+            table: AliasTable::new(&areas),
+        }
+    }
+
+    /// 厳密な累積分布関数（区間内は2次関数）
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.x[0] {
+            return 0.0;
+        }
+        if x >= *self.x.last().unwrap() {
+            return 1.0;
+        }
+        let i = self.x.partition_point(|&xi| xi <= x) - 1;
+        let t = x - self.x[i];
+        let slope = (self.p[i + 1] - self.p[i]) / (self.x[i + 1] - self.x[i]);
+        self.cumulative[i] + self.p[i] * t + 0.5 * slope * t * t
+    }
+}
+
+impl Distribution<f64> for TabulatedPdf {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let i = self.table.sample(rng);
+        let (p0, p1) = (self.p[i], self.p[i + 1]);
+        let w: f64 = rng.random();
+        // 区間内の密度 ∝ p0 + (p1 - p0) s（s ∈ [0, 1]）の逆関数
+        let s = if (p1 - p0).abs() < 1e-12 * (p0 + p1) {
+            w
+        } else {
+            ((p0 * p0 + w * (p1 * p1 - p0 * p0)).sqrt() - p0) / (p1 - p0)
+        };
+        self.x[i] + s * (self.x[i + 1] - self.x[i])
+    }
+}
+
+// ----------------------------------------
+// 5. Box–Muller 法
+// ----------------------------------------
+
+/// 2つの一様乱数から独立な標準正規乱数を2つ作る
+struct BoxMuller;
+
+impl BoxMuller {
+    fn sample_pair<R: Rng + ?Sized>(rng: &mut R) -> (f64, f64) {
+        let u1 = 1.0 - rng.random::<f64>();
+        let u2: f64 = rng.random();
+        let r = (-2.0 * u1.ln()).sqrt();
+        (r * (2.0 * PI * u2).cos(), r * (2.0 * PI * u2).sin())
+    }
+}
+
+impl Distribution<f64> for BoxMuller {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        BoxMuller::sample_pair(rng).0
+    }
+}
+
+// ----------------------------------------
+// 適合度検定
+// ----------------------------------------
+
+/// χ² 検定: [a, b] を n_bins 等分した区間と両側の裾の区間で度数を比較する
+/// 戻り値は (χ², 自由度, p 値)
+fn chi_square_test(
+    samples: &[f64],
+    cdf: &dyn Fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    n_bins: usize,
+) -> (f64, usize, f64) {
+    let n = samples.len() as f64;
+    let mut edges = vec![f64::NEG_INFINITY];
+    edges.extend((0..=n_bins).map(|i| a + (b - a) * i as f64 / n_bins as f64));
+    edges.push(f64::INFINITY);
+    let mut observed = vec![0usize; edges.len() - 1];
+    let last = observed.len() - 1;
+    for &x in samples {
+        let k = edges.partition_point(|&e| e <= x) - 1;
+        observed[k.min(last)] += 1;
+    }
+    let mut chi2 = 0.0;
+    let mut n_used = 0;
+    for (k, &o) in observed.iter().enumerate() {
+        let lower = if edges[k].is_finite() {
+            cdf(edges[k])
+        } else {
+            0.0
+        };
+        let upper = if edges[k + 1].is_finite() {
+            cdf(edges[k + 1])
+        } else {
+            1.0
+        };
+        let expected = n * (upper - lower);
+        // 期待度数が小さすぎる区間は使わない
+        if expected >= 5.0 {
+            chi2 += (o as f64 - expected).powi(2) / expected;
+            n_used += 1;
+        }
+    }
+    let dof = n_used - 1;
+    (chi2, dof, 1.0 - gamma_p(0.5 * dof as f64, 0.5 * chi2))
+}
+
+/// 離散分布の χ² 検定（期待度数 5 未満の裾はまとめる）
+fn chi_square_discrete(counts: &[usize], probs: &[f64]) -> (f64, usize, f64) {
+    let n: usize = counts.iter().sum();
+    let (mut chi2, mut n_used) = (0.0, 0);
+    let (mut o_rest, mut e_rest) = (0.0, 0.0);
+    for (&o, &p) in counts.iter().zip(probs) {
+        let expected = n as f64 * p;
+        if expected >= 5.0 {
+            chi2 += (o as f64 - expected).powi(2) / expected;
+            n_used += 1;
+        } else {
+            o_rest += o as f64;
+            e_rest += expected;
+        }
+    }
+    if e_rest > 0.0 {
+        chi2 += (o_rest - e_rest).powi(2) / e_rest;
+        n_used += 1;
+    }
+    let dof = n_used - 1;
+    (chi2, dof, 1.0 - gamma_p(0.5 * dof as f64, 0.5 * chi2))
+}
+
+/// Kolmogorov–Smirnov 検定: D = sup |F_n(x) - F(x)| と p 値
+fn ks_test(samples: &[f64], cdf: &dyn Fn(f64) -> f64) -> (f64, f64) {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len() as f64;
+    let d = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x);
+            (f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+        .fold(0.0, f64::max);
+    // Kolmogorov 分布 Q(λ) = 2 Σ (-1)^{j-1} exp(-2j²λ²)
+    let lambda = (n.sqrt() + 0.12 + 0.11 / n.sqrt()) * d;
+    let p = (1..=100)
+        .map(|j| {
+            let sign = if j % 2 == 1 { 2.0 } else { -2.0 };
+            sign * (-2.0 * (j * j) as f64 * lambda * lambda).exp()
+        })
+        .sum::<f64>()
+        .clamp(0.0, 1.0);
+    (d, p)
+}
+
+/// 検定結果を1行で表示する（有意水準 1%）
+fn report(name: &str, samples: &[f64], elapsed: f64, cdf: &dyn Fn(f64) -> f64, range: (f64, f64)) {
+    let (chi2, dof, p_chi2) = chi_square_test(samples, cdf, range.0, range.1, 50);
+    let (d, p_ks) = ks_test(samples, cdf);
+    let pass = p_chi2 > 0.01 && p_ks > 0.01;
+    println!(
+        "{:<34} {:>8.1} {:>8.1}/{:<3} {:>7.3} {:>9.5} {:>7.3}   {}",
+        name,
+        elapsed * 1e9 / samples.len() as f64,
+        chi2,
+        dof,
+        p_chi2,
+        d,
+        p_ks,
+        if pass { "合格" } else { "不合格" }
+    );
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let n = 200_000;
+
+    println!(
+        "=== 物理でよく使う分布のサンプリングと適合度検定 (N = {}) ===",
+        n
+    );
+    println!(
+        "{:<34} {:>8} {:>12} {:>7} {:>9} {:>7}   判定",
+        "分布 / 方法", "ns/個", "χ²/自由度", "p 値", "KS の D", "p 値"
+    );
+
+    // Maxwell–Boltzmann 分布の速さ（a = √(kT/m) = 1）
+    // f(v) = √(2/π) v² exp(-v²/2)、F(v) = erf(v/√2) - √(2/π) v exp(-v²/2)
+    let mb_cdf = |v: f64| {
+        if v <= 0.0 {
+            0.0
+        } else {
+            erf(v / 2.0_f64.sqrt()) - (2.0 / PI).sqrt() * v * (-0.5 * v * v).exp()
+        }
+    };
+    let mb_range = (0.0, 4.5);
+
+    let inverse = InverseCdf {
+        cdf: &mb_cdf,
+        lo: 0.0,
+        hi: 40.0,
+    };
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| inverse.sample(&mut rng)).collect();
+    report(
+        "Maxwell–Boltzmann / 逆関数法(Brent)",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &mb_cdf,
+        mb_range,
+    );
+
+    // ln f = 2 ln v - v²/2 は凹関数
+    let mb_h = |v: f64| 2.0 * v.ln() - 0.5 * v * v;
+    let mb_dh = |v: f64| 2.0 / v - v;
+    let mut ars = AdaptiveRejection::new(&mb_h, &mb_dh, 0.0, f64::INFINITY, &[0.5, 3.0]);
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| ars.sample(&mut rng)).collect();
+    report(
+        "Maxwell–Boltzmann / 適応的棄却法",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &mb_cdf,
+        mb_range,
+    );
+    let ars_evals = ars.n_evals;
+    let ars_points = ars.points.len();
+
+    // Maxwell–Boltzmann の速度 3 成分を Box–Muller で作って速さにする方法との比較
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n)
+        .map(|_| {
+            let (vx, vy) = BoxMuller::sample_pair(&mut rng);
+            let vz = BoxMuller.sample(&mut rng);
+            (vx * vx + vy * vy + vz * vz).sqrt()
+        })
+        .collect();
+    report(
+        "Maxwell–Boltzmann / Box–Muller 3成分",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &mb_cdf,
+        mb_range,
+    );
+
+    // 標準正規分布（Box–Muller）
+    let normal_cdf = |x: f64| 0.5 * (1.0 + erf(x / 2.0_f64.sqrt()));
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| BoxMuller.sample(&mut rng)).collect();
+    report(
+        "標準正規分布 / Box–Muller",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &normal_cdf,
+        (-4.0, 4.0),
+    );
+
+    // Planck 分布（光子のエネルギー x = hν/kT）: f(x) ∝ x³/(eˣ - 1)、∫f = π⁴/15
+    let planck = |x: f64| {
+        if x <= 0.0 {
+            0.0
+        } else {
+            x.powi(3) / x.exp_m1()
+        }
+    };
+    let planck_norm = PI.powi(4) / 15.0;
+    // 累積分布関数は細かい格子での Simpson 積分の表を線形補間する
+    let (x_max, n_table) = (60.0, 60_000);
+    let h = x_max / n_table as f64;
+    let mut planck_table = vec![0.0];
+    for i in 0..n_table {
+        let x0 = i as f64 * h;
+        let integral = h / 6.0 * (planck(x0) + 4.0 * planck(x0 + 0.5 * h) + planck(x0 + h));
+        planck_table.push(planck_table.last().unwrap() + integral / planck_norm);
+    }
+    let planck_cdf = |x: f64| {
+        if x >= x_max {
+            return 1.0;
+        }
+        let t = x.max(0.0) / h;
+        let i = t as usize;
+        planck_table[i] + (t - i as f64) * (planck_table[i + 1] - planck_table[i])
+    };
+    let rou = RatioOfUniforms::new(&planck, 0.0, x_max);
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| rou.sample(&mut rng)).collect();
+    report(
+        "Planck 分布 / 比一様法",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &planck_cdf,
+        (0.0, 12.0),
+    );
+    let mean = samples.iter().sum::<f64>() / n as f64;
+
+    // Rutherford 散乱の角度分布 dσ/dΩ ∝ 1/sin⁴(θ/2)（θ ≥ θ_min で遮蔽）
+    // μ = cos θ の密度は ∝ 1/(1 - μ)²（μ ∈ [-1, μ_max]）
+    let theta_min: f64 = 5.0_f64.to_radians();
+    let mu_max = theta_min.cos();
+    let c = 1.0 / (1.0 - mu_max) - 0.5;
+    let rutherford_cdf =
+        |mu: f64| ((1.0 / (1.0 - mu.clamp(-1.0, mu_max)) - 0.5) / c).clamp(0.0, 1.0);
+    let inverse = InverseCdf {
+        cdf: &rutherford_cdf,
+        lo: -1.0,
+        hi: mu_max,
+    };
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| inverse.sample(&mut rng)).collect();
+    report(
+        "Rutherford cos θ / 逆関数法(Brent)",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &rutherford_cdf,
+        (0.9, mu_max),
+    );
+    // 閉じた形の逆関数 1/(1 - μ) = 1/2 + u c との比較
+    let max_diff = (0..1000)
+        .map(|i| {
+            let u = (i as f64 + 0.5) / 1000.0;
+            let exact = 1.0 - 1.0 / (0.5 + u * c);
+            let root = brent(|mu| rutherford_cdf(mu) - u, -1.0, mu_max, 1e-12);
+            (root - exact).abs()
+        })
+        .fold(0.0, f64::max);
+
+    // 表で与えられたスペクトル（指数的な背景 + 662 keV の光電ピーク）
+    let energies: Vec<f64> = (0..=160).map(|i| 5.0 * i as f64).collect();
+    let intensities: Vec<f64> = energies
+        .iter()
+        .map(|&e| (-e / 200.0).exp() + 0.8 * (-(e - 662.0).powi(2) / (2.0 * 15.0 * 15.0)).exp())
+        .collect();
+    let spectrum = TabulatedPdf::new(energies, intensities);
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| spectrum.sample(&mut rng)).collect();
+    report(
+        "表で与えたスペクトル / エイリアス法",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &|x| spectrum.cdf(x),
+        (0.0, 800.0),
+    );
+
+    // 離散分布: Poisson(λ = 4)（エイリアス法）
+    let lambda: f64 = 4.0;
+    let probs: Vec<f64> = (0..30)
+        .map(|k| (k as f64 * lambda.ln() - lambda - ln_gamma(k as f64 + 1.0)).exp())
+        .collect();
+    let alias = AliasTable::new(&probs);
+    let mut counts = vec![0usize; probs.len()];
+    let start = Instant::now();
+    for _ in 0..n {
+        counts[alias.sample(&mut rng)] += 1;
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    let (chi2, dof, p) = chi_square_discrete(&counts, &probs);
+    println!(
+        "{:<34} {:>8.1} {:>8.1}/{:<3} {:>7.3} {:>9} {:>7}   {}",
+        "Poisson(λ = 4) / エイリアス法",
+        elapsed * 1e9 / n as f64,
+        chi2,
+        dof,
+        p,
+        "-",
+        "-",
+        if p > 0.01 { "合格" } else { "不合格" }
+    );
+
+    println!("\n補足:");
+    println!(
+        "  適応的棄却法: 接点 {} 個、ln f の評価 {} 回（1サンプルあたり {:.5} 回）",
+        ars_points,
+        ars_evals,
+        ars_evals as f64 / n as f64
+    );
+    println!(
+        "  比一様法: 受理率 {:.4}, Planck 分布の平均 {:.4}（厳密値 4ζ(5)/ζ(4) = {:.4}）",
+        rou.acceptance_rate(planck_norm),
+        mean,
+        4.0 * 1.036_927_755_143_37 / (PI.powi(4) / 90.0)
+    );
+    println!(
+        "  Rutherford: Brent 法の解と閉じた形の逆関数の最大差 {:.2e}",
+        max_diff
+    );
+
+    // 故意に誤った分布（Maxwell–Boltzmann の a = √(kT/m) を 5% ずらす）が検定で棄却されることの確認
+    let start = Instant::now();
+    let samples: Vec<f64> = (0..n).map(|_| 1.05 * ars.sample(&mut rng)).collect();
+    println!("\n検出力の確認（温度を約 10% ずらした Maxwell–Boltzmann 分布を検定）:");
+    report(
+        "Maxwell–Boltzmann (a = 1.05)",
+        &samples,
+        start.elapsed().as_secs_f64(),
+        &mb_cdf,
+        mb_range,
+    );
+}
@@ -0,0 +1,417 @@
+use ch09::special::{gamma_q, ln_gamma};
+use rand::{Rng, SeedableRng, TryRng};
+use rand_chacha::ChaCha8Rng;
+use std::convert::Infallible;
+use std::f64::consts::PI;
+
+// ----------------------------------------
+// χ² 検定（特殊関数は ch09::special）
+// ----------------------------------------
+
+/// 自由度 dof の χ² 分布の上側確率
+fn chi_square_p(chi2: f64, dof: usize) -> f64 {
+    gamma_q(0.5 * dof as f64, 0.5 * chi2)
+}
+
+/// 観測度数と期待度数から χ² と p 値を計算する
+fn chi_square(observed: &[f64], expected: &[f64]) -> (f64, f64) {
+    let chi2: f64 = observed
+        .iter()
+        .zip(expected)
+        .map(|(o, e)| (o - e).powi(2) / e)
+        .sum();
+    (chi2, chi_square_p(chi2, observed.len() - 1))
+}
+
+// ----------------------------------------
+// 検定の対象とする生成器
+// ----------------------------------------
+
+/// 線形合同法 x_{k+1} = (a x_k + c) mod m（出力は x/m を 32 ビットに拡大したもの）
+struct Lcg {
+    state: u128,
+    a: u128,
+    c: u128,
+    m: u128,
+}
+
+impl Lcg {
+    fn new(a: u128, c: u128, m: u128, seed: u128) -> Self {
+        Self {
+            state: seed % m,
+            a,
+            c,
+            m,
+        }
+    }
+}
+
+impl TryRng for Lcg {
+    type Error = Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+        self.state = (self.a * self.state + self.c) % self.m;
+        Ok(((self.state << 32) / self.m) as u32)
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+        let hi = self.try_next_u32()? as u64;
+        let lo = self.try_next_u32()? as u64;
+        Ok((hi << 32) | lo)
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+        for chunk in dst.chunks_mut(4) {
+            let bytes = self.try_next_u32()?.to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+/// [0, 1) の一様乱数（上位 32 ビットを使う）
+fn uniform<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    rng.next_u32() as f64 / 4_294_967_296.0
+}
+
+// ----------------------------------------
+// 検定
+// ----------------------------------------
+
+/// 検定1つぶんの結果
+struct TestResult {
+    name: &'static str,
+    statistic: String,
+    p_value: f64,
+}
+
+impl TestResult {
+    /// p 値が 0.001 未満（偏りが大きすぎる）か 0.999 より大きい（一様すぎる）なら不合格
+    fn passed(&self) -> bool {
+        (0.001..=0.999).contains(&self.p_value)
+    }
+}
+
+/// 1次元の等分布性: 上位 10 ビットで 1024 区間に分けた度数の χ² 検定
+fn equidistribution_test<R: Rng + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    let k = 1024;
+    let mut counts = vec![0.0; k];
+    for _ in 0..n {
+        counts[(rng.next_u32() >> 22) as usize] += 1.0;
+    }
+    let (chi2, p) = chi_square(&counts, &vec![n as f64 / k as f64; k]);
+    TestResult {
+        name: "等分布 χ² (1024 区間)",
+        statistic: format!("χ² = {:.1}", chi2),
+        p_value: p,
+    }
+}
+
+/// Kolmogorov–Smirnov 検定: 一様分布の累積分布関数との最大差
+fn ks_test<R: Rng + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    let mut u: Vec<f64> = (0..n).map(|_| uniform(rng)).collect();
+    u.sort_by(|a, b| a.total_cmp(b));
+    let nf = n as f64;
+    let d = u
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (x - i as f64 / nf).max((i + 1) as f64 / nf - x))
+        .fold(0.0, f64::max);
+    let lambda = (nf.sqrt() + 0.12 + 0.11 / nf.sqrt()) * d;
+    let p = (1..=100)
+        .map(|j| {
+            let sign = if j % 2 == 1 { 2.0 } else { -2.0 };
+            sign * (-2.0 * (j * j) as f64 * lambda * lambda).exp()
+        })
+        .sum::<f64>()
+        .clamp(0.0, 1.0);
+    TestResult {
+        name: "Kolmogorov–Smirnov",
+        statistic: format!("D = {:.5}", d),
+        p_value: p,
+    }
+}
+
+/// 系列検定: 重ならない d 個組を各軸 cells 等分した d 次元の格子で数える
+fn serial_test<R: Rng + ?Sized>(rng: &mut R, n_tuples: usize, d: u32, cells: usize) -> TestResult {
+    let bits = cells.trailing_zeros();
+    let mut counts = vec![0.0; cells.pow(d)];
+    for _ in 0..n_tuples {
+        let index = (0..d).fold(0, |acc, _| {
+            acc * cells + (rng.next_u32() >> (32 - bits)) as usize
+        });
+        counts[index] += 1.0;
+    }
+    let expected = n_tuples as f64 / counts.len() as f64;
+    let (chi2, p) = chi_square(&counts, &vec![expected; counts.len()]);
+    TestResult {
+        name: if d == 2 {
+            "系列検定 2つ組 (64² 区画)"
+        } else {
+            "系列検定 3つ組 (16³ 区画)"
+        },
+        statistic: format!("χ² = {:.1}", chi2),
+        p_value: p,
+    }
+}
+
+/// 系列相関係数（Knuth 3.3.2 K、ラグ 1、巡回的）
+/// 帰無仮説のもとで C は平均 -1/(n-1)、標準偏差 ≈ 1/√n の正規分布に従う
+fn serial_correlation_test<R: Rng + ?Sized>(rng: &mut R, n: usize) -> TestResult {
+    let u: Vec<f64> = (0..n).map(|_| uniform(rng)).collect();
+    let nf = n as f64;
+    let sum: f64 = u.iter().sum();
+    let sum2: f64 = u.iter().map(|x| x * x).sum();
+    let cross: f64 = (0..n).map(|i| u[i] * u[(i + 1) % n]).sum();
+    let c = (nf * cross - sum * sum) / (nf * sum2 - sum * sum);
+    let z = (c + 1.0 / (nf - 1.0)) * nf.sqrt();
+    TestResult {
+        name: "系列相関 (ラグ 1)",
+        statistic: format!("C = {:+.5}", c),
+        // 両側 p 値 erfc(|z|/√2) = Q(1/2, z²/2)
+        p_value: gamma_q(0.5, 0.5 * z * z),
+    }
+}
+
+/// ギャップ検定: u ∈ [0, 0.1) となる間隔の長さ r の分布 p(1-p)^r
+fn gap_test<R: Rng + ?Sized>(rng: &mut R, n_gaps: usize) -> TestResult {
+    let (p, t) = (0.1, 30);
+    let mut counts = vec![0.0; t + 1];
+    for _ in 0..n_gaps {
+        let mut r = 0;
+        while uniform(rng) >= p {
+            r += 1;
+        }
+        counts[r.min(t)] += 1.0;
+    }
+    let mut expected: Vec<f64> = (0..t)
+        .map(|r| n_gaps as f64 * p * (1.0 - p).powi(r as i32))
+        .collect();
+    expected.push(n_gaps as f64 * (1.0 - p).powi(t as i32));
+    let (chi2, p_value) = chi_square(&counts, &expected);
+    TestResult {
+        name: "ギャップ検定 [0, 0.1)",
+        statistic: format!("χ² = {:.1}", chi2),
+        p_value,
+    }
+}
+
+/// 上昇連の検定（Knuth 3.3.2 G）
+/// 連が終わるたびに次の1個を捨てると、連の長さ r は独立に確率 1/r! - 1/(r+1)! をとる
+fn runs_test<R: Rng + ?Sized>(rng: &mut R, n_runs: usize) -> TestResult {
+    let t = 6;
+    let mut counts = vec![0.0; t];
+    for _ in 0..n_runs {
+        let mut prev = uniform(rng);
+        let mut len = 1;
+        loop {
+            let x = uniform(rng);
+            if x <= prev {
+                break;
+            }
+            prev = x;
+            len += 1;
+        }
+        counts[len.min(t) - 1] += 1.0;
+    }
+    let factorial = |r: usize| (1..=r).product::<usize>() as f64;
+    let mut expected: Vec<f64> = (1..t)
+        .map(|r| n_runs as f64 * (1.0 / factorial(r) - 1.0 / factorial(r + 1)))
+        .collect();
+    expected.push(n_runs as f64 / factorial(t));
+    let (chi2, p) = chi_square(&counts, &expected);
+    TestResult {
+        name: "上昇連の検定",
+        statistic: format!("χ² = {:.1}", chi2),
+        p_value: p,
+    }
+}
+
+/// 誕生日間隔検定（Marsaglia）
+/// 2^24 日の1年に 512 人の誕生日を置き、並べた間隔のうち重複した値の数 J を数える
+/// J は平均 λ = m³/(4n) = 2 の Poisson 分布に従う
+fn birthday_spacings_test<R: Rng + ?Sized>(rng: &mut R, n_trials: usize) -> TestResult {
+    let (m, log2_days) = (512, 24);
+    let lambda = (m as f64).powi(3) / (4.0 * (1u64 << log2_days) as f64);
+    let t = 6;
+    let mut counts = vec![0.0; t + 1];
+    for _ in 0..n_trials {
+        let mut birthdays: Vec<u32> = (0..m).map(|_| rng.next_u32() >> (32 - log2_days)).collect();
+        birthdays.sort_unstable();
+        let mut spacings: Vec<u32> = birthdays.windows(2).map(|w| w[1] - w[0]).collect();
+        spacings.push(birthdays[0]);
+        spacings.sort_unstable();
+        let duplicates = spacings.windows(2).filter(|w| w[0] == w[1]).count();
+        counts[duplicates.min(t)] += 1.0;
+    }
+    let poisson = |j: usize| (j as f64 * lambda.ln() - lambda - ln_gamma(j as f64 + 1.0)).exp();
+    let mut expected: Vec<f64> = (0..t).map(|j| n_trials as f64 * poisson(j)).collect();
+    expected.push(n_trials as f64 - expected.iter().sum::<f64>());
+    let (chi2, p) = chi_square(&counts, &expected);
+    TestResult {
+        name: "誕生日間隔検定",
+        statistic: format!("χ² = {:.1}", chi2),
+        p_value: p,
+    }
+}
+
+/// 2次元のスペクトル検定（経験的）
+/// 連続する2つ組 (u_i, u_{i+1}) のフーリエ係数 S(h) = |Σ exp(2πi h·u)|²/n は一様なら Exp(1) に従う
+/// 点が格子上に並ぶと、双対格子のベクトル h で S(h) が n 程度に跳ね上がる
+fn spectral_test_2d<R: Rng + ?Sized>(rng: &mut R, n_pairs: usize) -> TestResult {
+    let h_max: i64 = 8;
+    // 連続する2つ組は重ならないように取る
+    let pairs: Vec<(f64, f64)> = (0..n_pairs).map(|_| (uniform(rng), uniform(rng))).collect();
+    // h と -h は同じ値を与えるので半平面だけを調べる
+    let mut wave_vectors: Vec<(i64, i64)> = (-h_max..=h_max)
+        .flat_map(|h2| (0..=h_max).map(move |h1| (h1, h2)))
+        .filter(|&(h1, h2)| h1 > 0 || (h1 == 0 && h2 > 0))
+        .collect();
+    // 同じ値のピークが複数あるときは最も短い h を報告する
+    wave_vectors.sort_by_key(|&(h1, h2)| h1 * h1 + h2 * h2);
+    let (mut s_max, mut h_peak) = (0.0, (0, 0));
+    for &(h1, h2) in &wave_vectors {
+        let (mut re, mut im) = (0.0, 0.0);
+        for &(x, y) in &pairs {
+            let phase = 2.0 * PI * (h1 as f64 * x + h2 as f64 * y);
+            re += phase.cos();
+            im += phase.sin();
+        }
+        let s = (re * re + im * im) / n_pairs as f64;
+        if s > s_max {
+            s_max = s;
+            h_peak = (h1, h2);
+        }
+    }
+    // K 個の独立な Exp(1) の最大値が s_max を超える確率
+    let k = wave_vectors.len() as f64;
+    let p = 1.0 - (1.0 - (-s_max).exp()).powf(k);
+    TestResult {
+        name: "2次元スペクトル検定",
+        statistic: format!("max S = {:.1} @ h = ({}, {})", s_max, h_peak.0, h_peak.1),
+        p_value: p,
+    }
+}
+
+/// 全検定を実行する
+fn run_battery<R: Rng + ?Sized>(rng: &mut R) -> Vec<TestResult> {
+    vec![
+        equidistribution_test(rng, 1_000_000),
+        ks_test(rng, 100_000),
+        serial_test(rng, 1_000_000, 2, 64),
+        serial_test(rng, 1_000_000, 3, 16),
+        serial_correlation_test(rng, 1_000_000),
+        gap_test(rng, 100_000),
+        runs_test(rng, 100_000),
+        birthday_spacings_test(rng, 1_000),
+        spectral_test_2d(rng, 1 << 16),
+    ]
+}
+
+/// 線形合同法の2次元スペクトル検定（理論値）
+/// 双対格子 {(h1, h2) : h1 + a h2 ≡ 0 (mod m)} の最短ベクトルの長さ ν₂ を Gauss の簡約で求め、
+/// Knuth の指標 μ₂ = π ν₂² / m を返す（μ₂ ≥ 0.1 なら合格、1 程度なら良好）
+fn lcg_spectral_2d(a: u64, m: u64) -> (f64, f64) {
+    let (mut u, mut v) = ((m as i128, 0i128), (-(a as i128), 1i128));
+    let norm = |w: (i128, i128)| w.0 * w.0 + w.1 * w.1;
+    loop {
+        if norm(u) < norm(v) {
+            std::mem::swap(&mut u, &mut v);
+        }
+        // u から v の整数倍を引いて短くする
+        let dot = u.0 * v.0 + u.1 * v.1;
+        let q = (dot as f64 / norm(v) as f64).round() as i128;
+        let w = (u.0 - q * v.0, u.1 - q * v.1);
+        if norm(w) >= norm(v) {
+            break;
+        }
+        u = w;
+    }
+    let nu = (norm(v) as f64).sqrt();
+    (nu, PI * nu * nu / m as f64)
+}
+
+/// 検定する生成器の名前、本体、線形合同法ならそのパラメータ (a, m)
+type Generator = (&'static str, Box<dyn Rng>, Option<(u64, u64)>);
+
+fn main() {
+    println!("=== 乱数生成器の統計的検定 ===");
+    println!("判定: 0.001 ≤ p ≤ 0.999 なら合格（p が小さすぎても大きすぎても不合格）\n");
+
+    let generators: Vec<Generator> = vec![
+        ("ChaCha8Rng", Box::new(ChaCha8Rng::seed_from_u64(42)), None),
+        (
+            "MMIX の LCG (m = 2^64, 上位 32 ビット)",
+            Box::new(Lcg::new(
+                6364136223846793005,
+                1442695040888963407,
+                1 << 64,
+                42,
+            )),
+            None,
+        ),
+        (
+            "MINSTD (a = 16807, m = 2^31 - 1)",
+            Box::new(Lcg::new(16807, 0, (1 << 31) - 1, 42)),
+            Some((16807, (1 << 31) - 1)),
+        ),
+        (
+            "RANDU (a = 65539, m = 2^31)",
+            Box::new(Lcg::new(65539, 0, 1 << 31, 43)),
+            Some((65539, 1 << 31)),
+        ),
+        (
+            "周期の短い LCG (a = 25173, m = 2^16)",
+            Box::new(Lcg::new(25173, 13849, 1 << 16, 42)),
+            Some((25173, 1 << 16)),
+        ),
+        (
+            "悪い乗数の LCG (a = 3, m = 2^31 - 1)",
+            Box::new(Lcg::new(3, 0, (1 << 31) - 1, 42)),
+            Some((3, (1 << 31) - 1)),
+        ),
+    ];
+
+    let mut summary = Vec::new();
+    for (name, mut rng, lcg) in generators {
+        println!("--- {} ---", name);
+        let results = run_battery(&mut *rng);
+        for r in &results {
+            println!(
+                "  {:<30} {:<30} p = {:<10.4e} {}",
+                r.name,
+                r.statistic,
+                r.p_value,
+                if r.passed() { "合格" } else { "不合格" }
+            );
+        }
+        if let Some((a, m)) = lcg {
+            let (nu, mu) = lcg_spectral_2d(a, m);
+            println!(
+                "  {:<30} ν₂ = {:.1}, μ₂ = {:.3}  {}",
+                "2次元スペクトル検定（理論値）",
+                nu,
+                mu,
+                if mu >= 0.1 { "合格" } else { "不合格" }
+            );
+        }
+        let n_passed = results.iter().filter(|r| r.passed()).count();
+        summary.push((name, n_passed, results.len()));
+        println!();
+    }
+
+    println!("=== まとめ ===");
+    for (name, n_passed, n_tests) in summary {
+        println!(
+            "{:<42} {} / {} 合格  → {}",
+            name,
+            n_passed,
+            n_tests,
+            if n_passed == n_tests {
+                "本番計算に使用可"
+            } else {
+                "使用不可"
+            }
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+
+/// 対角ノイズの確率微分方程式 dX_i = a_i(t, X) dt + b_i(t, X) dW_i
+/// スカラーの SDE は dim() = 1 として扱う
+trait Sde {
+    fn dim(&self) -> usize;
+    /// ドリフト項 a(t, x)
+    fn drift(&self, t: f64, x: &[f64], out: &mut [f64]);
+    /// 拡散項 b(t, x)（各成分が独立なウィーナー過程で駆動される）
+    fn diffusion(&self, t: f64, x: &[f64], out: &mut [f64]);
+    /// ∂b_i/∂x_i（Milstein 法で使う）。既定では中心差分で近似する
+    fn diffusion_derivative(&self, t: f64, x: &[f64], out: &mut [f64]) {
+        let h = 1e-6;
+        let mut xp = x.to_vec();
+        let mut bp = vec![0.0; x.len()];
+        let mut bm = vec![0.0; x.len()];
+        for i in 0..x.len() {
+            xp[i] = x[i] + h;
+            self.diffusion(t, &xp, &mut bp);
+            xp[i] = x[i] - h;
+            self.diffusion(t, &xp, &mut bm);
+            xp[i] = x[i];
+            out[i] = (bp[i] - bm[i]) / (2.0 * h);
+        }
+    }
+}
+
+/// 数値解法
+#[derive(Clone, Copy, Debug)]
+enum Scheme {
+    /// オイラー・丸山法（強収束 1/2 次、弱収束 1 次）
+    EulerMaruyama,
+    /// Milstein 法（強収束 1 次）
+    Milstein,
+    /// 微分を使わない確率ルンゲ・クッタ法 (Platen)（強収束 1 次）
+    StochasticRk,
+}
+
+/// 1ステップ進める。dw はこのステップのウィーナー過程の増分 ΔW ~ N(0, Δt)
+fn step<S: Sde>(sde: &S, scheme: Scheme, t: f64, dt: f64, x: &mut [f64], dw: &[f64]) {
+    let d = sde.dim();
+    let mut a = vec![0.0; d];
+    let mut b = vec![0.0; d];
+    sde.drift(t, x, &mut a);
+    sde.diffusion(t, x, &mut b);
+    match scheme {
+        Scheme::EulerMaruyama => {
+            for i in 0..d {
+                x[i] += a[i] * dt + b[i] * dw[i];
+            }
+        }
+        Scheme::Milstein => {
+            // 伊藤の公式から出る補正項 (1/2) b b' (ΔW² - Δt)
+            let mut db = vec![0.0; d];
+            sde.diffusion_derivative(t, x, &mut db);
+            for i in 0..d {
+                x[i] += a[i] * dt + b[i] * dw[i] + 0.5 * b[i] * db[i] * (dw[i] * dw[i] - dt);
+            }
+        }
+        Scheme::StochasticRk => {
+            // 支持値 Υ = X + aΔt + b√Δt で b' を差分近似する
+            let sqrt_dt = dt.sqrt();
+            let support: Vec<f64> = (0..d).map(|i| x[i] + a[i] * dt + b[i] * sqrt_dt).collect();
+            let mut b_support = vec![0.0; d];
+            sde.diffusion(t, &support, &mut b_support);
+            for i in 0..d {
+                x[i] += a[i] * dt
+                    + b[i] * dw[i]
+                    + 0.5 * (b_support[i] - b[i]) * (dw[i] * dw[i] - dt) / sqrt_dt;
+            }
+        }
+    }
+}
+
+/// 与えられたウィーナー過程の増分の列に沿って [0, T] を積分する
+fn integrate_path<S: Sde>(
+    sde: &S,
+    scheme: Scheme,
+    x0: &[f64],
+    dt: f64,
+    increments: &[Vec<f64>],
+) -> Vec<f64> {
+    let mut x = x0.to_vec();
+    for (n, dw) in increments.iter().enumerate() {
+        step(sde, scheme, n as f64 * dt, dt, &mut x, dw);
+    }
+    x
+}
+
+/// ウィーナー過程の増分 ΔW ~ N(0, Δt) を n_steps 個生成する
+fn brownian_increments(dim: usize, n_steps: usize, dt: f64, rng: &mut impl Rng) -> Vec<Vec<f64>> {
+    let sqrt_dt = dt.sqrt();
+    (0..n_steps)
+        .map(|_| {
+            (0..dim)
+                .map(|_| {
+                    let xi: f64 = StandardNormal.sample(rng);
+                    sqrt_dt * xi
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 細かい増分を factor 個ずつ足して粗い刻みの増分にする（同じブラウン運動の経路を使うため）
+fn coarsen(increments: &[Vec<f64>], factor: usize) -> Vec<Vec<f64>> {
+    increments
+        .chunks(factor)
+        .map(|chunk| {
+            let mut sum = vec![0.0; chunk[0].len()];
+            for dw in chunk {
+                for (s, w) in sum.iter_mut().zip(dw) {
+                    *s += w;
+                }
+            }
+            sum
+        })
+        .collect()
+}
+
+// ----------------------------------------
+// SDE の例
+// ----------------------------------------
+
+/// 幾何ブラウン運動 dX = μX dt + σX dW
+/// 厳密解 X_T = X_0 exp((μ - σ²/2)T + σW_T)
+struct GeometricBrownianMotion {
+    mu: f64,
+    sigma: f64,
+}
+
+impl Sde for GeometricBrownianMotion {
+    fn dim(&self) -> usize {
+        1
+    }
+    fn drift(&self, _t: f64, x: &[f64], out: &mut [f64]) {
+        out[0] = self.mu * x[0];
+    }
+    fn diffusion(&self, _t: f64, x: &[f64], out: &mut [f64]) {
+        out[0] = self.sigma * x[0];
+    }
+    fn diffusion_derivative(&self, _t: f64, _x: &[f64], out: &mut [f64]) {
+        out[0] = self.sigma;
+    }
+}
+
+/// オルンシュタイン・ウーレンベック過程 dX = θ(μ - X) dt + σ dW
+struct OrnsteinUhlenbeck {
+    theta: f64,
+    mu: f64,
+    sigma: f64,
+}
+
+impl OrnsteinUhlenbeck {
+    /// 遷移確率が正規分布なので、任意の Δt で厳密にサンプリングできる
+    fn exact_step(&self, x: f64, dt: f64, rng: &mut impl Rng) -> f64 {
+        let decay = (-self.theta * dt).exp();
+        let std = self.sigma * ((1.0 - decay * decay) / (2.0 * self.theta)).sqrt();
+        let xi: f64 = StandardNormal.sample(rng);
+        self.mu + (x - self.mu) * decay + std * xi
+    }
+}
+
+impl Sde for OrnsteinUhlenbeck {
+    fn dim(&self) -> usize {
+        1
+    }
+    fn drift(&self, _t: f64, x: &[f64], out: &mut [f64]) {
+        out[0] = self.theta * (self.mu - x[0]);
+    }
+    fn diffusion(&self, _t: f64, _x: &[f64], out: &mut [f64]) {
+        out[0] = self.sigma;
+    }
+}
+
+/// 調和ポテンシャル中のランジュバン方程式（ベクトル SDE の例）
+/// dx = v dt,  dv = (-ω²x - γv) dt + √(2γ k_B T) dW
+struct Langevin {
+    omega: f64,
+    gamma: f64,
+    temperature: f64,
+}
+
+impl Sde for Langevin {
+    fn dim(&self) -> usize {
+        2
+    }
+    fn drift(&self, _t: f64, x: &[f64], out: &mut [f64]) {
+        out[0] = x[1];
+        out[1] = -self.omega * self.omega * x[0] - self.gamma * x[1];
+    }
+    fn diffusion(&self, _t: f64, _x: &[f64], out: &mut [f64]) {
+        out[0] = 0.0;
+        out[1] = (2.0 * self.gamma * self.temperature).sqrt();
+    }
+}
+
+/// d 次元の単純立方格子上のランダムウォーク
+/// 戻り値: (n_steps 後の変位の2乗, 原点に戻ったか)
+fn lattice_random_walk(dim: usize, n_steps: usize, rng: &mut impl Rng) -> (f64, bool) {
+    let mut pos = vec![0i64; dim];
+    let mut returned = false;
+    for _ in 0..n_steps {
+        let axis = rng.random_range(0..dim);
+        pos[axis] += if rng.random::<bool>() { 1 } else { -1 };
+        if pos.iter().all(|&p| p == 0) {
+            returned = true;
+        }
+    }
+    let r2 = pos.iter().map(|&p| (p * p) as f64).sum();
+    (r2, returned)
+}
+
+/// 最小二乗法による log-log の傾き
+fn fit_slope(xs: &[f64], ys: &[f64]) -> f64 {
+    let k = xs.len() as f64;
+    let lx: Vec<f64> = xs.iter().map(|x| x.ln()).collect();
+    let ly: Vec<f64> = ys.iter().map(|y| y.ln()).collect();
+    let (mx, my) = (lx.iter().sum::<f64>() / k, ly.iter().sum::<f64>() / k);
+    let sxy: f64 = lx.iter().zip(&ly).map(|(x, y)| (x - mx) * (y - my)).sum();
+    let sxx: f64 = lx.iter().map(|x| (x - mx).powi(2)).sum();
+    sxy / sxx
+}
+
+fn main() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2024);
+
+    // ----------------------------------------
+    // 1. 格子上のランダムウォーク: <R²> = N と再帰性 (Pólya)
+    // ----------------------------------------
+    println!("=== 1. 格子上のランダムウォーク (N = 10000 ステップ, 2000 本) ===");
+    println!(
+        "{:>6} {:>12} {:>12} {:>18}",
+        "次元", "<R²>", "<R²>/N", "原点に戻った割合"
+    );
+    let n_steps = 10_000;
+    for dim in 1..=3 {
+        let n_walkers = 2000;
+        let (mut r2_sum, mut n_returned) = (0.0, 0);
+        for _ in 0..n_walkers {
+            let (r2, returned) = lattice_random_walk(dim, n_steps, &mut rng);
+            r2_sum += r2;
+            n_returned += returned as usize;
+        }
+        let r2_mean = r2_sum / n_walkers as f64;
+        println!(
+            "{:>6} {:>12.1} {:>12.4} {:>18.4}",
+            dim,
+            r2_mean,
+            r2_mean / n_steps as f64,
+            n_returned as f64 / n_walkers as f64
+        );
+    }
+    println!("(1, 2 次元では N → ∞ で必ず戻る。3 次元で戻る確率は 0.3405 (Pólya))");
+
+    // ----------------------------------------
+    // 2. 幾何ブラウン運動での強収束・弱収束の次数
+    // ----------------------------------------
+    // 強誤差は拡散が支配的な場合、弱誤差はドリフトが支配的な場合に次数がはっきり見える
+    let gbm_strong = GeometricBrownianMotion {
+        mu: 0.5,
+        sigma: 1.0,
+    };
+    let gbm_weak = GeometricBrownianMotion {
+        mu: 1.5,
+        sigma: 0.5,
+    };
+    let (x0, t_end) = (1.0, 1.0);
+    let n_paths = 20_000;
+    let finest = 1 << 10;
+    let factors = [128, 64, 32, 16, 8, 4];
+    println!(
+        "\n=== 2. 幾何ブラウン運動 (T = {}, X₀ = {}, {} 経路) ===",
+        t_end, x0, n_paths
+    );
+
+    let schemes = [
+        Scheme::EulerMaruyama,
+        Scheme::Milstein,
+        Scheme::StochasticRk,
+    ];
+    let mut strong = vec![vec![0.0; factors.len()]; schemes.len()];
+    let mut weak = vec![vec![0.0; factors.len()]; schemes.len()];
+    let dt_fine = t_end / finest as f64;
+    let exact = |gbm: &GeometricBrownianMotion, w_t: f64| {
+        x0 * ((gbm.mu - 0.5 * gbm.sigma * gbm.sigma) * t_end + gbm.sigma * w_t).exp()
+    };
+    for _ in 0..n_paths {
+        let fine = brownian_increments(1, finest, dt_fine, &mut rng);
+        let w_t: f64 = fine.iter().map(|dw| dw[0]).sum();
+        let (exact_strong, exact_weak) = (exact(&gbm_strong, w_t), exact(&gbm_weak, w_t));
+        for (k, &factor) in factors.iter().enumerate() {
+            let coarse = coarsen(&fine, factor);
+            let dt = dt_fine * factor as f64;
+            for (s, &scheme) in schemes.iter().enumerate() {
+                // 強誤差 E|X_N - X(T)|
+                let x = integrate_path(&gbm_strong, scheme, &[x0], dt, &coarse)[0];
+                strong[s][k] += (x - exact_strong).abs();
+                // 弱誤差 |E[X_N] - E[X(T)]|（同じ経路の厳密解を引いて統計誤差を抑える）
+                let x = integrate_path(&gbm_weak, scheme, &[x0], dt, &coarse)[0];
+                weak[s][k] += x - exact_weak;
+            }
+        }
+    }
+
+    let dts: Vec<f64> = factors.iter().map(|&f| dt_fine * f as f64).collect();
+    for (title, errors, expected) in [
+        (
+            format!(
+                "強誤差 E|X_N - X(T)| (μ = {}, σ = {})",
+                gbm_strong.mu, gbm_strong.sigma
+            ),
+            &mut strong,
+            "EM: 0.5, Milstein: 1.0, SRK: 1.0",
+        ),
+        (
+            format!(
+                "弱誤差 |E[X_N] - E[X(T)]| (μ = {}, σ = {})",
+                gbm_weak.mu, gbm_weak.sigma
+            ),
+            &mut weak,
+            "すべて 1.0",
+        ),
+    ] {
+        println!("\n{}", title);
+        println!(
+            "{:>10} {:>14} {:>14} {:>14}",
+            "Δt", "Euler–Maruyama", "Milstein", "確率 RK"
+        );
+        for row in errors.iter_mut() {
+            for e in row.iter_mut() {
+                *e = (*e / n_paths as f64).abs();
+            }
+        }
+        for (k, dt) in dts.iter().enumerate() {
+            println!(
+                "{:>10.6} {:>14.4e} {:>14.4e} {:>14.4e}",
+                dt, errors[0][k], errors[1][k], errors[2][k]
+            );
+        }
+        println!(
+            "{:>10} {:>14.2} {:>14.2} {:>14.2}   (期待値 {})",
+            "次数",
+            fit_slope(&dts, &errors[0]),
+            fit_slope(&dts, &errors[1]),
+            fit_slope(&dts, &errors[2]),
+            expected
+        );
+    }
+
+    // ----------------------------------------
+    // 3. オルンシュタイン・ウーレンベック過程
+    // ----------------------------------------
+    let ou = OrnsteinUhlenbeck {
+        theta: 2.0,
+        mu: 1.0,
+        sigma: 0.8,
+    };
+    println!(
+        "\n=== 3. OU 過程 (θ = {}, μ = {}, σ = {}) ===",
+        ou.theta, ou.mu, ou.sigma
+    );
+    let dt = 0.05;
+    let n = 400_000;
+    let mut x = 0.0;
+    let mut exact_series = Vec::with_capacity(n);
+    for _ in 0..n {
+        x = ou.exact_step(x, dt, &mut rng);
+        exact_series.push(x);
+    }
+    let mut em_series = Vec::with_capacity(n);
+    let mut y = [0.0];
+    for (k, dw) in brownian_increments(1, n, dt, &mut rng).iter().enumerate() {
+        step(&ou, Scheme::EulerMaruyama, k as f64 * dt, dt, &mut y, dw);
+        em_series.push(y[0]);
+    }
+
+    let stats = |s: &[f64]| {
+        let s = &s[1000..];
+        let m = s.iter().sum::<f64>() / s.len() as f64;
+        let var = s.iter().map(|v| (v - m).powi(2)).sum::<f64>() / s.len() as f64;
+        // ラグ τ = 10Δt の自己相関
+        let lag = 10;
+        let c = (0..s.len() - lag)
+            .map(|i| (s[i] - m) * (s[i + lag] - m))
+            .sum::<f64>()
+            / ((s.len() - lag) as f64 * var);
+        (m, var, c)
+    };
+    let (m_ex, v_ex, c_ex) = stats(&exact_series);
+    let (m_em, v_em, c_em) = stats(&em_series);
+    println!(
+        "{:<14} {:>10} {:>10} {:>14}",
+        "", "平均", "分散", "自己相関(0.5)"
+    );
+    println!(
+        "{:<14} {:>10.4} {:>10.4} {:>14.4}",
+        "理論値",
+        ou.mu,
+        ou.sigma * ou.sigma / (2.0 * ou.theta),
+        (-ou.theta * 10.0 * dt).exp()
+    );
+    println!(
+        "{:<14} {:>10.4} {:>10.4} {:>14.4}",
+        "厳密な更新", m_ex, v_ex, c_ex
+    );
+    println!(
+        "{:<14} {:>10.4} {:>10.4} {:>14.4}",
+        "Euler–Maruyama", m_em, v_em, c_em
+    );
+    println!(
+        "(Euler–Maruyama の定常分散は σ²/(2θ - θ²Δt) = {:.4} にずれる)",
+        ou.sigma * ou.sigma / (2.0 * ou.theta - ou.theta * ou.theta * dt)
+    );
+
+    // ----------------------------------------
+    // 4. ベクトル SDE: ランジュバン方程式と等分配則
+    // ----------------------------------------
+    let langevin = Langevin {
+        omega: 2.0,
+        gamma: 0.5,
+        temperature: 1.5,
+    };
+    println!(
+        "\n=== 4. ランジュバン方程式 (ω = {}, γ = {}, k_B T = {}) ===",
+        langevin.omega, langevin.gamma, langevin.temperature
+    );
+    let dt: f64 = 0.005;
+    let n = 2_000_000;
+    let mut state = [0.0, 0.0];
+    let (mut x2, mut v2) = (0.0, 0.0);
+    let burn_in = 10_000;
+    let sqrt_dt = dt.sqrt();
+    for k in 0..n {
+        let xi: f64 = StandardNormal.sample(&mut rng);
+        let dw = [0.0, sqrt_dt * xi];
+        step(
+            &langevin,
+            Scheme::StochasticRk,
+            k as f64 * dt,
+            dt,
+            &mut state,
+            &dw,
+        );
+        if k >= burn_in {
+            x2 += state[0] * state[0];
+            v2 += state[1] * state[1];
+        }
+    }
+    let samples = (n - burn_in) as f64;
+    println!(
+        "<ω²x²> = {:.4}, <v²> = {:.4}  (等分配則: どちらも k_B T = {})",
+        langevin.omega * langevin.omega * x2 / samples,
+        v2 / samples,
+        langevin.temperature
+    );
+}
@@ -0,0 +1,464 @@
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::f64::consts::{E, PI};
+
+/// d 次元の直方体 [a_1, b_1] × ... × [a_d, b_d]
+struct Domain {
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+}
+
+impl Domain {
+    fn unit_cube(dim: usize) -> Self {
+        Self {
+            lower: vec![0.0; dim],
+            upper: vec![1.0; dim],
+        }
+    }
+
+    fn dim(&self) -> usize {
+        self.lower.len()
+    }
+
+    fn volume(&self) -> f64 {
+        self.lower
+            .iter()
+            .zip(&self.upper)
+            .map(|(a, b)| b - a)
+            .product()
+    }
+
+    /// 単位超立方体の点 u を領域内の点 x に写す
+    fn map(&self, u: &[f64], x: &mut [f64]) {
+        for i in 0..u.len() {
+            x[i] = self.lower[i] + (self.upper[i] - self.lower[i]) * u[i];
+        }
+    }
+}
+
+/// 積分の推定値
+struct Estimate {
+    value: f64,
+    std_error: f64,
+    /// 被積分関数の評価回数
+    n_evals: usize,
+}
+
+impl Estimate {
+    /// 同じ評価回数の単純モンテカルロ法に対する分散削減率 σ²_plain / σ²_method
+    fn variance_reduction_factor(&self, plain: &Estimate) -> f64 {
+        let scale = plain.n_evals as f64 / self.n_evals as f64;
+        (plain.std_error / self.std_error).powi(2) * scale
+    }
+}
+
+/// 制御変量: 積分値が既知の関数 g
+/// f の代わりに h = f - c(g - E[g]) を平均する（係数 c はサンプルから推定）
+struct ControlVariate<'a> {
+    g: &'a dyn Fn(&[f64]) -> f64,
+    /// 領域上での g の積分値
+    integral: f64,
+}
+
+/// 分散減少法を組み合わせられるモンテカルロ積分器
+struct MonteCarlo<'a> {
+    domain: Domain,
+    antithetic: bool,
+    control: Option<ControlVariate<'a>>,
+    /// 各次元の層の数（層の総数は strata^d）
+    strata: usize,
+}
+
+impl<'a> MonteCarlo<'a> {
+    fn new(domain: Domain) -> Self {
+        Self {
+            domain,
+            antithetic: false,
+            control: None,
+            strata: 1,
+        }
+    }
+
+    /// 対称変量法: u と 1 - u の組で評価する
+    fn antithetic(mut self) -> Self {
+        self.antithetic = true;
+        self
+    }
+
+    fn control_variate(mut self, g: &'a dyn Fn(&[f64]) -> f64, integral: f64) -> Self {
+        self.control = Some(ControlVariate { g, integral });
+        self
+    }
+
+    /// 層別抽出: 各次元を k 等分した小直方体ごとに同数のサンプルをとる
+    fn stratified(mut self, k: usize) -> Self {
+        self.strata = k;
+        self
+    }
+
+    /// 評価回数およそ n_evals で積分する
+    fn integrate<F: Fn(&[f64]) -> f64>(
+        &self,
+        f: &F,
+        n_evals: usize,
+        rng: &mut impl Rng,
+    ) -> Estimate {
+        let d = self.domain.dim();
+        let n_strata = self.strata.pow(d as u32);
+        let evals_per_unit = if self.antithetic { 2 } else { 1 };
+        // 各層で分散を推定するため、1層あたり2単位以上にする
+        let units_per_stratum = (n_evals / (n_strata * evals_per_unit)).max(2);
+        let width = 1.0 / self.strata as f64;
+
+        let mut u = vec![0.0; d];
+        let mut x = vec![0.0; d];
+        // 層ごとの (f の値, g の値) の列（対称変量法では組の平均を1単位とする）
+        let mut units: Vec<Vec<(f64, f64)>> = Vec::with_capacity(n_strata);
+        for s in 0..n_strata {
+            // 層の番号を各次元のインデックスに分解する
+            let mut offset = vec![0.0; d];
+            let mut rest = s;
+            for o in offset.iter_mut() {
+                *o = (rest % self.strata) as f64 * width;
+                rest /= self.strata;
+            }
+
+            let mut stratum = Vec::with_capacity(units_per_stratum);
+            for _ in 0..units_per_stratum {
+                let v: Vec<f64> = (0..d).map(|_| rng.random::<f64>()).collect();
+                let mut eval = |v: &[f64]| {
+                    for i in 0..d {
+                        u[i] = offset[i] + width * v[i];
+                    }
+                    self.domain.map(&u, &mut x);
+                    let g = self.control.as_ref().map_or(0.0, |c| (c.g)(&x));
+                    (f(&x), g)
+                };
+                let (mut fv, mut gv) = eval(&v);
+                if self.antithetic {
+                    // 層の中で反転させた点
+                    let w: Vec<f64> = v.iter().map(|vi| 1.0 - vi).collect();
+                    let (f2, g2) = eval(&w);
+                    fv = 0.5 * (fv + f2);
+                    gv = 0.5 * (gv + g2);
+                }
+                stratum.push((fv, gv));
+            }
+            units.push(stratum);
+        }
+
+        // 制御変量の最適係数 c = Cov(f, g) / Var(g)（層内の共分散を合算して推定）
+        let (c, g_mean) = match &self.control {
+            Some(cv) => {
+                let (mut cov, mut var) = (0.0, 0.0);
+                for stratum in &units {
+                    let m = stratum.len() as f64;
+                    let fm = stratum.iter().map(|p| p.0).sum::<f64>() / m;
+                    let gm = stratum.iter().map(|p| p.1).sum::<f64>() / m;
+                    for &(fv, gv) in stratum {
+                        cov += (fv - fm) * (gv - gm);
+                        var += (gv - gm) * (gv - gm);
+                    }
+                }
+                (cov / var, cv.integral / self.domain.volume())
+            }
+            None => (0.0, 0.0),
+        };
+
+        // 層ごとの平均と分散から推定値と標準誤差を組み立てる
+        // I = V · (1/K) Σ_s mean_s(h),  SE² = V² / K² · Σ_s var_s(h) / m
+        let volume = self.domain.volume();
+        let (mut total, mut var_total) = (0.0, 0.0);
+        for stratum in &units {
+            let m = stratum.len() as f64;
+            let h: Vec<f64> = stratum
+                .iter()
+                .map(|&(fv, gv)| fv - c * (gv - g_mean))
+                .collect();
+            let mean = h.iter().sum::<f64>() / m;
+            let var = h.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (m - 1.0);
+            total += mean;
+            var_total += var / m;
+        }
+        let k = n_strata as f64;
+        Estimate {
+            value: volume * total / k,
+            std_error: volume * var_total.sqrt() / k,
+            n_evals: n_strata * units_per_stratum * evals_per_unit,
+        }
+    }
+}
+
+/// VEGAS 法: 各次元の区分的一様な密度（格子）を反復ごとに |f| に合わせて適応させる重点サンプリング
+struct Vegas {
+    n_bins: usize,
+    /// 各次元の格子点 0 = e_0 < e_1 < ... < e_N = 1（単位超立方体上）
+    edges: Vec<Vec<f64>>,
+}
+
+impl Vegas {
+    fn new(dim: usize, n_bins: usize) -> Self {
+        let uniform: Vec<f64> = (0..=n_bins).map(|i| i as f64 / n_bins as f64).collect();
+        Self {
+            n_bins,
+            edges: vec![uniform; dim],
+        }
+    }
+
+    /// n_iter 回の反復で積分する。最初の n_warmup 回は格子の適応だけに使い、
+    /// 残りの反復の推定値を分散の逆数で重み付け平均する
+    fn integrate<F: Fn(&[f64]) -> f64>(
+        &mut self,
+        f: &F,
+        domain: &Domain,
+        n_per_iter: usize,
+        n_iter: usize,
+        n_warmup: usize,
+        rng: &mut impl Rng,
+    ) -> (Estimate, f64) {
+        let d = domain.dim();
+        let nb = self.n_bins;
+        let volume = domain.volume();
+        let mut u = vec![0.0; d];
+        let mut x = vec![0.0; d];
+        let mut bins = vec![0usize; d];
+        let (mut weighted_sum, mut weight_total) = (0.0, 0.0);
+        let mut iteration_estimates = Vec::new();
+
+        for iter in 0..n_iter {
+            // 各次元・各区間での Σ (f·J)² （格子の更新に使う）
+            let mut importance = vec![vec![0.0; nb]; d];
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..n_per_iter {
+                // y ~ U[0,1)^d を格子で写す。ヤコビアン J = Π N·Δe_i
+                let mut jacobian = volume;
+                for i in 0..d {
+                    let y = rng.random::<f64>() * nb as f64;
+                    let b = (y as usize).min(nb - 1);
+                    let (lo, hi) = (self.edges[i][b], self.edges[i][b + 1]);
+                    u[i] = lo + (y - b as f64) * (hi - lo);
+                    jacobian *= nb as f64 * (hi - lo);
+                    bins[i] = b;
+                }
+                domain.map(&u, &mut x);
+                let fj = f(&x) * jacobian;
+                sum += fj;
+                sum_sq += fj * fj;
+                for i in 0..d {
+                    importance[i][bins[i]] += fj * fj;
+                }
+            }
+            let n = n_per_iter as f64;
+            let mean = sum / n;
+            let var = (sum_sq / n - mean * mean) / (n - 1.0);
+
+            if iter >= n_warmup {
+                weighted_sum += mean / var;
+                weight_total += 1.0 / var;
+                iteration_estimates.push((mean, var));
+            }
+            self.refine(&importance);
+        }
+
+        let value = weighted_sum / weight_total;
+        // 各反復の推定値の整合性 χ²/自由度（1 程度なら誤差評価は信頼できる）
+        let dof = (iteration_estimates.len() as f64 - 1.0).max(1.0);
+        let chi2 = iteration_estimates
+            .iter()
+            .map(|(m, v)| (m - value).powi(2) / v)
+            .sum::<f64>()
+            / dof;
+        (
+            Estimate {
+                value,
+                std_error: (1.0 / weight_total).sqrt(),
+                n_evals: n_per_iter * n_iter,
+            },
+            chi2,
+        )
+    }
+
+    /// Lepage の方法で格子を更新する: 各区間の重要度が等しくなるように格子点を動かす
+    fn refine(&mut self, importance: &[Vec<f64>]) {
+        let nb = self.n_bins;
+        let alpha = 1.5;
+        for (edges, imp) in self.edges.iter_mut().zip(importance) {
+            // 隣の区間と平滑化してから、急激な変化を抑える圧縮 ((r-1)/ln r)^α をかける
+            let mut smoothed: Vec<f64> = (0..nb)
+                .map(|b| {
+                    let left = if b > 0 { imp[b - 1] } else { imp[b] };
+                    let right = if b + 1 < nb { imp[b + 1] } else { imp[b] };
+                    (left + 6.0 * imp[b] + right) / 8.0
+                })
+                .collect();
+            let total: f64 = smoothed.iter().sum();
+            if total <= 0.0 {
+                continue;
+            }
+            for s in smoothed.iter_mut() {
+                let r = *s / total;
+                *s = if r > 0.0 && r < 1.0 {
+                    ((r - 1.0) / r.ln()).powf(alpha)
+                } else {
+                    r
+                };
+            }
+            let per_bin = smoothed.iter().sum::<f64>() / nb as f64;
+
+            // 累積重要度が per_bin の整数倍になる位置に新しい格子点を置く
+            let mut new_edges = Vec::with_capacity(nb + 1);
+            new_edges.push(0.0);
+            let mut acc = 0.0;
+            let mut b = 0;
+            for k in 1..nb {
+                let target = k as f64 * per_bin;
+                while acc + smoothed[b] < target {
+                    acc += smoothed[b];
+                    b += 1;
+                }
+                let frac = (target - acc) / smoothed[b];
+                new_edges.push(edges[b] + frac * (edges[b + 1] - edges[b]));
+            }
+            new_edges.push(1.0);
+            *edges = new_edges;
+        }
+    }
+}
+
+/// 各手法の結果を表に並べる
+fn print_row(name: &str, est: &Estimate, exact: f64, plain: &Estimate) {
+    println!(
+        "{:<28} {:>12.8} {:>11.3e} {:>11.3e} {:>9} {:>10.1}",
+        name,
+        est.value,
+        est.std_error,
+        (est.value - exact).abs(),
+        est.n_evals,
+        est.variance_reduction_factor(plain)
+    );
+}
+
+fn print_header() {
+    println!(
+        "{:<28} {:>12} {:>11} {:>11} {:>9} {:>10}",
+        "手法", "推定値", "標準誤差", "真の誤差", "評価回数", "分散削減率"
+    );
+}
+
+fn main() {
+    let n = 100_000;
+
+    // ----------------------------------------
+    // 1. importance_sampling.rs と同じ 1次元の積分
+    // ----------------------------------------
+    println!("=== 1. ∫₀^10 e^(-x)/(1 + x²) dx (N = {}) ===", n);
+    let exact = 0.62144962; // [10, ∞) の寄与は 1e-6 程度で、この比較では無視できる
+    let f1 = |x: &[f64]| (-x[0]).exp() / (1.0 + x[0] * x[0]);
+    // 制御変量 g = e^(-x)、∫₀^10 g dx = 1 - e^(-10)
+    let g1 = |x: &[f64]| (-x[0]).exp();
+    let g1_integral = 1.0 - (-10.0f64).exp();
+    let domain = || Domain {
+        lower: vec![0.0],
+        upper: vec![10.0],
+    };
+
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let plain = MonteCarlo::new(domain()).integrate(&f1, n, &mut rng);
+    print_header();
+    print_row("単純 MC", &plain, exact, &plain);
+    let est = MonteCarlo::new(domain())
+        .antithetic()
+        .integrate(&f1, n, &mut rng);
+    print_row("対称変量", &est, exact, &plain);
+    let est = MonteCarlo::new(domain())
+        .control_variate(&g1, g1_integral)
+        .integrate(&f1, n, &mut rng);
+    print_row("制御変量", &est, exact, &plain);
+    let est = MonteCarlo::new(domain())
+        .stratified(100)
+        .integrate(&f1, n, &mut rng);
+    print_row("層別 (100 層)", &est, exact, &plain);
+    let est = MonteCarlo::new(domain())
+        .stratified(100)
+        .control_variate(&g1, g1_integral)
+        .integrate(&f1, n, &mut rng);
+    print_row("層別 + 制御変量", &est, exact, &plain);
+    let (est, chi2) = Vegas::new(1, 50).integrate(&f1, &domain(), n / 10, 10, 3, &mut rng);
+    print_row("VEGAS", &est, exact, &plain);
+    println!("  (VEGAS の反復間の χ²/自由度 = {:.2})", chi2);
+
+    // ----------------------------------------
+    // 2. 4次元の滑らかな単調関数 exp(x₁ + ... + x₄)
+    // ----------------------------------------
+    let d = 4;
+    let exact = (E - 1.0).powi(d as i32);
+    println!(
+        "\n=== 2. ∫_[0,1]^4 exp(Σx_i) dx (厳密値 (e-1)^4 = {:.8}) ===",
+        exact
+    );
+    let f2 = |x: &[f64]| x.iter().sum::<f64>().exp();
+    // 制御変量 g = Π (1 + x_i + x_i²/2)（e^x のテイラー展開）、∫ = (1 + 1/2 + 1/6)^4
+    let g2 = |x: &[f64]| {
+        x.iter()
+            .map(|&xi| 1.0 + xi + 0.5 * xi * xi)
+            .product::<f64>()
+    };
+    let g2_integral = (1.0f64 + 0.5 + 1.0 / 6.0).powi(d as i32);
+
+    let plain = MonteCarlo::new(Domain::unit_cube(d)).integrate(&f2, n, &mut rng);
+    print_header();
+    print_row("単純 MC", &plain, exact, &plain);
+    let est = MonteCarlo::new(Domain::unit_cube(d))
+        .antithetic()
+        .integrate(&f2, n, &mut rng);
+    print_row("対称変量", &est, exact, &plain);
+    let est = MonteCarlo::new(Domain::unit_cube(d))
+        .control_variate(&g2, g2_integral)
+        .integrate(&f2, n, &mut rng);
+    print_row("制御変量", &est, exact, &plain);
+    let est = MonteCarlo::new(Domain::unit_cube(d))
+        .stratified(4)
+        .integrate(&f2, n, &mut rng);
+    print_row("層別 (4^4 層)", &est, exact, &plain);
+    let est = MonteCarlo::new(Domain::unit_cube(d))
+        .stratified(4)
+        .antithetic()
+        .control_variate(&g2, g2_integral)
+        .integrate(&f2, n, &mut rng);
+    print_row("層別 + 対称 + 制御", &est, exact, &plain);
+    let (est, chi2) =
+        Vegas::new(d, 50).integrate(&f2, &Domain::unit_cube(d), n / 10, 10, 3, &mut rng);
+    print_row("VEGAS", &est, exact, &plain);
+    println!("  (VEGAS の反復間の χ²/自由度 = {:.2})", chi2);
+
+    // ----------------------------------------
+    // 3. 鋭いピークをもつ関数（VEGAS が有効な例）
+    // ----------------------------------------
+    let d = 4;
+    let sigma: f64 = 0.05;
+    println!("\n=== 3. 4次元ガウス型ピーク (σ = {}, 厳密値 1) ===", sigma);
+    let norm = (sigma * (2.0 * PI).sqrt()).powi(d as i32);
+    let f3 = |x: &[f64]| {
+        let r2: f64 = x.iter().map(|&xi| (xi - 0.5).powi(2)).sum();
+        (-r2 / (2.0 * sigma * sigma)).exp() / norm
+    };
+    let exact = 1.0;
+    let plain = MonteCarlo::new(Domain::unit_cube(d)).integrate(&f3, n, &mut rng);
+    print_header();
+    print_row("単純 MC", &plain, exact, &plain);
+    let est = MonteCarlo::new(Domain::unit_cube(d))
+        .antithetic()
+        .integrate(&f3, n, &mut rng);
+    print_row("対称変量", &est, exact, &plain);
+    let est = MonteCarlo::new(Domain::unit_cube(d))
+        .stratified(4)
+        .integrate(&f3, n, &mut rng);
+    print_row("層別 (4^4 層)", &est, exact, &plain);
+    let (est, chi2) =
+        Vegas::new(d, 50).integrate(&f3, &Domain::unit_cube(d), n / 10, 10, 3, &mut rng);
+    print_row("VEGAS", &est, exact, &plain);
+    println!("  (VEGAS の反復間の χ²/自由度 = {:.2})", chi2);
+
+    println!("\n分散削減率 = 同じ評価回数の単純 MC の分散 / 各手法の分散");
+    println!("(対称変量は単調な関数でのみ有効で、ピークが中心にある対称な関数では逆効果になる)");
+}
@@ -0,0 +1,4 @@
+pub mod metropolis;
+pub mod qmc;
+pub mod rng;
+pub mod special;
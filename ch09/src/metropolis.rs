@@ -0,0 +1,8 @@
+use rand::{Rng, RngExt};
+
+/// Metropolis 法の受理判定
+/// 比 p(x')/p(x) から受理確率 A = min(1, p(x')/p(x)) で候補を受理するかを決める
+/// 比が 1 以上なら乱数を使わずに受理する
+pub fn accept<R: Rng + ?Sized>(ratio: f64, rng: &mut R) -> bool {
+    ratio >= 1.0 || rng.random::<f64>() < ratio
+}
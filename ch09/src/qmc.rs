@@ -0,0 +1,262 @@
+use crate::rng::stream_rng;
+use rand::{Rng, RngExt};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::Distribution;
+use std::cell::{Cell, RefCell};
+
+/// [0, 1)^d の一様分布（通常のモンテカルロ法の点）
+/// 低食い違い量列 (low-discrepancy sequence) も同じ Distribution<Vec<f64>> を実装するので、
+/// rand の分布を使うコードでそのまま置き換えられる
+pub struct UniformCube {
+    pub dim: usize,
+}
+
+impl Distribution<Vec<f64>> for UniformCube {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        (0..self.dim).map(|_| rng.random()).collect()
+    }
+}
+
+// ----------------------------------------
+// Halton 列
+// ----------------------------------------
+
+const PRIMES: [u64; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// 基数 b の根基逆関数 φ_b(n): n の b 進表記の桁を小数点の反対側に折り返す
+pub fn radical_inverse(mut n: u64, base: u64) -> f64 {
+    let inv_base = 1.0 / base as f64;
+    let mut factor = inv_base;
+    let mut result = 0.0;
+    while n > 0 {
+        result += (n % base) as f64 * factor;
+        n /= base;
+        factor *= inv_base;
+    }
+    result
+}
+
+/// Halton 列: 第 j 成分は j 番目の素数を基数とする根基逆関数
+/// shift を与えると Cranley–Patterson のランダムシフト (x + u mod 1) をかける
+pub struct Halton {
+    dim: usize,
+    /// Distribution::sample は &self をとるので、列の位置は Cell で進める
+    index: Cell<u64>,
+    shift: Option<Vec<f64>>,
+}
+
+impl Halton {
+    pub fn new(dim: usize) -> Self {
+        assert!(dim <= PRIMES.len(), "Halton 列は {} 次元まで", PRIMES.len());
+        // n = 0 は全成分 0 になるので 1 から始める
+        Self {
+            dim,
+            index: Cell::new(1),
+            shift: None,
+        }
+    }
+
+    pub fn randomized(dim: usize, rng: &mut impl Rng) -> Self {
+        let mut h = Self::new(dim);
+        h.shift = Some((0..dim).map(|_| rng.random()).collect());
+        h
+    }
+}
+
+/// 低食い違い量列は決定的なので rng は使わず、呼ぶたびに列の次の点を返す
+/// （乱択化は randomized で作るときに済ませておく）
+impl Distribution<Vec<f64>> for Halton {
+    fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> Vec<f64> {
+        let index = self.index.get();
+        self.index.set(index + 1);
+        (0..self.dim)
+            .map(|j| {
+                let x = radical_inverse(index, PRIMES[j]);
+                match &self.shift {
+                    Some(shift) => (x + shift[j]).fract(),
+                    None => x,
+                }
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------
+// Sobol 列
+// ----------------------------------------
+
+/// Joe & Kuo (2008) の方向数 (new-joe-kuo-6.21201) の先頭部分
+/// (次数 s, 原始多項式の係数 a, 初期値 m_1..m_s)。第1成分は別扱い (m_k = 1)
+const SOBOL_TABLE: [(u32, u32, &[u32]); 15] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+];
+
+const BITS: usize = 32;
+
+/// Sobol 列（グレイコードによる逐次生成）
+/// 乱択化するときは Matoušek の線形行列スクランブルとデジタルシフトを使う
+pub struct Sobol {
+    index: Cell<u32>,
+    /// 方向数 V_k（第 j 成分の k ビット目）
+    directions: Vec<[u32; BITS]>,
+    /// 現在の点（整数表現）
+    state: RefCell<Vec<u32>>,
+}
+
+impl Sobol {
+    pub fn new(dim: usize) -> Self {
+        assert!(
+            dim <= SOBOL_TABLE.len() + 1,
+            "Sobol 列は {} 次元まで",
+            SOBOL_TABLE.len() + 1
+        );
+        let mut directions = vec![[0u32; BITS]; dim];
+        // 第1成分: V_k = 2^(32-k)（ファン・デル・コルプト列）
+        for (k, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (BITS - 1 - k);
+        }
+        for j in 1..dim {
+            let (s, a, m_init) = SOBOL_TABLE[j - 1];
+            let s = s as usize;
+            let v = &mut directions[j];
+            for k in 0..s.min(BITS) {
+                v[k] = m_init[k] << (BITS - 1 - k);
+            }
+            // 漸化式 V_k = a_1 V_{k-1} ⊕ ... ⊕ a_{s-1} V_{k-s+1} ⊕ V_{k-s} ⊕ (V_{k-s} >> s)
+            for k in s..BITS {
+                let mut vk = v[k - s] ^ (v[k - s] >> s);
+                for i in 1..s {
+                    if (a >> (s - 1 - i)) & 1 == 1 {
+                        vk ^= v[k - i];
+                    }
+                }
+                v[k] = vk;
+            }
+        }
+        Self {
+            index: Cell::new(0),
+            directions,
+            state: RefCell::new(vec![0; dim]),
+        }
+    }
+
+    /// 線形行列スクランブル + デジタルシフトで乱択化した Sobol 列
+    /// 下三角（対角成分 1）のランダムな 2 元行列 L を方向数にかけ、初期値にランダムなビット列を与える
+    pub fn scrambled(dim: usize, rng: &mut impl Rng) -> Self {
+        let mut sobol = Self::new(dim);
+        for j in 0..dim {
+            // L の第 r 行（r = 0 が最上位ビット）
+            let rows: Vec<u32> = (0..BITS)
+                .map(|r| {
+                    let diag = 1u32 << (BITS - 1 - r);
+                    // 対角より上位のビットをランダムに選ぶ
+                    let upper = if r == 0 {
+                        0
+                    } else {
+                        rng.random::<u32>() & !(u32::MAX >> r)
+                    };
+                    diag | upper
+                })
+                .collect();
+            for v in sobol.directions[j].iter_mut() {
+                // 各行と V のビットごとの内積（mod 2）が新しい V の対応するビット
+                let mut scrambled = 0u32;
+                for (r, row) in rows.iter().enumerate() {
+                    if (row & *v).count_ones() % 2 == 1 {
+                        scrambled |= 1 << (BITS - 1 - r);
+                    }
+                }
+                *v = scrambled;
+            }
+            sobol.state.get_mut()[j] = rng.random();
+        }
+        sobol
+    }
+}
+
+/// Halton と同じく rng は使わない
+impl Distribution<Vec<f64>> for Sobol {
+    fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> Vec<f64> {
+        let mut state = self.state.borrow_mut();
+        let x = state
+            .iter()
+            .map(|&s| s as f64 / (1u64 << BITS) as f64)
+            .collect();
+        // 次の点: index の最下位の 0 ビットの位置 c に対応する方向数と XOR をとる
+        let index = self.index.get();
+        let c = index.trailing_ones() as usize;
+        for (s, v) in state.iter_mut().zip(&self.directions) {
+            *s ^= v[c];
+        }
+        self.index.set(index + 1);
+        x
+    }
+}
+
+// ----------------------------------------
+// 積分
+// ----------------------------------------
+
+/// 分布 points から n 点をとって ∫_{[0,1)^d} f(x) dx を近似する
+/// 擬似乱数 (UniformCube) でも低食い違い量列でも同じように扱える
+pub fn integrate<F, D, R>(f: &F, points: &D, rng: &mut R, n: usize) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+    D: Distribution<Vec<f64>>,
+    R: Rng + ?Sized,
+{
+    (0..n).map(|_| f(&points.sample(rng))).sum::<f64>() / n as f64
+}
+
+/// シード seed のストリーム r の乱数生成器ごとに make で点の分布を作って積分した n_replicates 個の推定値
+/// QMC では make の中で乱数を使って乱択化し、MC ではそのまま同じ乱数で点を生成する
+pub fn replicate<F, D, M>(f: &F, n: usize, n_replicates: usize, seed: u64, make: M) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+    D: Distribution<Vec<f64>>,
+    M: Fn(&mut ChaCha8Rng) -> D,
+{
+    (0..n_replicates)
+        .map(|r| {
+            let mut rng = stream_rng(seed, r as u64);
+            let points = make(&mut rng);
+            integrate(f, &points, &mut rng, n)
+        })
+        .collect()
+}
+
+/// 乱択化 (R)QMC による積分: 独立に乱択化した点列で n_replicates 回積分し、
+/// その平均と標準誤差を返す（乱択化した各推定値は不偏なので通常の統計で誤差が評価できる）
+/// make が UniformCube を返せば、同じ手順で通常の MC 法の平均と標準誤差になる
+pub fn rqmc_integrate<F, D, M>(
+    f: &F,
+    n: usize,
+    n_replicates: usize,
+    seed: u64,
+    make: M,
+) -> (f64, f64)
+where
+    F: Fn(&[f64]) -> f64,
+    D: Distribution<Vec<f64>>,
+    M: Fn(&mut ChaCha8Rng) -> D,
+{
+    let estimates = replicate(f, n, n_replicates, seed, make);
+    let k = n_replicates as f64;
+    let mean = estimates.iter().sum::<f64>() / k;
+    let var = estimates.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / (k - 1.0);
+    (mean, (var / k).sqrt())
+}
@@ -0,0 +1,11 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// シード seed のストリーム番号 stream の乱数生成器
+/// ChaCha8 は同じシードで 2^64 本の独立なストリームをもつので、
+/// チャンク番号や試行番号をストリーム番号にすれば、どのスレッドが処理しても同じ乱数列になる
+pub fn stream_rng(seed: u64, stream: u64) -> ChaCha8Rng {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(stream);
+    rng
+}
@@ -0,0 +1,444 @@
+use ch10::md::{CUTOFF2, MDSystem};
+use ndarray::{Array2, Axis};
+use std::cell::RefCell;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::time::Instant;
+
+// ----------------------------------------
+// テープと Var
+// ----------------------------------------
+
+/// テープの 1 ノード: 親ノードの番号とその局所偏微分（最大 2 つ）
+#[derive(Clone, Copy)]
+struct Node {
+    deps: [(usize, f64); 2],
+}
+
+/// 演算の記録（Wengert リスト）
+///
+/// ノードは評価順に追加されるので、番号の逆順にたどれば逆伝播になります。
+/// 一度の逆伝播で全変数についての偏微分が得られ、計算量は変数の数によりません。
+struct Tape {
+    nodes: RefCell<Vec<Node>>,
+}
+
+impl Tape {
+    fn new() -> Self {
+        Self {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// 入力変数（葉ノード）を作る
+    fn var(&self, value: f64) -> Var<'_> {
+        self.push(value, [(0, 0.0), (0, 0.0)])
+    }
+
+    fn push(&self, value: f64, deps: [(usize, f64); 2]) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        let index = nodes.len();
+        nodes.push(Node { deps });
+        Var {
+            tape: self,
+            index,
+            value,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    /// 出力 y から逆伝播し、全ノードの随伴値 ∂y/∂v_i を返す
+    fn gradient(&self, y: Var) -> Gradients {
+        let nodes = self.nodes.borrow();
+        let mut adjoint = vec![0.0; y.index + 1];
+        adjoint[y.index] = 1.0;
+        for i in (0..=y.index).rev() {
+            let a = adjoint[i];
+            if a == 0.0 {
+                continue;
+            }
+            for &(parent, partial) in &nodes[i].deps {
+                adjoint[parent] += partial * a;
+            }
+        }
+        Gradients { adjoint }
+    }
+}
+
+/// 逆伝播の結果
+struct Gradients {
+    adjoint: Vec<f64>,
+}
+
+impl Gradients {
+    /// 変数 x についての偏微分
+    fn wrt(&self, x: Var) -> f64 {
+        self.adjoint.get(x.index).copied().unwrap_or(0.0)
+    }
+}
+
+/// テープに記録される変数（値とテープ上の番号）
+#[derive(Clone, Copy)]
+struct Var<'t> {
+    tape: &'t Tape,
+    index: usize,
+    value: f64,
+}
+
+impl<'t> Var<'t> {
+    /// 1 変数関数: 値 f(x) と局所微分 f'(x) を記録する
+    fn unary(self, value: f64, d: f64) -> Self {
+        self.tape.push(value, [(self.index, d), (0, 0.0)])
+    }
+
+    fn sin(self) -> Self {
+        self.unary(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.unary(self.value.cos(), -self.value.sin())
+    }
+
+    fn exp(self) -> Self {
+        let e = self.value.exp();
+        self.unary(e, e)
+    }
+
+    fn ln(self) -> Self {
+        self.unary(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn sqrt(self) -> Self {
+        let r = self.value.sqrt();
+        self.unary(r, 0.5 / r)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.unary(self.value.powi(n), n as f64 * self.value.powi(n - 1))
+    }
+
+    fn recip(self) -> Self {
+        let inv = 1.0 / self.value;
+        self.unary(inv, -inv * inv)
+    }
+}
+
+impl<'t> Add for Var<'t> {
+    type Output = Self;
+    fn add(self, o: Self) -> Self {
+        self.tape
+            .push(self.value + o.value, [(self.index, 1.0), (o.index, 1.0)])
+    }
+}
+
+impl<'t> Sub for Var<'t> {
+    type Output = Self;
+    fn sub(self, o: Self) -> Self {
+        self.tape
+            .push(self.value - o.value, [(self.index, 1.0), (o.index, -1.0)])
+    }
+}
+
+impl<'t> Mul for Var<'t> {
+    type Output = Self;
+    fn mul(self, o: Self) -> Self {
+        self.tape.push(
+            self.value * o.value,
+            [(self.index, o.value), (o.index, self.value)],
+        )
+    }
+}
+
+impl<'t> Div for Var<'t> {
+    type Output = Self;
+    fn div(self, o: Self) -> Self {
+        let inv = 1.0 / o.value;
+        self.tape.push(
+            self.value * inv,
+            [(self.index, inv), (o.index, -self.value * inv * inv)],
+        )
+    }
+}
+
+impl<'t> Neg for Var<'t> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.unary(-self.value, -1.0)
+    }
+}
+
+impl<'t> Add<f64> for Var<'t> {
+    type Output = Self;
+    fn add(self, c: f64) -> Self {
+        self.unary(self.value + c, 1.0)
+    }
+}
+
+impl<'t> Sub<f64> for Var<'t> {
+    type Output = Self;
+    fn sub(self, c: f64) -> Self {
+        self.unary(self.value - c, 1.0)
+    }
+}
+
+impl<'t> Mul<f64> for Var<'t> {
+    type Output = Self;
+    fn mul(self, c: f64) -> Self {
+        self.unary(self.value * c, c)
+    }
+}
+
+impl<'t> Mul<Var<'t>> for f64 {
+    type Output = Var<'t>;
+    fn mul(self, x: Var<'t>) -> Var<'t> {
+        x * self
+    }
+}
+
+impl<'t> Sub<Var<'t>> for f64 {
+    type Output = Var<'t>;
+    fn sub(self, x: Var<'t>) -> Var<'t> {
+        x.unary(self - x.value, -1.0)
+    }
+}
+
+// ----------------------------------------
+// MDSystem の力を逆モード自動微分で求める
+// ----------------------------------------
+
+/// 力の計算方法
+#[derive(Clone, Copy)]
+enum ForceMethod {
+    /// 手で導いた f_scalar · dr（MDSystem::compute_forces）
+    Analytic,
+    /// LJ エネルギーの逆伝播
+    ReverseAd,
+}
+
+/// 全ポテンシャルエネルギー U = Σ 4 (r^{-12} - r^{-6}) をテープ上で評価する
+///
+/// 座標 x[i][k] はテープの入力変数。カットオフ判定と最小像のずれは値だけで決め、
+/// 微分すべき部分（距離とポテンシャル）だけを Var で計算します。
+fn potential<'t>(sys: &MDSystem, x: &[[Var<'t>; 2]]) -> Option<Var<'t>> {
+    let mut total: Option<Var<'t>> = None;
+    for i in 0..sys.n {
+        for j in (i + 1)..sys.n {
+            let dx = x[i][0] - x[j][0];
+            let dy = x[i][1] - x[j][1];
+            let dx = dx - sys.image_shift(dx.value);
+            let dy = dy - sys.image_shift(dy.value);
+            let r2 = dx * dx + dy * dy;
+            if r2.value >= CUTOFF2 {
+                continue;
+            }
+            let r6_inv = r2.powi(3).recip();
+            let u = 4.0 * (r6_inv * r6_inv - r6_inv);
+            total = Some(match total {
+                Some(t) => t + u,
+                None => u,
+            });
+        }
+    }
+    total
+}
+
+/// 逆モード自動微分による力 F = -∇U（質量 1 なので加速度と同じ）
+fn compute_forces_reverse_ad(sys: &mut MDSystem) -> f64 {
+    let tape = Tape::new();
+    let x: Vec<[Var; 2]> = (0..sys.n)
+        .map(|i| [tape.var(sys.pos[[i, 0]]), tape.var(sys.pos[[i, 1]])])
+        .collect();
+    let Some(u) = potential(sys, &x) else {
+        sys.acc.fill(0.0);
+        return 0.0;
+    };
+    let grad = tape.gradient(u);
+    for (mut acc, xi) in sys.acc.rows_mut().into_iter().zip(&x) {
+        acc[0] = -grad.wrt(xi[0]);
+        acc[1] = -grad.wrt(xi[1]);
+    }
+    u.value
+}
+
+fn compute_forces(sys: &mut MDSystem, method: ForceMethod) -> f64 {
+    match method {
+        ForceMethod::Analytic => sys.compute_forces(),
+        ForceMethod::ReverseAd => compute_forces_reverse_ad(sys),
+    }
+}
+
+fn step(sys: &mut MDSystem, dt: f64, method: ForceMethod) -> f64 {
+    sys.step_with(dt, |s| compute_forces(s, method))
+}
+
+/// 全ポテンシャルエネルギーの値だけを計算する（差分による勾配の比較用）
+fn potential_value(sys: &MDSystem) -> f64 {
+    let mut pot = 0.0;
+    for i in 0..sys.n {
+        for j in (i + 1)..sys.n {
+            let dr = sys.get_dr(i, j);
+            let r2 = dr.dot(&dr);
+            if r2 < CUTOFF2 {
+                let r6_inv = 1.0 / (r2 * r2 * r2);
+                pot += 4.0 * (r6_inv * r6_inv - r6_inv);
+            }
+        }
+    }
+    pot
+}
+
+fn max_abs_diff(a: &Array2<f64>, b: &Array2<f64>) -> f64 {
+    (a - b).iter().fold(0.0, |m: f64, v| m.max(v.abs()))
+}
+
+fn main() {
+    // ----------------------------------------
+    // 1. 小さな例で逆伝播を確認
+    // ----------------------------------------
+    println!("=== 1. 逆伝播の確認 ===");
+    // f(x, y) = sin(x) e^y + ln(x² + y²) / √y
+    let tape = Tape::new();
+    let (x, y) = (tape.var(0.7), tape.var(1.3));
+    let f = x.sin() * y.exp() + (x * x + y * y).ln() / y.sqrt();
+    let grad = tape.gradient(f);
+    let (xv, yv) = (0.7f64, 1.3f64);
+    let q = xv * xv + yv * yv;
+    let dfdx = xv.cos() * yv.exp() + 2.0 * xv / (q * yv.sqrt());
+    let dfdy = xv.sin() * yv.exp() + 2.0 * yv / (q * yv.sqrt()) - 0.5 * q.ln() / yv.powf(1.5);
+    println!(
+        "∂f/∂x = {:.15} (解析解との差 {:.1e}), ∂f/∂y = {:.15} (差 {:.1e}), テープ長 {}",
+        grad.wrt(x),
+        (grad.wrt(x) - dfdx).abs(),
+        grad.wrt(y),
+        (grad.wrt(y) - dfdy).abs(),
+        tape.len()
+    );
+    // 同じ変数を何度も使う式: g(x) = x · x · cos(x) → 随伴値が加算される
+    let tape = Tape::new();
+    let x = tape.var(2.0);
+    let g = x * x * x.cos();
+    let dg = 2.0 * 2.0 * 2f64.cos() - 4.0 * 2f64.sin();
+    println!(
+        "g(x) = x² cos x: g'(2) = {:.15} (解析解との差 {:.1e})",
+        tape.gradient(g).wrt(x),
+        (tape.gradient(g).wrt(x) - dg).abs()
+    );
+
+    // ----------------------------------------
+    // 2. LJ 力: 自動微分と手書きの f_scalar の比較
+    // ----------------------------------------
+    println!("\n=== 2. LJ 力: 逆モード自動微分 vs 手書きの f_scalar ===");
+    // molecular_dynamics.rs と同じ 16 粒子・箱 10.0・温度 0.5 から少し時間発展させた配置
+    let mut system = MDSystem::new(16, 10.0, 0.5);
+    compute_forces(&mut system, ForceMethod::Analytic);
+    for _ in 0..200 {
+        step(&mut system, 0.01, ForceMethod::Analytic);
+    }
+    let mut analytic = system.clone();
+    let mut reverse = system.clone();
+    let u_analytic = compute_forces(&mut analytic, ForceMethod::Analytic);
+    let u_reverse = compute_forces(&mut reverse, ForceMethod::ReverseAd);
+    let f_scale = analytic.acc.iter().fold(0.0, |m: f64, v| m.max(v.abs()));
+    println!(
+        "ポテンシャル: 手書き {:.12}, 自動微分 {:.12}",
+        u_analytic, u_reverse
+    );
+    println!(
+        "力の最大差 {:.2e}（力の最大値 {:.3}）",
+        max_abs_diff(&analytic.acc, &reverse.acc),
+        f_scale
+    );
+    let total_force = reverse.acc.sum_axis(Axis(0));
+    println!(
+        "自動微分の力の総和（作用・反作用で 0）: ({:.1e}, {:.1e})",
+        total_force[0], total_force[1]
+    );
+
+    // ----------------------------------------
+    // 3. 同じ初期条件で軌道を比較
+    // ----------------------------------------
+    println!("\n=== 3. 時間発展（100 ステップ）の比較 ===");
+    let dt = 0.01;
+    println!(
+        "{:>5} {:>14} {:>14} {:>12}",
+        "Step", "E (手書き)", "E (自動微分)", "位置の最大差"
+    );
+    for i in 0..=100 {
+        let pot_a = step(&mut analytic, dt, ForceMethod::Analytic);
+        let pot_r = step(&mut reverse, dt, ForceMethod::ReverseAd);
+        if i % 20 == 0 {
+            let kin_a = 0.5 * analytic.vel.mapv(|v| v * v).sum();
+            let kin_r = 0.5 * reverse.vel.mapv(|v| v * v).sum();
+            println!(
+                "{:>5} {:>14.8} {:>14.8} {:>12.1e}",
+                i,
+                pot_a + kin_a,
+                pot_r + kin_r,
+                max_abs_diff(&analytic.pos, &reverse.pos)
+            );
+        }
+    }
+
+    // ----------------------------------------
+    // 4. 粒子数に対する計算時間
+    // ----------------------------------------
+    println!("\n=== 4. 勾配 1 回あたりの計算時間（密度は上と同じ 0.16） ===");
+    println!(
+        "{:>6} {:>7} {:>12} {:>12} {:>12} {:>12} {:>10}",
+        "N", "変数数", "エネルギー", "手書きの力", "逆モード", "中心差分", "テープ長"
+    );
+    for n in [16, 64, 256, 1024] {
+        let l = (n as f64 / 0.16).sqrt();
+        let mut sys = MDSystem::new(n, l, 0.5);
+
+        let time = |f: &mut dyn FnMut()| {
+            let reps = (4096 / n).max(1);
+            let start = Instant::now();
+            for _ in 0..reps {
+                f();
+            }
+            start.elapsed().as_secs_f64() / reps as f64
+        };
+        let t_energy = time(&mut || {
+            std::hint::black_box(potential_value(&sys));
+        });
+        let t_analytic = time(&mut || {
+            compute_forces(&mut sys, ForceMethod::Analytic);
+        });
+        let t_reverse = time(&mut || {
+            compute_forces(&mut sys, ForceMethod::ReverseAd);
+        });
+        let tape = Tape::new();
+        let x: Vec<[Var; 2]> = (0..n)
+            .map(|i| [tape.var(sys.pos[[i, 0]]), tape.var(sys.pos[[i, 1]])])
+            .collect();
+        potential(&sys, &x);
+
+        // 中心差分は座標ごとに 2 回のエネルギー評価（前進モードも同じく 2N 回のオーダー）。
+        // 大きな N では時間がかかるので 1 座標分を測って 2N 倍する
+        let h = 1e-6;
+        let t_fd = time(&mut || {
+            let x0 = sys.pos[[0, 0]];
+            sys.pos[[0, 0]] = x0 + h;
+            let up = potential_value(&sys);
+            sys.pos[[0, 0]] = x0 - h;
+            let um = potential_value(&sys);
+            sys.pos[[0, 0]] = x0;
+            std::hint::black_box((up - um) / (2.0 * h));
+        }) * (2 * n) as f64;
+
+        println!(
+            "{:>6} {:>7} {:>10.2e} s {:>10.2e} s {:>10.2e} s {:>10.2e} s {:>10}",
+            n,
+            2 * n,
+            t_energy,
+            t_analytic,
+            t_reverse,
+            t_fd,
+            tape.len()
+        );
+    }
+    println!("逆モードの時間はエネルギー評価の定数倍、中心差分は変数数に比例してさらに増える");
+}
@@ -0,0 +1,108 @@
+use ndarray::{Array1, Array2, Axis};
+use ndarray_rand::RandomExt;
+use ndarray_rand::rand_distr::Uniform;
+
+/// LJ ポテンシャルのカットオフ半径の 2 乗（カットオフ 3.0）
+pub const CUTOFF2: f64 = 9.0;
+
+/// 周期境界の正方形の箱に入った LJ 粒子系（2 次元、質量 1）
+#[derive(Clone)]
+pub struct MDSystem {
+    pub n: usize,
+    pub l: f64,
+    pub pos: Array2<f64>,
+    pub vel: Array2<f64>,
+    pub acc: Array2<f64>,
+}
+
+impl MDSystem {
+    pub fn new(n: usize, l: f64, target_temp: f64) -> Self {
+        let mut pos = Array2::zeros((n, 2));
+        let n_side = (n as f64).sqrt() as usize;
+        let spacing = l / n_side as f64;
+
+        for i in 0..n {
+            pos[[i, 0]] = (i % n_side) as f64 * spacing + spacing * 0.5;
+            pos[[i, 1]] = (i / n_side) as f64 * spacing + spacing * 0.5;
+        }
+
+        // 1. ランダムな初速を与える（-0.5 ～ 0.5 の一様分布）
+        let mut vel = Array2::<f64>::random((n, 2), Uniform::new(-0.5, 0.5).unwrap());
+
+        // 2. 重心速度をゼロにする（系全体のドリフトを防ぐ）
+        let mean_vel = vel.mean_axis(Axis(0)).unwrap();
+        vel -= &mean_vel;
+
+        // 3. 温度（運動エネルギー）の調整
+        // 2次元の場合、自由度あたりのエネルギーから温度をスケーリング
+        let current_temp = 0.5 * vel.mapv(|v: f64| v.powi(2)).sum() / n as f64;
+        let scale = (target_temp / current_temp).sqrt();
+        vel *= scale;
+
+        Self {
+            n,
+            l,
+            pos,
+            vel,
+            acc: Array2::zeros((n, 2)),
+        }
+    }
+
+    /// 最小像規約で周期境界をまたいだときのずれ
+    pub fn image_shift(&self, d: f64) -> f64 {
+        if d > self.l * 0.5 {
+            self.l
+        } else if d < -self.l * 0.5 {
+            -self.l
+        } else {
+            0.0
+        }
+    }
+
+    pub fn get_dr(&self, i: usize, j: usize) -> Array1<f64> {
+        let mut dr = &self.pos.row(i) - &self.pos.row(j);
+        for k in 0..2 {
+            dr[k] -= self.image_shift(dr[k]);
+        }
+        dr
+    }
+
+    /// LJ 力を加速度に書き込み、全ポテンシャルエネルギーを返す
+    pub fn compute_forces(&mut self) -> f64 {
+        self.acc.fill(0.0);
+        let mut pot = 0.0;
+        for i in 0..self.n {
+            for j in (i + 1)..self.n {
+                let dr = self.get_dr(i, j);
+                let r2 = dr.dot(&dr);
+                if r2 < CUTOFF2 {
+                    let r2_inv = 1.0 / r2;
+                    let r6_inv = r2_inv * r2_inv * r2_inv;
+                    pot += 4.0 * (r6_inv * r6_inv - r6_inv);
+                    let f_scalar = 24.0 * r2_inv * (2.0 * r6_inv * r6_inv - r6_inv);
+                    for k in 0..2 {
+                        self.acc[[i, k]] += f_scalar * dr[k];
+                        self.acc[[j, k]] -= f_scalar * dr[k];
+                    }
+                }
+            }
+        }
+        pot
+    }
+
+    /// 速度ベルレ法で 1 ステップ進め、ポテンシャルエネルギーを返す
+    pub fn step(&mut self, dt: f64) -> f64 {
+        self.step_with(dt, Self::compute_forces)
+    }
+
+    /// 力の計算を差し替えて 1 ステップ進める
+    /// compute_forces と同じく、加速度を書き込んでポテンシャルエネルギーを返す関数を渡す
+    pub fn step_with<F: FnOnce(&mut Self) -> f64>(&mut self, dt: f64, compute_forces: F) -> f64 {
+        self.pos += &(&self.vel * dt + 0.5 * &self.acc * dt * dt);
+        self.pos.mapv_inplace(|x| x.rem_euclid(self.l));
+        let old_acc = self.acc.clone();
+        let pot = compute_forces(self);
+        self.vel += &(0.5 * (&old_acc + &self.acc) * dt);
+        pot
+    }
+}